@@ -1,46 +1,129 @@
-use std::collections::HashMap;
+use crate::{
+    bench::Solutions,
+    complexity::Complexity,
+    error::{HackerankError, Result},
+    generate::{Generate, Rng, Shape},
+    meta::{self, Constraint, Difficulty, Meta},
+    problem::Problem,
+    scanner::Scanner,
+};
 
 fn acm_icpc_team(topic: &[String]) -> Vec<i32> {
-    let mut map = HashMap::<String, i32>::new();
+    let mut max_topics = 0;
+    let mut teams = 0;
 
-    for a in topic {
-        for b in topic {
-            if a == b {
-                continue;
-            }
-
-            let combined = a
+    for (i, a) in topic.iter().enumerate() {
+        for b in &topic[i + 1..] {
+            let known = a
                 .chars()
                 .zip(b.chars())
-                .map(|v| match v {
-                    ('1', _) | (_, '1') => '1',
-                    _ => '0',
-                })
-                .collect::<String>();
-
-            let key = format!("{}-{}", a, b);
-            let maybe_count = map.get_mut(&key);
-
-            match maybe_count {
-                Some(count) => {
-                    *count += 1;
-                }
-                _ => {
-                    map.insert(key, 1);
-                }
-            };
+                .filter(|v| matches!(v, ('1', _) | (_, '1')))
+                .count() as i32;
+
+            if known > max_topics {
+                max_topics = known;
+                teams = 1;
+            } else if known == max_topics {
+                teams += 1;
+            }
         }
     }
 
-    let mut best = map
-        .into_iter()
-        .map(|(k, v)| (k.replace("0", "").len() as i32, v))
-        .collect::<Vec<_>>();
+    [max_topics, teams].to_vec()
+}
+
+pub struct AcmIcpcTeam;
+
+impl Problem for AcmIcpcTeam {
+    type Input = Vec<String>;
+    type Output = Vec<i32>;
+
+    const SLUG: &'static str = "acm_icpc_team";
+
+    const META: Meta = Meta {
+        title: "ACM ICPC Team",
+        url: "https://www.hackerrank.com/challenges/acm-icpc-team/problem",
+        domain: "implementation",
+        difficulty: Difficulty::Easy,
+        tags: &["bit manipulation", "brute force"],
+        constraints: &[Constraint::new("n", 2, 500), Constraint::new("m", 1, 500)],
+    };
+
+    fn parse(input: &[u8]) -> Result<Self::Input> {
+        let mut scanner = Scanner::new(input);
+        let n = scanner.next::<usize>()?;
+        let _m = scanner.next::<usize>()?;
+
+        Ok(scanner.vec::<String>(n)?)
+    }
+
+    fn values(topics: &Self::Input) -> Vec<(&'static str, i64)> {
+        let mut values = vec![("n", topics.len() as i64)];
+
+        values.extend(topics.iter().map(|topic| ("m", topic.len() as i64)));
+        values
+    }
+
+    fn check(topics: &Self::Input) -> Result<()> {
+        let m = topics.first().map_or(0, String::len);
 
-    best.sort();
-    best.reverse();
+        for (index, topic) in topics.iter().enumerate() {
+            if topic.len() != m {
+                return Err(HackerankError::constraint(
+                    "m",
+                    format!(
+                        "attendee {} knows {} topics out of {}",
+                        index + 1,
+                        topic.len(),
+                        m
+                    ),
+                ));
+            }
+
+            meta::chars(topic, |c| c == '0' || c == '1', "0 or 1")?;
+        }
+
+        Ok(())
+    }
+
+    fn solve(input: Self::Input) -> Result<Self::Output> {
+        Ok(acm_icpc_team(&input))
+    }
+}
+
+/// Topics of generated inputs, half of the 500 the constraints allow
+const TOPICS: usize = 250;
+
+impl Generate for AcmIcpcTeam {
+    const MAX_SIZE: usize = 500;
+
+    /// `size` people over a fixed number of topics, so that only the people
+    /// grow with the size. Uniform people know every topic, worst alternates
+    /// complementary halves so the best teams cover everything.
+    fn generate(rng: &mut Rng, size: usize, shape: Shape) -> Self::Input {
+        (0..size.max(2))
+            .map(|person| match shape {
+                Shape::Random => rng.string(TOPICS, b"01"),
+                Shape::Uniform => "1".repeat(TOPICS),
+                Shape::Worst => (0..TOPICS)
+                    .map(|topic| match (person + topic) % 2 {
+                        0 => '1',
+                        _ => '0',
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    fn write(input: &Self::Input) -> String {
+        format!("{} {}\n{}\n", input.len(), input[0].len(), input.join("\n"))
+    }
+}
 
-    [best[0].0, best[0].1].to_vec()
+impl Solutions for AcmIcpcTeam {
+    /// Every pair of people compares every topic, which is quadratic in the
+    /// input when the topics are fixed
+    const COMPLEXITY: Complexity = Complexity::Quadratic;
 }
 
 #[cfg(test)]
@@ -56,6 +139,6 @@ mod tests {
             "00101".to_string(),
         ]);
 
-        assert_eq!(result, vec![]);
+        assert_eq!(result, vec![5, 2]);
     }
 }
@@ -1,7 +1,18 @@
+use crate::{
+    bench::Solutions,
+    cases::Cases,
+    complexity::Complexity,
+    error::Result,
+    generate::{Generate, LOWERCASE, Rng, Shape},
+    meta::{self, Constraint, Difficulty, Meta},
+    scanner::Scanner,
+    trace::{Level, trace},
+};
+
 fn anagram(s: &str) -> i32 {
     let mut answer = -1;
 
-    if s.len() % 2 > 0 {
+    if !s.len().is_multiple_of(2) {
         return answer;
     }
 
@@ -42,9 +53,7 @@ fn anagram(s: &str) -> i32 {
         },
     );
 
-    println!("---");
-    println!("left: {:?}", chunk_l);
-    println!("right: {:?}", chunk_r);
+    trace!(Level::Debug, "chunks", left = chunk_l, right = chunk_r);
 
     for index in 0..alphabet.count() {
         let diff = chunk_l[index].len() as i32 - chunk_r[index].len() as i32;
@@ -58,6 +67,73 @@ fn anagram(s: &str) -> i32 {
     }
 }
 
+pub struct Anagram;
+
+impl Cases for Anagram {
+    type Case = String;
+    type Answer = i32;
+
+    const SLUG: &'static str = "anagram";
+
+    const META: Meta = Meta {
+        title: "Anagram",
+        url: "https://www.hackerrank.com/challenges/anagram/problem",
+        domain: "strings",
+        difficulty: Difficulty::Easy,
+        tags: &["strings", "counting"],
+        constraints: &[
+            Constraint::new("q", 1, 100),
+            Constraint::new("|s|", 1, 10_000),
+        ],
+    };
+
+    const COUNT: &'static str = "q";
+
+    fn parse_case(scanner: &mut Scanner) -> Result<Self::Case> {
+        Ok(scanner.next::<String>()?)
+    }
+
+    fn case_values(s: &Self::Case) -> Vec<(&'static str, i64)> {
+        vec![("|s|", s.len() as i64)]
+    }
+
+    fn check_case(s: &Self::Case) -> Result<()> {
+        meta::lowercase(s)
+    }
+
+    fn solve_case(s: Self::Case) -> Result<Self::Answer> {
+        Ok(anagram(&s))
+    }
+}
+
+impl Generate for Anagram {
+    const MAX_SIZE: usize = 10_000;
+
+    /// Up to 100 strings of up to `size` letters, odd lengths included. Worst
+    /// strings have halves with nothing in common.
+    fn generate(rng: &mut Rng, size: usize, shape: Shape) -> Self::Input {
+        (0..rng.size(1..=size.min(100)))
+            .map(|_| {
+                let len = rng.size(1..=size);
+
+                match shape {
+                    Shape::Random => rng.string(len, LOWERCASE),
+                    Shape::Uniform => "a".repeat(len),
+                    Shape::Worst => "a".repeat(len / 2) + &"b".repeat(len - len / 2),
+                }
+            })
+            .collect()
+    }
+
+    fn write(input: &Self::Input) -> String {
+        format!("{}\n{}\n", input.len(), input.join("\n"))
+    }
+}
+
+impl Solutions for Anagram {
+    const COMPLEXITY: Complexity = Complexity::Linear;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,25 +141,25 @@ mod tests {
     #[test]
     fn anagram_01() {
         let result = anagram("xyyx");
-        assert_eq!(result, -1);
+        assert_eq!(result, 0);
     }
 
     #[test]
     fn anagram_02() {
         let result = anagram("hhpddlnnsjfoyxpciioigvjqzfbpllssuj");
-        assert_eq!(result, -1);
+        assert_eq!(result, 10);
     }
 
     #[test]
     fn anagram_03() {
         let result = anagram("xulkowreuowzxgnhmiqekxhzistdocbnyozmnqthhpievvlj");
-        assert_eq!(result, -1);
+        assert_eq!(result, 13);
     }
 
     #[test]
     fn anagram_04() {
         let result = anagram("dnqaurlplofnrtmh");
-        assert_eq!(result, -1);
+        assert_eq!(result, 5);
     }
 
     #[test]
@@ -91,14 +167,14 @@ mod tests {
         let result = anagram(
             "aujteqimwfkjoqodgqaxbrkrwykpmuimqtgulojjwtukjiqrasqejbvfbixnchzsahpnyayutsgecwvcqngzoehrmeeqlgknnb",
         );
-        assert_eq!(result, -1);
+        assert_eq!(result, 26);
     }
 
     #[test]
     fn anagram_06() {
         let result =
             anagram("lbafwuoawkxydlfcbjjtxpzpchzrvbtievqbpedlqbktorypcjkzzkodrpvosqzxmpad");
-        assert_eq!(result, -1);
+        assert_eq!(result, 15);
     }
 
     #[test]
@@ -110,6 +186,6 @@ mod tests {
     #[test]
     fn anagram_08() {
         let result = anagram("ubulzt");
-        assert_eq!(result, -1);
+        assert_eq!(result, 3);
     }
 }
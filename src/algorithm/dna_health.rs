@@ -50,8 +50,66 @@
 use std::{
     cmp::{max, min},
     collections::{HashMap, VecDeque},
+    fmt,
+    sync::LazyLock,
 };
 
+use crate::{
+    bench::{Solution, Solutions},
+    checker::Checker,
+    complexity::Complexity,
+    error::{HackerankError, Result},
+    explain::explain,
+    generate::{self, Generate, Rng, Shape},
+    layout::{Fields, Layout, Reader, Source},
+    meta::{self, Constraint, Difficulty, Meta},
+    problem::Problem,
+    scanner::Scanner,
+    shrink::{self, Shrink},
+    stress::Reference,
+    trace::{Level, trace},
+};
+
+/// Steps `hackerank explain` shows
+enum Step {
+    /// A gene of the strand's range ending at a position of the strand
+    Matched {
+        gene: usize,
+        end: usize,
+        health: i64,
+    },
+    Strand {
+        index: usize,
+        first: i32,
+        last: i32,
+        dna: String,
+        health: i64,
+    },
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Matched { gene, end, health } => write!(
+                f,
+                "gene {} matches ending at {}, health {:+}",
+                gene, end, health
+            ),
+            Step::Strand {
+                index,
+                first,
+                last,
+                dna,
+                health,
+            } => write!(
+                f,
+                "strand {} `{}` with genes {} to {} is worth {}",
+                index, dna, first, last, health
+            ),
+        }
+    }
+}
+
 /// Node in the Aho-Corasick trie structure
 /// Each node represents a state in the automaton
 #[derive(Debug, Clone)]
@@ -165,7 +223,7 @@ impl AhoCorasick {
         let mut current = 0;
         let mut total_health = 0;
 
-        for ch in text.chars() {
+        for (position, ch) in text.chars().enumerate() {
             while current != 0 && !self.trie[current].children.contains_key(&ch) {
                 current = self.trie[current].failure;
             }
@@ -181,6 +239,12 @@ impl AhoCorasick {
                 for &(gene_index, health_value) in &self.trie[output_node].output {
                     if gene_index >= start_gene && gene_index <= end_gene {
                         total_health += health_value;
+
+                        explain!(Step::Matched {
+                            gene: gene_index,
+                            end: position,
+                            health: health_value,
+                        });
                     }
                 }
                 output_node = self.trie[output_node].failure;
@@ -207,7 +271,7 @@ impl AhoCorasick {
 ///   - dna_sequence: the DNA strand to search in
 ///
 /// # Returns
-/// String containing "min_health max_health" where:
+/// Healths of the unhealthiest and healthiest strands, where:
 /// - min_health: minimum health value found across all strands
 /// - max_health: maximum health value found across all strands
 ///
@@ -222,9 +286,13 @@ impl AhoCorasick {
 /// let health = vec![1, 2];
 /// let strands = vec![(0, 1, "aaa".to_string())];
 /// let result = dna_health(genes, health, strands);
-/// // Result: "5 5" (three 'a' matches + two 'aa' matches = 3×1 + 2×2 = 7)
+/// // Result: (7, 7) (three 'a' matches + two 'aa' matches = 3×1 + 2×2 = 7)
 /// ```
-fn dna_health(genes: Vec<String>, health: Vec<i64>, strands: Vec<(i32, i32, String)>) -> String {
+fn dna_health(
+    genes: Vec<String>,
+    health: Vec<i64>,
+    strands: Vec<(i32, i32, String)>,
+) -> (i64, i64) {
     let mut aho_corasick = AhoCorasick::new();
 
     // Build the trie with all genes
@@ -239,68 +307,125 @@ fn dna_health(genes: Vec<String>, health: Vec<i64>, strands: Vec<(i32, i32, Stri
     let mut max_health = i64::MIN;
 
     // Process each DNA strand
-    for (start, end, dna) in strands {
+    for (index, (start, end, dna)) in strands.into_iter().enumerate() {
         let strand_health = aho_corasick.search(&dna, start as usize, end as usize);
+
+        explain!(Step::Strand {
+            index,
+            first: start,
+            last: end,
+            dna,
+            health: strand_health,
+        });
+
         min_health = min(min_health, strand_health);
         max_health = max(max_health, strand_health);
     }
 
-    let result = format!("{} {}", min_health, max_health);
-    println!("{}", result);
-    result
+    trace!(Level::Info, "healths", min = min_health, max = max_health);
+
+    (min_health, max_health)
 }
 
-/// Parse input from file and run DNA health analysis
-/// Input format:
-/// - Line 1: number of genes (n)
-/// - Line 2: space-separated gene sequences
-/// - Line 3: space-separated health values
-/// - Line 4: number of test cases (s)
-/// - Lines 5 to 4+s: each line contains "start end dna_string"
-pub fn parse_and_run_dna_health(file_path: &str) -> std::io::Result<String> {
-    use std::fs;
-
-    let content = fs::read_to_string(file_path)?;
-    let mut lines = content.lines();
-
-    // Parse number of genes
-    let n: usize = lines.next().unwrap().parse().unwrap();
-
-    // Parse genes
-    let genes: Vec<String> = lines
-        .next()
-        .unwrap()
-        .split_whitespace()
-        .take(n)
-        .map(|s| s.to_string())
-        .collect();
-
-    // Parse health values
-    let health: Vec<i64> = lines
-        .next()
-        .unwrap()
-        .split_whitespace()
-        .take(n)
-        .map(|s| s.parse().unwrap())
-        .collect();
-
-    // Parse number of test cases
-    let s: usize = lines.next().unwrap().parse().unwrap();
-
-    // Parse test cases
-    let mut strands = Vec::new();
-    for _ in 0..s {
-        let line = lines.next().unwrap();
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        let start: i32 = parts[0].parse().unwrap();
-        let end: i32 = parts[1].parse().unwrap();
-        let dna = parts[2].to_string();
-        strands.push((start, end, dna));
+/// Genes with their health, then strands with the range of genes they count
+static LAYOUT: LazyLock<Layout> = LazyLock::new(|| {
+    Layout::new(
+        "n:usize; genes:[String;n]; health:[i64;n]; s:usize; \
+         strands:[(first:i32, last:i32, d:String);s]",
+    )
+});
+
+/// Fields of [`LAYOUT`] holding `input`
+fn fields((genes, health, strands): &<DnaHealth as Problem>::Input) -> Fields<'_> {
+    Fields::default()
+        .with("genes", genes)
+        .with("health", health)
+        .with("strands", strands)
+}
+
+/// Input held by the fields of [`LAYOUT`] `reader` hands out
+fn read(mut reader: Reader<impl Source>) -> Result<<DnaHealth as Problem>::Input> {
+    Ok((
+        reader.field("genes")?,
+        reader.field("health")?,
+        reader.field("strands")?,
+    ))
+}
+
+pub struct DnaHealth;
+
+impl Problem for DnaHealth {
+    type Input = (Vec<String>, Vec<i64>, Vec<(i32, i32, String)>);
+    /// Healths of the unhealthiest and healthiest strands
+    type Output = (i64, i64);
+
+    const SLUG: &'static str = "dna_health";
+
+    const META: Meta = Meta {
+        title: "Determining DNA Health",
+        url: "https://www.hackerrank.com/challenges/determining-dna-health/problem",
+        domain: "strings",
+        difficulty: Difficulty::Hard,
+        tags: &["strings", "Aho-Corasick"],
+        constraints: &[
+            Constraint::new("n", 1, 100_000),
+            Constraint::new("health[i]", 0, 10_000_000),
+            Constraint::new("s", 1, 100_000),
+            Constraint::new("first", 0, 99_999),
+            Constraint::new("last", 0, 99_999),
+            Constraint::new("total length", 1, 2_000_000),
+        ],
+    };
+
+    /// Both healths are compared as tokens, whatever the spacing
+    const CHECKER: Checker = Checker::Tokens;
+
+    fn parse(input: &[u8]) -> Result<Self::Input> {
+        read(LAYOUT.reader(Scanner::new(input)))
+    }
+
+    fn values(input: &Self::Input) -> Vec<(&'static str, i64)> {
+        let (genes, _, strands) = input;
+        let mut values = LAYOUT.values(&fields(input), Self::META.constraints);
+        let total = genes.iter().map(String::len).sum::<usize>()
+            + strands.iter().map(|(_, _, d)| d.len()).sum::<usize>();
+
+        values.push(("total length", total as i64));
+        values
     }
 
-    // Run the analysis
-    let result = dna_health(genes, health, strands);
-    Ok(result)
+    fn check((genes, health, strands): &Self::Input) -> Result<()> {
+        if health.len() != genes.len() {
+            return Err(HackerankError::constraint(
+                "n",
+                format!("{} genes but {} health values", genes.len(), health.len()),
+            ));
+        }
+
+        for (index, (first, last, _)) in strands.iter().enumerate() {
+            if first > last || *last as usize >= genes.len() {
+                return Err(HackerankError::constraint(
+                    "last",
+                    format!(
+                        "strand {} covers genes {} to {}, expected first <= last < n = {}",
+                        index,
+                        first,
+                        last,
+                        genes.len()
+                    ),
+                ));
+            }
+        }
+
+        genes
+            .iter()
+            .chain(strands.iter().map(|(_, _, d)| d))
+            .try_for_each(|s| meta::lowercase(s))
+    }
+
+    fn solve((genes, health, strands): Self::Input) -> Result<Self::Output> {
+        Ok(dna_health(genes, health, strands))
+    }
 }
 
 /// Naive implementation for performance comparison
@@ -310,7 +435,7 @@ fn dna_health_naive(
     genes: Vec<String>,
     health: Vec<i64>,
     strands: Vec<(i32, i32, String)>,
-) -> String {
+) -> (i64, i64) {
     let mut min_health = i64::MAX;
     let mut max_health = i64::MIN;
 
@@ -337,12 +462,181 @@ fn dna_health_naive(
         max_health = max(max_health, strand_health);
     }
 
-    format!("{} {}", min_health, max_health)
+    (min_health, max_health)
+}
+
+impl Generate for DnaHealth {
+    const MAX_SIZE: usize = 100_000;
+
+    /// Short genes over a three letter alphabet so strands hit many of them,
+    /// duplicates included, laid out by [`LAYOUT`]. Uniform only uses `a`,
+    /// worst makes the genes `a`, `aa`, `aaa`... for the deepest automaton
+    /// and matches every one of them at each position of all `a` strands.
+    /// Strands share what the genes leave of the 2·10^6 characters the
+    /// constraints allow in total.
+    fn generate(rng: &mut Rng, size: usize, shape: Shape) -> Self::Input {
+        let alphabet: &[u8] = match shape {
+            Shape::Random => b"abc",
+            Shape::Uniform => b"a",
+            Shape::Worst => return worst_input(rng, size),
+        };
+        let strand_len = (2 * size).min((2_000_000 - 3 * size) / size) as i64;
+        let size = size as i64;
+        let constraints = [
+            Constraint::new("n", size, size),
+            Constraint::new("|genes|", 1, 3),
+            Constraint::new("health[i]", 0, 100),
+            Constraint::new("s", 1, size),
+            Constraint::new("first", 0, size - 1),
+            Constraint::new("last", 0, size - 1),
+            Constraint::new("|d|", 1, strand_len),
+        ];
+        let reader = generate::layout(&LAYOUT, rng, size as usize, &constraints, alphabet);
+        let (genes, health, strands) = read(reader).expect("random fields fit the layout");
+        let strands = strands
+            .into_iter()
+            .map(|(first, last, d)| (first.min(last), first.max(last), d))
+            .collect();
+
+        (genes, health, strands)
+    }
+
+    fn write(input: &Self::Input) -> String {
+        LAYOUT.write(&fields(input))
+    }
+}
+
+/// Genes `a`, `aa`, `aaa`... and strands of `a` covering all of them
+fn worst_input(rng: &mut Rng, size: usize) -> <DnaHealth as Problem>::Input {
+    let n = size;
+    let s = rng.size(1..=size);
+    let depth = n.min(1_000_000 / n);
+    let genes = (0..n)
+        .map(|gene| "a".repeat(gene % depth + 1))
+        .collect::<Vec<String>>();
+    let left = 2_000_000 - genes.iter().map(String::len).sum::<usize>();
+    let strand_len = (2 * size).min(left / s);
+    let health = (0..n).map(|_| rng.int(0..=100)).collect();
+
+    (
+        genes,
+        health,
+        vec![(0, n as i32 - 1, "a".repeat(strand_len)); s],
+    )
+}
+
+impl Shrink for DnaHealth {
+    /// Drop strands, drop genes while mapping the strand ranges onto the genes
+    /// left, then shorten single strands and genes
+    fn shrink((genes, health, strands): &Self::Input) -> Vec<Self::Input> {
+        let mut candidates = Vec::new();
+
+        for strands in shrink::removals(strands) {
+            if !strands.is_empty() {
+                candidates.push((genes.clone(), health.clone(), strands));
+            }
+        }
+
+        for removed in shrink::removal_ranges(genes.len()) {
+            let strands = strands
+                .iter()
+                .filter_map(|(first, last, dna)| {
+                    let (first, last) = (*first as usize, *last as usize);
+                    let first = match first {
+                        first if first < removed.start => first,
+                        first if first < removed.end => removed.start,
+                        first => first - removed.len(),
+                    };
+                    let last = match last {
+                        last if last < removed.start => last,
+                        last if last < removed.end => removed.start.checked_sub(1)?,
+                        last => last - removed.len(),
+                    };
+
+                    (first <= last).then(|| (first as i32, last as i32, dna.clone()))
+                })
+                .collect::<Vec<_>>();
+
+            if !strands.is_empty() {
+                let genes = [&genes[..removed.start], &genes[removed.end..]].concat();
+                let health = [&health[..removed.start], &health[removed.end..]].concat();
+
+                candidates.push((genes, health, strands));
+            }
+        }
+
+        for (index, (first, last, dna)) in strands.iter().enumerate() {
+            for dna in shorter(dna) {
+                let mut strands = strands.clone();
+
+                strands[index] = (*first, *last, dna);
+                candidates.push((genes.clone(), health.clone(), strands));
+            }
+        }
+
+        for (index, gene) in genes.iter().enumerate() {
+            for gene in shorter(gene) {
+                let mut genes = genes.clone();
+
+                genes[index] = gene;
+                candidates.push((genes, health.clone(), strands.clone()));
+            }
+        }
+
+        candidates
+    }
+}
+
+/// Non empty strings made of `s` with some of its characters cut out
+fn shorter(s: &str) -> Vec<String> {
+    shrink::removals(&s.chars().collect::<Vec<_>>())
+        .into_iter()
+        .filter(|chars| !chars.is_empty())
+        .map(|chars| chars.into_iter().collect())
+        .collect()
+}
+
+impl Reference for DnaHealth {
+    fn reference((genes, health, strands): Self::Input) -> Result<Self::Output> {
+        Ok(dna_health_naive(genes, health, strands))
+    }
+}
+
+impl Solutions for DnaHealth {
+    /// O(n + m × k + z), linear in the input and the matches
+    const COMPLEXITY: Complexity = Complexity::Linear;
+
+    /// Length of the input plus the z occurrences of genes in the strands,
+    /// each duplicate gene counting on its own as the search visits them all.
+    /// They are counted by searching every strand with a health of 1 for
+    /// every gene.
+    fn measure(input: &Self::Input) -> usize {
+        let (genes, _, strands) = input;
+        let mut counter = AhoCorasick::new();
+
+        for (index, gene) in genes.iter().enumerate() {
+            counter.add_pattern(gene, index, 1);
+        }
+
+        counter.build_failure_links();
+
+        let occurrences = strands
+            .iter()
+            .map(|(_, _, dna)| counter.search(dna, 0, genes.len()) as usize)
+            .sum::<usize>();
+
+        Self::write(input).len() + occurrences
+    }
+
+    fn solutions() -> Vec<(&'static str, Solution<Self>)> {
+        vec![("optimized", Self::solve), ("reference", Self::reference)]
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fixture;
 
     #[test]
     fn dna_health_01() {
@@ -567,7 +861,7 @@ mod tests {
             ],
         );
 
-        assert_eq!(result, "3218660 11137051");
+        assert_eq!(result, (3218660, 11137051));
     }
 
     #[test]
@@ -579,7 +873,7 @@ mod tests {
         );
         // "aaaa" contains: "a" (4 times), "aa" (3 times), "aaa" (2 times)
         // Total: 4*1 + 3*2 + 2*3 = 4 + 6 + 6 = 16
-        assert_eq!(result, "16 16");
+        assert_eq!(result, (16, 16));
     }
 
     #[test]
@@ -589,7 +883,7 @@ mod tests {
             vec![10, 20],
             vec![(0, 1, "xyz".to_string())],
         );
-        assert_eq!(result, "0 0");
+        assert_eq!(result, (0, 0));
     }
 
     #[test]
@@ -602,13 +896,11 @@ mod tests {
                 (1, 2, "abc".to_string()), // genes "b" and "c" (health 2+3=5)
             ],
         );
-        assert_eq!(result, "1 5");
+        assert_eq!(result, (1, 5));
     }
 
     #[test]
     fn dna_health_05() {
-        use std::time::Instant;
-
         // Create a larger test case with many genes and longer DNA strands
         let mut genes = Vec::new();
         let mut health = Vec::new();
@@ -636,31 +928,11 @@ mod tests {
             (0, 20, "atgctagcatgctagc".repeat(2000)),
         ];
 
-        // Test Aho-Corasick implementation
-        let start = Instant::now();
+        // Timings of both live in `hackerank bench dna_health`
         let result_ac = dna_health(genes.clone(), health.clone(), strands.clone());
-        let duration_ac = start.elapsed();
-
-        // Test naive implementation
-        let start = Instant::now();
         let result_naive = dna_health_naive(genes, health, strands);
-        let duration_naive = start.elapsed();
 
-        // Results should be identical
         assert_eq!(result_ac, result_naive);
-
-        println!("Aho-Corasick: {:?}", duration_ac);
-        println!("Naive approach: {:?}", duration_naive);
-
-        if duration_ac.as_nanos() > 0 {
-            println!(
-                "Performance improvement: {:.2}x",
-                duration_naive.as_nanos() as f64 / duration_ac.as_nanos() as f64
-            );
-        }
-
-        // For large inputs, Aho-Corasick should significantly outperform naive approach
-        // The advantage becomes more pronounced with more patterns and longer text
     }
 
     #[test]
@@ -680,23 +952,13 @@ mod tests {
         // "shers" contains: "he" (1 match), "she" (1 match), "hers" (1 match)
         // Total: 1*1 + 1*2 + 1*4 = 1 + 2 + 4 = 7
         // The buggy implementation would miss some matches due to incorrect failure links
-        assert_eq!(result, "7 7");
+        assert_eq!(result, (7, 7));
     }
 
     #[test]
     fn dna_health_07() {
-        // Test with the input file that demonstrates the bug
-        let result = parse_and_run_dna_health("src/algorithm/dna_health_01.txt");
-        match result {
-            Ok(output) => {
-                println!("DNA Health result: {}", output);
-                // You can add assertions here if you know the expected output
-            }
-            Err(e) => {
-                println!("Error reading file: {}", e);
-                // The test will pass even if file doesn't exist, for flexibility
-            }
-        }
+        let report = fixture::run(&DnaHealth).unwrap();
+        assert!(report.passed(), "{}", report);
     }
 
     #[test]
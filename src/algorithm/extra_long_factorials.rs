@@ -1,3 +1,13 @@
+use crate::{
+    bench::Solutions,
+    complexity::Complexity,
+    error::Result,
+    generate::{Generate, Rng, Shape},
+    meta::{Constraint, Difficulty, Meta},
+    problem::Problem,
+    scanner::Scanner,
+};
+
 fn extra_long_factorials(n: i32) -> String {
     if n == 0 || n == 1 {
         return n.to_string();
@@ -28,6 +38,62 @@ fn extra_long_factorials(n: i32) -> String {
         .collect::<String>()
 }
 
+pub struct ExtraLongFactorials;
+
+impl Problem for ExtraLongFactorials {
+    type Input = i32;
+    type Output = String;
+
+    const SLUG: &'static str = "extra_long_factorials";
+
+    const META: Meta = Meta {
+        title: "Extra Long Factorials",
+        url: "https://www.hackerrank.com/challenges/extra-long-factorials/problem",
+        domain: "implementation",
+        difficulty: Difficulty::Medium,
+        tags: &["big integer", "math"],
+        constraints: &[Constraint::new("n", 1, 100)],
+    };
+
+    fn parse(input: &[u8]) -> Result<Self::Input> {
+        Ok(Scanner::new(input).next::<i32>()?)
+    }
+
+    fn values(n: &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![("n", *n as i64)]
+    }
+
+    fn solve(input: Self::Input) -> Result<Self::Output> {
+        Ok(extra_long_factorials(input))
+    }
+}
+
+impl Generate for ExtraLongFactorials {
+    const MAX_SIZE: usize = 100;
+
+    /// Any n up to `size`, or `size` itself for the other shapes
+    fn generate(rng: &mut Rng, size: usize, shape: Shape) -> Self::Input {
+        match shape {
+            Shape::Random => rng.size(1..=size) as i32,
+            Shape::Uniform | Shape::Worst => size as i32,
+        }
+    }
+
+    fn write(input: &Self::Input) -> String {
+        format!("{}\n", input)
+    }
+}
+
+impl Solutions for ExtraLongFactorials {
+    /// Each of the n multiplications goes over all digits so far
+    const COMPLEXITY: Complexity = Complexity::Quadratic;
+
+    /// The input is n alone, whose value rather than length sets the work
+    fn measure(n: &Self::Input) -> usize {
+        *n as usize
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1,3 +1,13 @@
+use crate::{
+    bench::Solutions,
+    complexity::Complexity,
+    error::{HackerankError, Result},
+    generate::{self, Generate, Rng, Shape},
+    meta::{Constraint, Difficulty, Meta},
+    problem::Problem,
+    scanner::Scanner,
+};
+
 fn forming_magic_square(s: &[Vec<i32>]) -> i32 {
     // All possible 3x3 magic squares (8 variations through rotations and reflections)
     let magic_squares = vec![
@@ -29,3 +39,74 @@ fn forming_magic_square(s: &[Vec<i32>]) -> i32 {
 
     min_cost
 }
+
+pub struct FormingMagicSquare;
+
+impl Problem for FormingMagicSquare {
+    type Input = Vec<Vec<i32>>;
+    type Output = i32;
+
+    const SLUG: &'static str = "forming_magic_square";
+
+    const META: Meta = Meta {
+        title: "Forming a Magic Square",
+        url: "https://www.hackerrank.com/challenges/magic-square-forming/problem",
+        domain: "implementation",
+        difficulty: Difficulty::Medium,
+        tags: &["brute force", "math"],
+        constraints: &[Constraint::new("s[i][j]", 1, 9)],
+    };
+
+    fn parse(input: &[u8]) -> Result<Self::Input> {
+        Ok(Scanner::new(input).matrix::<i32>(3, 3)?)
+    }
+
+    fn values(s: &Self::Input) -> Vec<(&'static str, i64)> {
+        s.iter()
+            .flatten()
+            .map(|&value| ("s[i][j]", value as i64))
+            .collect()
+    }
+
+    fn check(s: &Self::Input) -> Result<()> {
+        match s.len() == 3 && s.iter().all(|row| row.len() == 3) {
+            true => Ok(()),
+            false => Err(HackerankError::constraint("s", "expected a 3x3 matrix")),
+        }
+    }
+
+    fn solve(input: Self::Input) -> Result<Self::Output> {
+        Ok(forming_magic_square(&input))
+    }
+}
+
+impl Generate for FormingMagicSquare {
+    /// The square is always 3x3
+    const MAX_SIZE: usize = 1;
+
+    /// Digits from 1 to 9. Worst is all 9s, as far from every magic square as
+    /// a square gets.
+    fn generate(rng: &mut Rng, _: usize, shape: Shape) -> Self::Input {
+        let digit = rng.int(1..=9) as i32;
+
+        (0..3)
+            .map(|_| {
+                (0..3)
+                    .map(|_| match shape {
+                        Shape::Random => rng.int(1..=9) as i32,
+                        Shape::Uniform => digit,
+                        Shape::Worst => 9,
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn write(input: &Self::Input) -> String {
+        input.iter().map(|row| generate::join(row) + "\n").collect()
+    }
+}
+
+impl Solutions for FormingMagicSquare {
+    const COMPLEXITY: Complexity = Complexity::Constant;
+}
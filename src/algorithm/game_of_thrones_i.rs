@@ -1,4 +1,14 @@
-fn game_of_thrones(s: &str) -> String {
+use crate::{
+    bench::Solutions,
+    complexity::Complexity,
+    error::Result,
+    generate::{Generate, LOWERCASE, Rng, Shape},
+    meta::{self, Constraint, Difficulty, Meta},
+    problem::Problem,
+    scanner::Scanner,
+};
+
+fn game_of_thrones(s: &str) -> bool {
     let mut chars = s.chars().collect::<Vec<_>>();
 
     chars.sort();
@@ -32,11 +42,72 @@ fn game_of_thrones(s: &str) -> String {
         }
     }
 
-    match odds {
-        0 | 1 => "YES",
-        _ => "NO",
+    odds <= 1
+}
+
+pub struct GameOfThrones;
+
+impl Problem for GameOfThrones {
+    type Input = String;
+    type Output = bool;
+
+    const SLUG: &'static str = "game_of_thrones_i";
+
+    const META: Meta = Meta {
+        title: "Game of Thrones - I",
+        url: "https://www.hackerrank.com/challenges/game-of-thrones/problem",
+        domain: "strings",
+        difficulty: Difficulty::Easy,
+        tags: &["strings", "counting", "palindromes"],
+        constraints: &[Constraint::new("|s|", 1, 100_000)],
+    };
+
+    fn parse(input: &[u8]) -> Result<Self::Input> {
+        Ok(Scanner::new(input).line()?.to_string())
+    }
+
+    fn values(s: &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![("|s|", s.len() as i64)]
+    }
+
+    fn check(s: &Self::Input) -> Result<()> {
+        meta::lowercase(s)
+    }
+
+    fn solve(input: Self::Input) -> Result<Self::Output> {
+        Ok(game_of_thrones(&input))
     }
-    .to_string()
+}
+
+impl Generate for GameOfThrones {
+    const MAX_SIZE: usize = 100_000;
+
+    /// Up to `size` letters. Worst strings are shuffled palindromes, so every
+    /// letter has to be counted before answering yes.
+    fn generate(rng: &mut Rng, size: usize, shape: Shape) -> Self::Input {
+        let len = rng.size(1..=size);
+
+        match shape {
+            Shape::Random => rng.string(len, LOWERCASE),
+            Shape::Uniform => "a".repeat(len),
+            Shape::Worst => {
+                let half = rng.string(len / 2, LOWERCASE);
+                let middle = rng.string(len % 2, LOWERCASE);
+                let mut chars = (half.clone() + &middle + &half).into_bytes();
+
+                rng.shuffle(&mut chars);
+                chars.into_iter().map(char::from).collect()
+            }
+        }
+    }
+
+    fn write(input: &Self::Input) -> String {
+        format!("{}\n", input)
+    }
+}
+
+impl Solutions for GameOfThrones {
+    const COMPLEXITY: Complexity = Complexity::Linear;
 }
 
 #[cfg(test)]
@@ -46,18 +117,18 @@ mod tests {
     #[test]
     fn game_of_thrones_01() {
         let result = game_of_thrones("aaabbbb");
-        assert_eq!(result, "YES")
+        assert!(result)
     }
 
     #[test]
     fn game_of_thrones_02() {
         let result = game_of_thrones("cdefghmnopqrstuvw");
-        assert_eq!(result, "NO")
+        assert!(!result)
     }
 
     #[test]
     fn game_of_thrones_03() {
         let result = game_of_thrones("cdcdcdcdeeeef");
-        assert_eq!(result, "YES")
+        assert!(result)
     }
 }
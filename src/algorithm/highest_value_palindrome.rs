@@ -1,23 +1,125 @@
-use std::{fs::read_to_string, io::Result};
+use std::{fmt, sync::LazyLock};
+
+use crate::{
+    bench::Solutions,
+    complexity::Complexity,
+    error::{HackerankError, Result},
+    explain::explain,
+    generate::{self, Generate, Rng, Shape},
+    layout::{Fields, Layout, Reader, Source},
+    meta::{self, Constraint, Difficulty, Meta},
+    problem::Problem,
+    scanner::Scanner,
+};
+
+/// Steps `hackerank explain` shows
+enum Step {
+    Matched {
+        left: usize,
+        right: usize,
+        digits: (u8, u8),
+    },
+    TooFewChanges {
+        missing: i32,
+    },
+    Upgraded {
+        left: usize,
+        right: usize,
+        cost: i32,
+        remaining: i32,
+    },
+    Middle {
+        position: usize,
+    },
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Matched {
+                left,
+                right,
+                digits: (a, b),
+            } => write!(
+                f,
+                "phase 1: digits {} and {} differ ({} and {}), both become {}",
+                left,
+                right,
+                a,
+                b,
+                a.max(b)
+            ),
+            Step::TooFewChanges { missing } => write!(
+                f,
+                "phase 1: {} more change(s) needed to make a palindrome",
+                missing
+            ),
+            Step::Upgraded {
+                left,
+                right,
+                cost,
+                remaining,
+            } => write!(
+                f,
+                "phase 2: digits {} and {} become 9 for {} change(s), {} left",
+                left, right, cost, remaining
+            ),
+            Step::Middle { position } => {
+                write!(
+                    f,
+                    "the middle digit {} becomes 9 with a change left",
+                    position
+                )
+            }
+        }
+    }
+}
+
+/// Largest palindrome reachable with at most `k` digit changes, `None` when
+/// `k` is too small to make `s` a palindrome at all
+fn highest_value_palindrome(s: &str, n: i32, k: i32) -> Result<Option<String>> {
+    if n < 0 || n as usize != s.len() {
+        return Err(HackerankError::constraint(
+            "n",
+            format!("n is {} but the string has {} digits", n, s.len()),
+        ));
+    }
 
-fn highest_value_palindrome(s: &str, n: i32, k: i32) -> String {
     let n = n as usize;
     let mut k = k;
 
     // Convert to mutable array of digits for efficient manipulation
-    let mut digits: Vec<u8> = s.chars().map(|c| c.to_digit(10).unwrap() as u8).collect();
-
-    // Track positions that need changes to become palindrome
-    let mut mismatch_positions = Vec::new();
+    let mut digits = s
+        .chars()
+        .enumerate()
+        .map(|(position, c)| match c.to_digit(10) {
+            Some(digit) => Ok(digit as u8),
+            None => Err(HackerankError::InvalidChar {
+                found: c,
+                position,
+                expected: "a digit",
+            }),
+        })
+        .collect::<Result<Vec<_>>>()?;
 
     // Phase 1: Identify mismatches and calculate minimum changes needed
     let half = n / 2;
-    for i in 0..half {
+
+    // Track positions that need changes to become palindrome
+    let mut mismatched = vec![false; half];
+
+    for (i, mismatch) in mismatched.iter_mut().enumerate() {
         let left = i;
         let right = n - 1 - i;
 
         if digits[left] != digits[right] {
-            mismatch_positions.push(i);
+            *mismatch = true;
+
+            explain!(Step::Matched {
+                left,
+                right,
+                digits: (digits[left], digits[right]),
+            });
 
             // Make palindrome by choosing the larger digit
             let max_digit = digits[left].max(digits[right]);
@@ -27,18 +129,20 @@ fn highest_value_palindrome(s: &str, n: i32, k: i32) -> String {
         }
     }
 
-    // If we don't have enough operations to fix basic mismatches, return -1
+    // Not enough operations to fix the basic mismatches
     if k < 0 {
-        return "-1".to_string();
+        explain!(Step::TooFewChanges { missing: -k });
+
+        return Ok(None);
     }
 
     // Phase 2: Maximize value with remaining operations
-    for i in 0..half {
+    for (i, &mismatch) in mismatched.iter().enumerate() {
         let left = i;
         let right = n - 1 - i;
 
         if digits[left] < 9 {
-            let cost = if mismatch_positions.contains(&i) {
+            let cost = if mismatch {
                 // This position was already changed once, so only 1 more operation needed
                 1
             } else {
@@ -50,6 +154,13 @@ fn highest_value_palindrome(s: &str, n: i32, k: i32) -> String {
                 digits[left] = 9;
                 digits[right] = 9;
                 k -= cost;
+
+                explain!(Step::Upgraded {
+                    left,
+                    right,
+                    cost,
+                    remaining: k,
+                });
             }
         }
     }
@@ -58,106 +169,172 @@ fn highest_value_palindrome(s: &str, n: i32, k: i32) -> String {
     if n % 2 == 1 && k > 0 {
         let mid = n / 2;
         digits[mid] = 9;
+
+        explain!(Step::Middle { position: mid });
     }
 
     // Convert back to string
-    digits
-        .iter()
-        .map(|&d| char::from_digit(d as u32, 10).unwrap())
-        .collect()
+    Ok(Some(digits.iter().map(|&d| (b'0' + d) as char).collect()))
+}
+
+/// Characters of the strings
+const DIGITS: &[u8] = b"0123456789";
+
+/// Number of digits and of changes allowed, then the digits
+static LAYOUT: LazyLock<Layout> = LazyLock::new(|| Layout::new("n:i32; k:i32; s:String"));
+
+/// Fields of [`LAYOUT`] holding `input`
+fn fields((s, n, k): &<HighestValuePalindrome as Problem>::Input) -> Fields<'_> {
+    Fields::default().with("n", n).with("k", k).with("s", s)
+}
+
+/// Input held by the fields of [`LAYOUT`] `reader` hands out
+fn read(mut reader: Reader<impl Source>) -> Result<<HighestValuePalindrome as Problem>::Input> {
+    let n = reader.field("n")?;
+    let k = reader.field("k")?;
+
+    Ok((reader.field("s")?, n, k))
+}
+
+pub struct HighestValuePalindrome;
+
+impl Problem for HighestValuePalindrome {
+    type Input = (String, i32, i32);
+    type Output = Option<String>;
+
+    const SLUG: &'static str = "highest_value_palindrome";
+
+    const META: Meta = Meta {
+        title: "Highest Value Palindrome",
+        url: "https://www.hackerrank.com/challenges/richie-rich/problem",
+        domain: "strings",
+        difficulty: Difficulty::Medium,
+        tags: &["strings", "greedy", "palindromes"],
+        constraints: &[
+            Constraint::new("n", 1, 100_000),
+            Constraint::new("k", 0, 100_000),
+        ],
+    };
+
+    fn parse(input: &[u8]) -> Result<Self::Input> {
+        read(LAYOUT.reader(Scanner::new(input)))
+    }
+
+    fn values(input: &Self::Input) -> Vec<(&'static str, i64)> {
+        LAYOUT.values(&fields(input), Self::META.constraints)
+    }
+
+    fn check((s, n, _): &Self::Input) -> Result<()> {
+        if *n as usize != s.len() {
+            return Err(HackerankError::constraint(
+                "n",
+                format!("n is {} but the string has {} digits", n, s.len()),
+            ));
+        }
+
+        meta::chars(s, |c| c.is_ascii_digit(), "a digit")
+    }
+
+    fn solve((s, n, k): Self::Input) -> Result<Self::Output> {
+        highest_value_palindrome(&s, n, k)
+    }
 }
 
-fn parse_and_run(file_path: &str) -> Result<String> {
-    let content = read_to_string(file_path)?;
-    let mut lines = content.lines();
+impl Generate for HighestValuePalindrome {
+    const MAX_SIZE: usize = 100_000;
 
-    let first_multiple_input: Vec<String> = lines
-        .next()
-        .unwrap()
-        .split(' ')
-        .map(|s| s.to_string())
-        .collect();
+    /// Up to `size` digits laid out by [`LAYOUT`], with up to as many changes
+    /// as digits. Uniform strings repeat one digit, worst ones mismatch on
+    /// every pair with exactly enough changes to fix them.
+    fn generate(rng: &mut Rng, size: usize, shape: Shape) -> Self::Input {
+        let digit = rng.string(1, DIGITS);
+        let alphabet = match shape {
+            Shape::Random => DIGITS,
+            Shape::Uniform => digit.as_bytes(),
+            Shape::Worst => {
+                let n = rng.size(1..=size);
 
-    let n = first_multiple_input[0].trim().parse::<i32>().unwrap();
-    let k = first_multiple_input[1].trim().parse::<i32>().unwrap();
-    let s = lines.next().unwrap();
+                return (
+                    "1".repeat(n / 2) + &"2".repeat(n - n / 2),
+                    n as i32,
+                    n as i32 / 2,
+                );
+            }
+        };
+        let constraints = [
+            Constraint::new("k", 0, size as i64),
+            Constraint::new("|s|", 1, size as i64),
+        ];
+        let reader = generate::layout(&LAYOUT, rng, size, &constraints, alphabet);
+        let (s, _, k) = read(reader).expect("random fields fit the layout");
+        let n = s.len() as i32;
+
+        // The count of digits is the length of the string, changes go up to it
+        (s, n, k % (n + 1))
+    }
 
-    // Run the analysis
-    let result = highest_value_palindrome(s, n, k);
-    Ok(result)
+    fn write(input: &Self::Input) -> String {
+        LAYOUT.write(&fields(input))
+    }
+}
+
+impl Solutions for HighestValuePalindrome {
+    const COMPLEXITY: Complexity = Complexity::Linear;
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::fixture;
 
     #[test]
     fn test_highest_value_palindrome_01() {
-        let result = highest_value_palindrome("3943", 4, 1);
-        assert_eq!(result, "3993");
+        let result = highest_value_palindrome("3943", 4, 1).unwrap();
+        assert_eq!(result.as_deref(), Some("3993"));
     }
 
     #[test]
     fn test_highest_value_palindrome_02() {
-        let result = highest_value_palindrome("092282", 6, 3);
-        assert_eq!(result, "992299");
+        let result = highest_value_palindrome("092282", 6, 3).unwrap();
+        assert_eq!(result.as_deref(), Some("992299"));
     }
 
     #[test]
     fn test_highest_value_palindrome_03() {
-        let result = highest_value_palindrome("0011", 4, 1);
-        assert_eq!(result, "-1");
+        let result = highest_value_palindrome("0011", 4, 1).unwrap();
+        assert_eq!(result, None);
     }
 
     #[test]
     fn test_highest_value_palindrome_04() {
-        let result = highest_value_palindrome("777", 3, 0);
-        assert_eq!(result, "777");
+        let result = highest_value_palindrome("777", 3, 0).unwrap();
+        assert_eq!(result.as_deref(), Some("777"));
     }
 
     #[test]
     fn test_highest_value_palindrome_05() {
-        // Test with the input file that demonstrates the bug
-        let result = parse_and_run("src/algorithm/highest_value_palindrome_01.txt");
-        match result {
-            Ok(output) => {
-                assert_eq!(output, "999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999993678548974454565987676784893355896623239572589357873368965806573726479687889772761333920546896791852388408977878254379526472815743726731859932896158341745897224593452595268482764587797447339942546432728788968177669729878399589596792877625798946574897772562978080654954398694477288968503686395965793851489991279798562565781165916998388679837797975469556745462199467674769428296855498924888544934558797666887253755697886539972567735627529896658365473732989078858898848989597862928987778786999172683439568976157974577638956969674893779118797856487598785948837798313798099157539838739622344664574529596999694295184657369986880984499173992927964146866338388639558395588429434122972783874917773738945783075789688989754649789667748294446778944550947579499143624549298998859936688872465767585924375096867979568781991889399837596179296978269679348985659693699596767588474489460873696332898389797862681667969918559178494754137550547699198839776789979676698257774516619956291589885885688977979397238696397949992369968577878723453998639764684859874677686694980444596975577598166748948929384794799998777365998587884592783499567899497873758545796335689083373856974743745369999987484852767894482859686296437839498534473277977698478499864075957887969796395959974897307566659678589286482573949691255989657757746250947668336987466727699989884982930541956339444876733958942997737498597657759766889717277511838896775795678874977888387878697547557676954934879584592298977972596888933674887488731489982858749458729994679483049576397459239867263789455890249949766937871868785822874739599549597376729299934527846989852596792379812995985886935966633295752560847989899675978123746494582593647672666595879935556827786594984579974579757495877989169886276752863787993797968893329577859634767184462039711568887788399459099997776468929285573784794442638648864637949534824587864884736922869898938639520382473657778568697724787653278495367699467176645774354747595463389149668345356556377987986497754931655851689853930999997693875829877366093295683584373649487415885635849437663586088839388767199639287359694760357571899966448574843992479586872986899639774378573379785386276258689663409448693897664867733781797149573197258684595560736546655593437738467279925484899763999931874999739791712462785885937638639228496733796936167778977467786478664898738479767577889468891099216362617353384896775785439569999569848984589889957573691288678691437995983367962649656249966578367665626995858887954374228516976973988766455639568540268455661736989799865816737846657365778597639934375390953797385897683245879744805568267868983787554284816696259484349741833648465589535964896617997879689338256526969797872899895958874752689184577256737835578936598691832467755967825858719893596958970797726838826646824890571779648157855317984357677636999555693670777937552382356629839375591636993783969936897167892896869956652847708984536896265597987978577356267242559699586376494247522628789769368787985593781745499617877888894667989784643825889694795656525978427689727448376641888790253278994899898446898628698978975449848238875993954983996376975959976927388739286789966755898934169537825369983838979839882779784384997987468557729856779779264856368241856484878667788997751875964955582639970429857777878767891878563229773198436596690798623387727549968778827478956897915373559978319717499557566977498737178755196648617458377656693467699849535484764739957479485776176729896872936736338453777856656778957982975537873386176397936237863925689967575661599573863988659749995879986662636794893769878257395984538799799955435536585599737396897878714679959397687589539647894579447189553935593297762838787499764988581794193758969183786589575574658586821735827899167558338599764273743298687567574537988882959296392343698754799378978408197776323592903889847758958952469969757637127784975476367559982482648069399556846977749663245674691948599626993689472839622768599776639769649599987499731936638799189958995988236169457798769585683789699846846494859249857533655754974372572934275277979595652797318685373779659896959578789867855907366586465799957698573966468993392517679436677378478769778857892957542973846763478891457741798175947556798698663689969675975653355717938983776689758988889433671479232594769895992889193609967506682353997865928053797978356833989591998754849636979496879879358974989785566278799954768553844878923972659956789579987615464746876575774511848968357127483979553496895789742148969379889864913747554386746958529688987376819239644712785876878748577728398676398687579746266799628888662679876806558426862297998867957289946413595232977716656363459924777850564779379846046598575118559569177078529934977458834683798662378559559976218577844575675569768997815848369558477669869846664703969298353458233544562569765929698896268799768556955389169688587997874475969598778829455823989759785607978398885262878455959796936219995683378489763945460892826989656882319546780998656476987976663888881699996119299598698689829943898760827813698375218699496795737837979968366199769712832396697958253497992628969599595955743937846538984984988499679678549682177286967636929857895948886998875877566366685949567376729779228482739986678982839728638586465146555707645963897878889167581299245987397993264903682468693745964379947699987029191888987677555749796855632977682689409456374993487357784598567529988693763785743871785849798458864725154779696496597477765234944888499469762876256399672599846832886315964869689728639995998817085697948494755214939619788694187592574761599847757955739917947069146375788645929569636897933997448659698868799598158214596692278626715499785996197854763967891774688448959958996958746848579739956572478936888857645569894667751729618368549750366699068688943795878578555579455996167447387669757957088690945965739129497758766879956587306959676673857487889993169789953498844865469496648694167859879908957675869786398749863898508386098566838838999959784975996157578859988663347498989949422479577968799375946478563679679969513487746369448694899477988334255244254695557295022833169958959958577598949291978893688869959998672718476859768948958999791698796657194967359979564567896749769139373913086776976959864899779973155988977386979758896566388988498684297875569684416898588743754646482395849447267974834069966385991645474788799752592915873976756299174263943188159916998786994999962119423875776294965387589339931469246257694792385997587172372919389829717982749987779582889289214868645799949857469359994687748575957379567708789694586599661638745184984438669737477967539978925881475788894241275953668939935990489969667780919555389933887986587239488473176288879266918975884666228693666592837274554583138726373697983849899678488879912997767973463978615467742459253298258998773787989775887824666876825881396997644994137847287243434548895865458387477786922819489838549697467768366773938997696768581999723975688899457889836494436661486679899394735876563983695296578329495566666287888899896795985975864636768884946885726498999889876869880577978782433173477491796547975585587226196693474369795976989695499889274788879796987378423865483686689898992289678499649785984793436524897579767966998489864849986837598888695478564984195892897767473952793999574645634397699353226378998266987662812847989992885918998848898993886177916986996969794469558877788188869971628959115985977885578885987989774358461468994676713893698949878588498885998792759989338968575558537428678975978865529785414394284956272735494596789638888675953768237670945748887197867945953538893538979295826616886792425366224573925475790368133482953737959698562993667968832674848497674537715927898693988279862846564666634847947656647039878536872273778347448482558947768285949978459958941968733801779418966694488453338598943995672939889472582785695075593577576649978988579789717563138565567254748386445785795977889595485636957345775994784569367899957789968699958897749997057176439585832757828757439397965486999940748779825516969238559584917694749577459332463716413487950227649569762117428783708896187894347665598275468798687748876562656348588538289788758865479695813692676748566587415865923934254359888799682779922783893899285658737663856873593563286694822664697739857592644779134477795999484287566578849988995716611649235119895867156785996836877476866767599537393932998691848886955991642889655893698584259487559683864538975348599576569466594496599503268755777436993888688845648833456698794538155595949974993659538443776859997849375618944448817374294598774482868645849743969252683798387996963976936716880876586897557669567997978844489373849966599554454755552584735986968589361586799694869347534748219696676949789399982698897687698981888695559957844656894214198666489668599961866788875369197879878888948093997927899898559899827797558789698249878337765732795619965928768749315823612388627966689887873989637288877498566843238698586878997880875919658763738456897956869766379876648919897978947985985998738895729659549838861769953596579691822774899778279987676692278771531183792688765745945598960396828333397797574486798978617586899759777355594185758778744773628796396842995217288657499975869899593896588794469779598319538569883976656679943479799948697964558476843835544842657318732945679873776953625878399299497977887976585474259586995084895597273581892895736937846639865742757629298898738742707493559745642834567883964512699447685398695594399595988985600775365549777195259385643685179568838557566482429846685969795898948325939696697698608892986943438887699738759795775897966696384975941676999980974544695075338817788579852147677686998949993969909839363842791798857758824791824396763737869896929619859567796292845686080712385624789363689886382594980636958689469896958457817969328589527595684964460781628815686877761459878685892395665759488658447949958456595773549782899247955632728473196476986985904549736929892569668559937571298587228844747287465968499956924837887776343729886829466769859367977858965893228196673863087986888784875999943666833457793157517799629978891398595898497877656979535967886983637989764215954362908174958368748584899335233384998915445847758704877799869775787968239993761893939431827595585987954866535229366292866988850185688289888236429487566956489548592576684676755999579773758571046904825955846494764278359755984883376599779438676581986635774976598789325749769879898465972784898427295866732245373179668966574875561369792867657855789335699384938269328686564869789589378479899974786863859605907826348875875599318879645348884858588539697985893285799993488877594775833378489209978859889878869798456698888998436197786656287474689858746991789344656894689582845465192796904788489885948977084779437386289999677785795999845448977618457685686977396589688745756697277495642749649856811438328545583438296896794987729877848755723493598787594558899590739427669198601854967776448736447879998934977284866872773685995864888547467922679978635951778988596769586458588733753867697586688497738669779919578367398999687447582568909795797996838871398797239997059847293757594999436369946254959547888879399286979888734926298806236969598687978410659877860942689969791454379698676575356438888984934276927882559366819999765149343497544859168876496774486495438644478699685995964159589397546419466258367491987676408153883048966769529685689994976657476969875580587269854408428544839886937478987787998636577888869425876885549713927575849716648865954881975857686669828774464758988768297722558995582577546289399958791889489676998579868698608369978749398479948969946886577085565929227688647787373897987898379767778556597271946796889587597659963547513331846198978696382984853888033288962999679975998898878576895538858384954528815999446831895978676178762859665558982963239742998687985879284986626815798373899899846988666568968283795267357489652766895439837763435981192714589824878999978837758646798197657874714396248788493493697698589240843999778497936659598581989717688776962879558947369688897298488671586577694457868496649559988890864118955969147123498788515998479749863998269924775744249996698779699869673573967778693346985733729995538893628646547893592474877888278958766874648974294894679599239141885688593987588548885313499434888576775896559327886195589634557986238794726739774755738675985882639789950894984486197217748878978897836788788754935895087588789388668807662595116988393854893097618365997887776674709676849499377894774666779528199767999797793558558983246844985580974397748296979895346874449526775596923867929525276985874877698566445377986989533793499881788606557158959485273686357545678261848156847642876146143946189969697999998762588189927775994589673656769782494878879347623495997354884321958819826897348775489477876158679895333981118957797486348391999995855778584948791810924735933889971678959489499997936796673468665998948768159673889357875596068988674237362184329277848367599748649596877389396756577477284747437925647495765893385768633258594666477569999964589712869966983377762956298866473986466682876087824376498348564748518644594785843444921588745889595147598999499694232476985456388956749995978882778276946707694987959956694845834975796716995889245856998477787277915664534297738689789656329551727672665568794788977873791834895254173483196593552805648659095789798236624360263786856855917826369428637788462240634447793887928697388498559623889239961778762699739668749578967288792677071383646588252677139428995467276997978991757698478979685976357407698868562597856677789686215749934548446125152976999658699958488095494885926599968585869899498358614938878894378686366676768354747470987666747326588118867297469977937558677459399866487597673986706877789577287955858478595277657994919867832999977966858364369986297399476197885279925874067997768892758649694387794723585619891927598688762444993866715852854996975867813386997378999728459653706279917752794446478732998936393337889644787896779986298784874879886598997777886379742775999596695207858624237574395968813359877596964638747433945723767889558499578583774043994476969689658437935388889925538473791963554459575796875899896642369282675788971389887299696851978368695735447249897698122696997873643989656279467897998215739899406749587569915365816867743385224255987425796992108598545559786646964482987986985833249259658739253583797272562799653957377946868986847874968999789726734147389425959657346345924289461489964788352379570775897476795658989476942689888794846255646764919870886779935415877998486368499786755278729152869877667963877889676899785878316493672511893772972879483859635797999989787786875999465439779378685386955515679678882783071769115758794182849976684764594828838589676749849876583989887865883747348791787498965694949168795558891629996978827649728618255992586714649735998286313445958585565928498049687187469865756378855794874562684673489832632997737557873982376977485722766598697874969499577865242475578897959643649878403483778187062847996842399995848689986518367666955444526048434697465922096923377458568926459917427634554869598684689681475794783549629765783885876591929194666578745994893558354827874933838989776957932443698667884397646919929861554595933797899889347198546526188799551174777888997877785185889296598382258775866376939347569597994955981449376458795888776793962485677769874993884977279966682969935459784998865717442499357889566549752868529618862389996858996165458322493646295987236869669887997822884687449534997316858579588844955499685691127993987977986983978968887677784839138828443764348823689799647675177729864678768538392962875549624727376555794222736687883043395582547996982873999838578458664397873485679462459797266799577566757537344558952959888573777587969757549377355886157183915988465795153529698979982459693848594943234958694773458886698296586879424764292265476721955239985889855983797977888399777886787285822999853896499680799694446694998822384896742399635929869986577846874564398767847765150869763401868997782794637948489978406988857767666779887599487799756463388992774559453649898804957578759749877488989347639783892999387043923399788295989958195914944592778834897578979408593969786879995557699989198689455488649775695663999526686998886363575646895408168278962899779279956371766951249359828679337559695646988487779145593748655565753597169544488236665778935739966842987838391435966188894499976235451878749342787796389664546665367858759654776987458813733997992788793136694355886427459695958761758678464939339687699579777794385888955230567949988877758985934877982886695427678972427853677799897699595979605387479291530969918696595694245935396968786989915927496695356888999776194685987545864999879862922887783777087934686983987965798767446658656955689756936836995589194878427975948727899698419990263771989996869998349477728775459788688568867699543355964857977539626839735694419798755811437986859345269947674975946587129398769727545177756598998876586626527566757373647445597979057963857795758277569888235960955554468845261389788493597578984387879866968793542884927897994454963497347288198558969458596643858698847537473394559849896871869699797967649613956978686287499654968675957939511853293992993686765961999866279940854876412965758264997911786258952965797599857698838999225886538997455571788894724972556829495576775833880677169989887337158697759889937246468769819248999887937935877819469982596884867772482944463448889548974999425479667858583697896376264835786529972697978458988695441347545962666583556235669755954799999474179997586539889848578673345698660593937674889764246797988762768655588998894892842898838584957327665897476856168757870839958477808471334782887366598488875874967969493218759295556769563358993894995717635568352997996376849848686979994778877968868995439899668898287009696324872396942558897553148255397972586988689298898656775955944703597857995895996775882428447338463457866685691978379948897698495186879887739593288653589499789989768586928886765966968669946290899859075653935867654659167294797433696856649757255254876537893600893698134485099858998737275426449973691659765908729957832588506839968463699939953699993995536498678768698688689969744356785851964478336129583188582794631819983849685976277979278745969968573724978769848367747712997259276983631497117651349894883472195569669828561378918296495988726987376849744441878886982418888494231569748794581973816284885832849263635568788284997663689666635456277816996689336978755999588782959989955877864887458999888599257896865567315926247888655364335912967595796067387768724866783286955868729388867815595826686516686890887477928064849797765893755877888593567295962486789753981696863679475466665776744795997869988813968449949797971172369639544656817559427945209586345488996844853844988986657375558983999997862489847977735859945876586985744967898965496658459348874701496497891983866805293981586789887287673927379751947883589286297949888999046358995565797578845956884733676996874675492146948678359489429985418416599478684168346738978894954588629179897463964868678969984423969790596989884389548386936976645968576966387742826678366255759638947832989968785363784668684453672224686529938749585725196797849487491127158889262827939542888833823848036979337888695996479886932469328849841769588679836637859953553927427474978955368657689935854643824391998941744672999653729777544279867836958877896881463685688666939458383958699263883797948579239797949875884413866789348358987486996268897588567297396771996688786485589467988984457269386989956742989947474799949852116696298482673698671598496676779997445387567979495787486699449178367398647744993564797599767054968487873948647892826845642739699365898611575789967377479736825578899885385477757566978776759566659468527799834898729788639785197676389753488947859693964325723936711699598478747858877876257647992799848998854488519555975545238827947566892916735692146758473885753869465487785684899398591598673299936931785375833699779898496745737998096783587699481696633775685919985763943585753796388619876967668066878854997858857778669863269658611768584298478698579999817937369569497799577849314377298689225507289083797576687769834562712388877951785757888929837898775849978888549295849479677684578598699948586596376189938899492279698672946085272952859996489455683998879787757578285984621768549946859849677946563898654398654597893684742889937427199418481966855596748586898619792335487887367586458355776287981516848949295659978797946968285882476337769527694993741935229285792991998578787789798257894922496098666579223599915929798943299892797668659998852485368988965677798859586995579156097854559791397951798476696686787999177462998538569795985749999386673848793148495818547888835995975421879550818891788488998559667277048994899893525828766987938355739716569754989213855938948447267981962479357594725799289938547678982898593678877932598975974599739888826548786897384874974558979778272692524597972766249784596676586785897479928996937878936586431611349378684897473168987559692369799887989856981389366636445968466434969935478327184786696798968947708476549903459488767296877788898389974438478018285477698929489626686979949789366145977834655359495836674685675287785286953958194848667989818895829589998796356686717956854957251484877469186938647866897789696889856898993466792168458768667859734593876397257467433298958436745739537774698963858194618054987946639887576988659729487542569753887578974888432972696967961928395496526867789027481744866895593996697565489699894967948679992571972816894999287168829994455729899464788149633698889487596439953768626976562516737557949386543487969595888977364892649312897824328859864795636747777858827408827798977358795699469727737278378485766549869097493768588494858429668918977725968823988689879879945489769861471736176309947487719556148432960864759072960789251575312969439991773898627784977996659249972749895238476538868647388377261688357298768987759873976937568654994996577705787891883585667787798627979609797988837592958939792657986275216987642983229976974953879699877388649454842987416379534666679577975677764866787857499976697677499526396859776986762864172737682894964799466865566670536466313888474828686748378478868798668379597695891292387747959849598687844769889598327429634448874754714878986567988919672569939567759959891953466568494561987441967773371433917869667996798941889883774973577163756159757395797668984535999667785628879999539539891533482859804763374958264786035986368989978779964826675194455833925347794639798714819944869457855488396825837675267994644682987987765789973399679594988854849475357577756997699925798978827953972955947896785769757226864847546865649547878969429563476885286484962998988859973229864462663576259979776678779463753858797893699380967885798989975658947389799649456999941937496943917035959779875389928623848854359337673319696967655926956984495986199937562775822455487285699899289996335679686295928899649177196327487789793685902924752559699626816939867359977683866889779659679499678664159759899776791875823979698397796688567475506727217453761698684893785829261077469497667389879788699889373967935625656945996988885549356946911306898819386449931886398766588355979758545386012438872995965694687769797738139975326689654688986495868495976787658954729394814582757423988896786452419929375895867822626949825747632996349375152398777099188928354948735743384766899896595322878956997777835431949698476535698777654736595197773869284761747771488781568595554887558247876662942288579987018865959769817757992859385774448667789799337373759574979528739746677936188660595195787699178793598249457683967958977141459899385857974976665956678948289740999861747373373899463835889874679862997938983628777937889818946994795385326663258313578737886791689297956766559974823799785966678284997232956984852548568539989636827589778587267399719969828639839851969587617777957799869188489029858798956707941769493576576895974997786673938864412987467209857566670794574737984979245579389993766987976326986859829644546952349542646746318755997576956788329282784475569793873479868457929448626547684672566797475876433854788761588824539838998288876855698869645699359632688768367586828464578666623354589474987667667899851158917342884983317637909564789673963288798888959673388967226834785374666688989586331278998377528422867293897676466653117993449963584897699978844695884669383989935765639785655774967698398879798296996997418669484992868894843875873565158857838298158684221157844999907839923667825666668388747549694751682387549497776864766857975478786924572613378156460596793999798559645758382709978237864657692365996312286895667395926895181994184928739766686644698836248474754973797486987845434784259367575679259567571138827999679846979996244896782732484972889886488963377268754887398979244956297485268689999347675839540262164524748966559342895363995976979646954695796474329799955847867696375849248646743228697884687680487874179566986433987996457789898889528346887696685297785786767653663486388696857557760725974499862995935499699553851398987978688927776536996088429849975484767644687799446879795342549987543898617738929835656964999467959863998682854596986297897924845574947882368936935868998871888549189749489997269715598386797608559536466925777264479459956984758657867682648479857697465779799448997487596788999729475854869998788732374523086667778693297459298978896392423988678836675864561887334936939929576793939959875437978977697396649366397369997997814099359468869575694794643244896956347956956996257724846335747359654916472898586285293948972158335355688486283357483881687863088769886895588831459687469998463673589978279986738785679429595788240375688746619663794796047562294573584566797939556979677947496866699768998974198569987974993929699669976826851589867365728379899456877699541869987843693769675777856867866475394327566979584067625774882692326548338977997554683694598775566685844888596277478773844684868467861855592377595427337994877196970527484508974249878955398633963657956775575975469579936699295877779876148673966868897989858399939958807978246376964374639664976939538996665868867987849989295773445206763955789444966555794988594857526958964279827036697347358067967875949783888338692654819746878989395697555997111954774789939674653693855565663994646942777962495465998399746989949748525863887859579876192036759435843643585695858776884905955848533179262579144594999609972898617356186599964486554597738749979887887842497549828798786596576585579999490663216891694495564787896966585776067885964480234886564631223875645776939488416985298546956412055689792314573683675589769828776967737652689349834696863876588765786895678919345777766499879515530699083899225218839667289159575417857486773335999449564888965753947789773429936993773449887248696646373404541998999999461248483866904569657467375956865994627696988797416961459887863867217779259637033860841877449290487376187439935621988777614257889977749773379759798212684046887588928767970358795550737764594355988697023945574447932765855338588934857675994875384384425358237493786499981579576871576778864447887196684890777998928594697898645992696588868271877632929499481479657367575986358995522979927479359926893758068429469996647685645657999458867619981528779835859979593956947485777776064437877949833847429974659793659887649795996798754978042792789579463838522154998887176446196816968794942182379687756954998936798997635466846735466986268539195677398992869344928375896844996594869738456979665176999772793494323639887628587917978963365944761948898965289979989486183472949437879879536637847859188976351789859727526587558386997566539373769918497896378892907957139714278237685595121899787689697595699996524733647698388257746358276886075692928666869276292399898684788853093848848857598943398334383556635272558636157656559849625977497499996329397293548967288751265449697859837777897763858848389595958698585969269989568985686496958878368988435896232988996738984379928287685688784399738487948795789587579487268548329837539558858748739868934989294775737076495899623839889813979945574524853279558956749899854884674946829957785295955786959637663099885879445867586269529547363668599769727454574928979884695839946277747768557265997929753689796799945879665937967985468468837629878774987194398697486893893495406798743465744858794775639877668691966988696889773468765899608934945994189448765636466518968875729658562515495668588378859857873484576425879679686754967835758669368622588556899993485576899698947889389176887425184989759794748569589693943196789878728724985978495680327897977558833685362699854957780123548485977977164988769678387978399768893969997099994795874794979774829974451347288777676861689998763437298898859697681517748699627983770239763145469997255894384367780993877697479948889948989551996648488327678349979588714797846963259957479495937947839769997769587984771977943448896647935991758868949625667396474776380858868972247689286838656241653848803898770498798857998786898816874676799576627897329341828777956335959257691549765349684721297746696929551659788686478338446582492889739799999219865788696616989646737595635843886898745382578999868875487729746929586699599879746599867889696723865659824896159799384545885975809869757656965494649889986783094699487796687855836467466819385996598974479871037318266958903835497966265881787782758979878807597668865988339958893957237889292983859994709456844839398996481998897748998579298686357694598760382878658225586797745389758576749789698694257745764888740694976375634889596745875515568447996887699451773385859577452073753869335479789549705873668245892897329787578563163528878779666793895987843881698968732877818388879747948782555788675715777779868779966859324758783089764286762732675899578392289189679924683876978439625488647699937787686578687587694419546296895877899926744578716885475474997716585723476959548781839846776615839454577640486754639978697912627551997499666783866772849892152646964529865345549574499726974572388878679915349486344367396467531788864379819497454569745887959934894947298669598878327283815817750886369886398949872557785415599797377975773956056258577496573598075566697829766679478924718969874784692949230958875352796468289959213796319857746195979239987878988738359391876969546254798964674775953192674984647687683666263598788866794434848508282595372565933527969914798934698792599623454469858591544566155773768989539896565541144789984589879673985849642849698962876806968988895768538624386626675945783485555958856134795744519399838998689756176978489888275277779976847369823599997279645875862328978813836271966755720677665189478485884686857782916787857762978266387939274468924779549961878936896937966449677898879888584685475597818855399592988739453989469448525848394773787596682787920445464708965582343962796599365222879958569659949768797988958693567856546638892536877699979896839993837148967487798939898950161627987526592978855786864814746297877475782985483549707688346934568937998818509698738973498985785682748659814990463869827788682739663858769698360967689677664798576794973465699658996778946869538788746784219487893786788638739886983893569344758989625983679646737985378736182668692369967778758837997826382078489699967375886698868889792297164467856997289679959664462897985327974884667817585758599594883929567748795957958359797628649997777137514959499969825975856254909447976965296599582886834887817387287999964479879396964733677969988787968439995985683924579899658596924965666936897884064486987566595876993567638883728998338879488777695591377414882748879235976434989540367856668265699957998676856879799745782857966884569798337975095884689832325365449689487688746887697784699859868115937456794575209489787677857857843842556858247597338488864922449964359468662477785896985887255899475789589943797966475784594943886679649169585888389495998771881497866839159327998777458965929999997858544665999369475885869427863995089370178169925958975946596695538853888822982469749435774556998645214194882399392768848965839399959898454586879385463637883586789918599898338423248558976835687668827782778187522488947299296586896367188869686699508958773776959979846946365867491888662697624479498185685187971896286927983683869996249983656557674747595959579196816341739858836845185662359953567559434399165868566955596248597979286272793738792637757997959955595949745689979745768397874678375577197959889765317788998736794787556846669058712987499586866485678699988948453677696944886089722759339968865423828981842694887465625887393597856581924768844498578249958459817628628367278489886859566667699794858148766515893426455898386699986976558598574974888874738998186868718969528346198987789747469478928779897978864592684972945238934651295184992427676758581599689491578589604065464663540387846787997579887748624549679286985986742986741988845854756789999498636984783659388983785999897879977578977566198895544396964964387516865792479844843977950858964962727817289282997797959230755939952869088989798879764769379932326738882293967998556966966973988659575558581647514365938974949348767596844685987087598976858965577667899273182978843525936884343849957157819994289978275339757969128685686876488468889670995899885993754697777598348328168568734249978279672934157479777575846648842579774876258378173898969427663332888211759568686463896609698395968969868776659677928799536972598735724955797387185259677533753749696644759394987874496787579727527993999379898887444419698986947286827586223676495381573578266976699499211474827288697685169825389817897521975898649633384416573648465757677579636867817896546277799987696649587916953848987956782558556458683891974987945776756157688716495989998957896257276566586577490787656897981664838774488988988769758478877989349757924994974963288434527798527577794647758974589245162817178989994998766789879646996596264698729866434368666835626864691635943859797318969677182898969974794996537823629675969859688496999595178699357693954665307685495588399649995747993199965466998944477434649488783886597281599776897877397714939789778956739939847999487466836998666938598867667488539916938996989533764995774486389844465938989198299976175956284663893274173782783967768876275994709528752567977897957827695438968774775786968849485375445671566974796536898678834664383333697552985746508696777875818135697629968866496799536979386598638613939395899329549379788768570396731466649778766464797897276294895578598654773699465279908867389942758229190899375955997987836865966855943488359678199693565164998647756953878778955795664587389868637696688456635646298688328889446966767478859795828466795825834776947924664125657695229354758876859399539498894657595592678778694768923556870799897865966449567895332993873616785219374599485332359695976459779466876779698178868565390794836199998841639223682174929267494739639639397978849786497036999858166739758592904885973969934009796748956929737977859223755668885958994589798766684191883857979104987349969649997985761989487519693685247469578646694586492497988477797325768928779786635358519487478347686984723998778362775594976877797281794746666697048977449118776348679645963917749393395747893699619915995687995198539519597888286767858778735687848977262572956368592695133495776669988185325968897957765879897878767475441397705394996866354298323677894973455749762699716654763607974699661253984979977979466635869282479668654364999857877886894429838487848558653698508472772856508965684279539858293564289969597938885297516396468976906418968665739347678294939758889794598797856679727998727577956720574786335998954786014648779799939979899638865164869568987676476976848497935829432933545294788948697858197775296799654887787477758849618247957453483359373521206299997972385742269589898026788899278668669990394599759527896797772976636547396112762969869952955289289586598375951964699796995977678774977554449887768987466868468919798978323693969987842976312747574941939489851821719659379953786728464754698997659835228925638369575476561697598559874547988679879982935529953781174999582369997778653789593979057887175898986999546689667971743145771898935949966988898806759746957459759888489458676756378979796596798876975738925976323985795548861178539485851898474892227857573543987927849559098848953795957729989599777965939462091819636986178986964584926586597974797997999758336794996879959767397824984646496796974745757957647966979455679759596167869926184793768678819894298447568882684639798559592773832984848486488381778698947796586624837888992445739884799779899693025344495395582445899378996142991894176638947993989756577566398557667898897477696559816975299991725568949973159295685686756973048467974567837776799859657969898384473749949969863939549277546986157279566744975756831868166868994954858287695993214796745875946836781981766888867989879875463845466789896765768957960989838884598628685468577298689564896894546555766838684767432741396395999814514869347699479549677468993113438497964377796768927092343799336787674449198687879499586841953661786126779848999757598666672358786906969588895789535268768463763487499679977967538498984667399933499973417254596224638958215361995977879698347457978889374797798775798994394585968097587979886699372992457765864673397780597899681752998997996883862941718589758453584579449639628645375799877368473894738691787389874129899568788995906729477838395572693937778787399969363859172796959549879533686798749997265876645796755787976786867869839575983082677995986559486378638368225499375664967937858548287864378477650887844375488797699887764997378076857389787988799699856766825798837897697889198973654398855457588956790968487669895888979537916856349799499897584989767637985787965818244942948548866763199489739279376337685885258226975479469595767799458786999376531342786997894326855677573235776557489339957364859391546894574868722997686847667577946256235726545658768598067674799859849899739889639749345846596992769399887994872597885833788276803687387869388746848786398549808845388873556282294784679784829527379187789818576866629779974538747872587615839499997728625989297569976698976749328255677597377474749079898777545886995486768885879449899958966837399458852970539629128993699075875989965698891173427786589934765858789876738813296915844776546977899843855851997489748777897836995892743647723841487568552564377478532958616665644291005983938783546917352523896686068857689997870379234267975643497777589959677585476489784297848688446976691559596944397965394678666948796799976667947559645737977991545984832367849424549589696964944688738999928858777866895344855436748625679967565659933879888289843953751474867869865879711866392973946748327932977764774616596199868856493416981449586380748633696969965818734728827514599787466884825875853797775937869947988648976484975316558933989888884438975336874866918946319489379985981447779877648875879597497449977473669855583696988664136877731664748796799659828599874459498335557766888697038668975958851952857865835353893981846866659888873996344694596358689329772577879844547619753156599917789976799397869787778683458996935968785976633533689467587679674892595986434396799718433975294767865826288698689649940947963757825569654893187776566866451468978876137652749945573989916595499579785847999397853874983974859999986668256679788917676198644359694557679269982477559782445675679382468985758857777690789849967765199466891973789977769578343586419665752675895696565389166699257199865973886987378919788899449968619658997790998779735549761836446585366762996173588079899843499892283785966793479674392656496283479888758686784582755956475159659668555895858058889656720994867998897649776795685696733668588928646768738979792969839463943895149685585827898919537995734887728269696369883316454364432493296962655987922856273698944744198569888667979478955685883446658943887383596498969378987936849898115997898332796968879568876684893755849496587895759887589885666957667552645572482751396465734665757688283791805185756897968494254359537129877517496999888180541779734224765661799512879789173136995475278356979955868688778574964939757999464398376385994676835728997664613867867537988697848835289559399946669479799395265497797599979493879424956435898836813966584298486404839977778386584287874285767909596769377575989299789957887949197728279487496698683690396698687987427467646879996794795784678935856844268719698162887635444898589755697689734269638545977538765873917140938738796795494463672618488988930924943589848876373592715895916499487989749895895978765441559266543848775975295481649662439484175879688986258997272989962475743676840397917477597619713977818857969888559917972993876988719586559798956438778439355098579824628935581589725681286754765973088499679238889767564895496557886858884668786977578643740239857643999975559268277797774768769379774989741695749499472567915788385638588653696191767421854457899996993554627353465758526817878588974859599468879875870447877468566852506829883507377672787768765633426258878644466287886338247679679693924156796595699696579446693754593976736870778694555683899944476865968987880837885958799679335924984949585855896887797672968432262179997582965926489899496865949740786878951799877987269988996648906388979983583607559596848239924896469971944887681547938674576838388777786743668373618659538859037778348536784899739597387856372758729995899878348396898693634529472656955887358727566698567595083575695948976627759655597975874452885995937394457249848664989878688971778539958167989887699566291563972891646128994587238203877064349487939612849643767769678508646959676978688977799286465987986435291787978675874779496983395949965294788964661937182874791989999663699873588589175658775459773959917662884916201765766357761757639647765078597895648774773847398955563862997829748876278424269599480986940866685767976196943947799947995679867285598625359779674573684796489433686876859499775779528636743535968052788874871365454778329576329293446964899997979885949747967614128944878442785169773347367739755488496979799745489786992949617689455892449997767779699578217377587687346889984844894969840743526768438896184597857999859307779648879574689398238699349687748473884289398623812716737987886385572496786788481389525779547955728995733628488937245869028795985689166989435366988378676739878854063598691718396988347877677303466583489476878636946999438784478048735777587874958287289993478675987469871987893619265966735943894965646476893198596585979189635585888989759985278463783967077972666439885486773529478546797376793885842872385565683966879769496888759657483894846377663185493894683749254629769926867469656474997929587857629776469696279749624446289665299779374846874698637707113596367768455746358859389673759569598578985872594398958347548689597969272798907783263873998294646862786657947969878455699937269772376283776849847848936649832696956693159162838498569677589427671611899828669977674989467762979841873462386297889362941575188482975949496718681898895289876535675883765977655669688259326667837258888878298951978957899874546656387488623958687189881465456939544968794926765561966689377396778379888947968247477337878683526873867598899486883977296854778535619679392553875858772286886634894370683477638882784656599078637993965687259543798987839761621653813454942350030053249454318356126167938789897345952786569399736870995656487288836774386073498436688682277858578355293976916535877458692779388684998895768378625386878733774742869749888973877693773986669165567629497869445939654564188981786859326884783656645478998759879159892878888852738766623952886966556779567388576535678982598898186817694949579284881575149263988792683264378148979267764989476779966828998116176724985776965894838261951396659696238946639848748948677382673277962739996554878969749756687268646492899378362387709897272969795986845743859893495278589875895965957376983958853647554867763695311707736896478648473977992566982644426947972696964677926758785929799474656964768629967926452947386498394581366773648498384756957888694967978669386565583278248588397673797645874925377684588934666279770769387364872589957989888585536981979585695891398674646569498349537669562916398789178964789576874399982782859478785777537840874487834999649636878674984385664303776778743889693817196895360458878937676873889663534989661986589597820968542739884826337599827559745977525983184887687694275583688789737617218326893982488374847786943996832893986475978846977703958999758795481698834867625347048969498448489988643786785773712875996977767799944298554986716949299687984547997979694884557937763743377961587244878449821416769747949588979799998469644392923675923877454563178478887250869535347636825977577994958678686334984697486375476977953526895582768976599749997749349691679767586668049689084995962424872678847928799268365559893748377477846598795870567746936757167753667567102619488266719959377954577856571985885378996366999989197478281739166469887492569949593389694977478576879787192534689789564682997779886879676959646805876967767346948216939784943460778302832785499821646198279365192665996788989761859935877179886878989466848942754493739599588254478579795556957726679849596575380595765896665727853788559656274925436396898693843878998599927857273658783795937998487635843877730958835956816373866347687777883838675476839745186788449179964698429932848695955706385389979883609846699889962789778997159878687047949568694998984629569285799971262234869276797788698558585949489429533976997859588738088789869568674449998386555496877078637679395457396644975696996595697651429396976976742833688782664446878852624336567867787276773705388928605258665864778744078578978864995958479885878718625857564353726455399699998754458124767191696356885836583887519765274994947596147989477973967867477797772862955579999346758932047346875779687866488858688755694598465767988832976994880379567457682186527985185539826428975890553934877834659897955685917889678399279719955888969758818779317916795774719793048676347574269989272799852689886978571484934266946184592579577848345662955144567879598598947989784994619598517295373678848985349429039889884816276364494597697837839041719378567835779545836962437986796557985898444536788261896917862448658539876487597497699978646764724789786896693096386896694784972827791949788759987992989575773967695909767582478782485683877779938404684892485669318638898534659424978394979995797794562593997974966649993955982538848796889735768768316466799827538676499583673893464999757939469475877886868559979653872574599631371987978215997166567422437977145081888999694715778921735953452494869798657581508197382886757566437564693157284275546255766759666588985788957598785694948557398486678865978869697233898799511898948639789873969894695383788349856644388586559874979766888965891447449896372658229789556269692394234463454613388963696962827788437599735919898728585586941598349364938969297979837867646829885866337696586597677946798899768499027656988850858598555866956951574659557285487686857888974382694656293476974397669587382298994348998970885371699267663585644638167945537977899097799856916869944998887919873789688379568991752996661983565696598576257566914685343875967779987379198664991567769948987096777758857589864283976576544287955774289962976755496953446891676719887976652866689999958479389478358793999748587975994595619989375549947256731678879864154668665677781398456965528757369749049946986896882628568767492579334817997693434689595298476976785764986335336679587869539699854386877787968793997679987719995651357916745448978775277923986853695496443699378888956668648189398353538568758259158859579866830796888667755533894954478995828956997697847466137778631466889696385558966374779944794795978578846778977744189589973984913649819668478633579834488888989339855613579484679846889749968739577797358578528488664787995415728827437818569969696336847083685944189614394658868991695616477467779239723847649379293668117978568968768474157359348982888978339956565769976526847634558443598668777858829999837886449469696985945424948763238489545199779737546955749766679997697849666876493569793449695955196679644886848792487984674585776959985777794346579762432973078799986758860686698325253719645387839389500192446566616859235874773465255865784148327746347298599638798777847984799158558348998779645677448519692318837678987858567439985687724371198896569989578570996399821926935079258854993738669859998944978588867684599688545777898970947474773795776552823947679896679965792989526827799994938516785278747835479977926668675818987781973725928487976487492282655378883548808945893687848647883968783786308672887338588795278499788993967299695648543947936988937998948958997476760895867856545627532652649775766748686799227868475498645193958463759933984755677532375776558623498799687243135673999687854997767595964974579622852588586733673972937984991367668845849249442818569787589736767989485798994997943658619735979888598966784869097659885754558893456379891988796798738897528667658996997889787983758670873799467788996797884573448788056774873468782845858739769466573994522863836873684955689599776280389575938968768687679787557697546678562799947897686335978945959697271958363969993787877739396275593838774927609599887865998921478983787196837498374863778997573546826936944975485354857985817149268388699799899257186998795087793376468567754299273996688979785790869585493499897577897797473988879754743896978779599163512859836422695452714379994339993766489894835769779976994784367364867862535987598885969609687853276666895757999848977621687166359148685994978786891944476787633997343290729867697773469794834311399864776945974996743968415418999593693147234767486838667555645498698465986892775864586826895488838989069759867567698987664548364578978989768888667189187638649578547697412399596782858459499868661868138657579447665972751689645772945939368969949947374483898969756958997677738765479764840379657686586592951379949865527199992579618955696774798898766755893665775657989399749836671498199241699873998544285593594443520396998977997488937544299888738426685697749896877183884684848489238377295955897936486288865744892498918876867397481629968761695957976554979669746759757547479697694646489428793767959978699497633857999799797479795685629485469689871689636918190264939569777995989927759597359848890955948729789345375758722298474898158584935871168845597589323679529837579678897695697979873657676854984888957954759647957608898889669949539898177541347179766986645999689898571788750979395987356877799963285999471187359925539289978976889745478955895796165674575963836529822538956799896457464827687359973956917128158984939149475747213679248789969396323879897919864868664789867788944455779477876779599697996469159573895685982982559259968969267211693745636679277797698725957995493099966866872998887620898985962247583279799992602125373953384354759742816948857774787788456997692577791858796849887492545339234928539794848679674676789865968461568836998979939997977846410687459899533687475027659775727899727976658797895497988857939492876743937566869814609679864693615792588839795969982465392858935972486569805658277274805896356855848784838924498688778758999463456866974282968536664979779979489352166996479706367456617996267947554379498776323892453668699493507793144574767878798978567759798869523581889966677594331596295863659275262779848786537877858767682888795915935891599786599519916996398747593393947719369546976843677811944779840796666647497182797778679495577263877899327489686743874784915853536687977829867523797774889794294685496646875964742586396915784989167589799946969943789401979758388191486667897985499859588866557322958779737929659847697900439969379588409295857937661858999630794687948879793936936937494762929471286322936148899991638497093565868871896977678664977954679596953233584995473912587616378399233598765944669568798997078655329867496877876295595756498894935993958678857453922596756521466429749677438528597664828597958874767669644988823886892646536654886696736868983785466597559877878359657746899461565396991876953884349558669568638789799559573998091922857249983768809972564996377456895875598492672798797464667877946664137693075867887973945923998593939316836895683979635997694668869926796531818578777696805647589255796333383466438876898635697479665176544573584948869687577477869834596728759798779765257825907499572678867769387287371472398366482659571679992891989839564448983684477599467335989699839619935884766768895839666899638664784999748939937659877987939417793778798677995182795688387884946434774449899664569991399747599946993885594586703566459396753996871595999694886958969576926328735699497479969898281776969813797958349536196468626538666863434668927896462695699646978987667899499989871718261542985479857746497775725897725434882369479499429757943989778874857967889889884477838466189798656787094775685665672752698759899989594617886751657677549789479198386854655855287659789848359619785946696789997772645698718768636975776757564846375614483336946898579125798718983528961586796882728474112994996679662875375183594676322685728682749689896914444788898973999399725727975787694478789493957446696947357335776952581783797559427537895279635997829776956677868969869593896906698364686865957112888233366724969898371873852678477975248846648575777974751439276972879942437865861823843895777796457399588998599076988864884678686586821969757933572879982499918751759948343488639525348879281372998766775569858679895780789586448695767843949479839563415746185855575956889379669669655899769392288837623239973967467978897989880968259939557032959797799282982718727269469858059779348448974297568615783469469693445598891665779875779978798999587389883956387489636894999987657458548889147689247689589682976945426847788975799787648783045366464560406985875194986995185857676724299481592156439832549279486295468879798977829874964747987789891643825969817868681899837478888479475895855679689996683898554624398515667841858497996766665958688984872763826826718954859942875894448867429185658795393788526564788496248189828324568869933957227980688449696776354849889996876584668685994789217850966648655787497637899887713567988959791775573876478793867547979986547949595559959799757736297837397272682979795842695559665868561993434955765359953266581548638858937143618691975959595747476755656389942699968386389729682698179781586581894974426796266888194768563649648979959677377859805996686968881763698685692992749884225781877287728866786538679855842324833898995819987685388736364583978685454898959993938569848867293993288491412546899655477534947964289228888358835596695649579859529961871073980599368724968588574963999566445858799999929569854777899723951938668794188177899594983888585961946976688349495487574669797349985987574998552788589698587774266864953469944229468884833795742858655248348758758776787984902575497654739511868958996487796788647886784986944563523238986488590579733897965488669758287547997978658676899759996562866658763045989434679532978847288414773195596777884978833899827388836765399678595665789684460488798639666569429695856998975429386589599934869787889969776337469693978974469999782783718788438688285995692569679744909452658579528969994959415731777799946826797953859759597847765929388495995857585718766488479723589798264466959976982799658764461792297988868896688573769996984870283628799738857877769963296866281637873589737646976389526989857443965398389688937836887687398784912487647887835968649877699856996564379497675897466776986769063896967858366937286887728968364099418956847286587589894379837896905818899739865439643886707945384589287574778792647418468687558879295625789726161059898939897784769841738399938698979996778635298836645658765396859889797867949956965859978222563995697269343285569807464544029787286695787377493848525844964989354937889295993558818795574586485888978898776944669739698639878169945977429864472939783662879267758787619287758686488584874981566776027557669172638318879823268578546972799995328963748679977772572888984879671657986899838993915447597431658859555584387549576626683426835867598889869608678269896948246948589376978985489987441145565698935989867377551665445195858964454326995297896439897419969725339565273595282805848434497668887895362666386786746489476291359577476469897452645969678193953837889878789932979591647758913697312959982864697253578859032949296487478969817429874976667928796665570895375694775852650659377579773797995514587755278949893688963688057718518382723878895966892749498439959788547965454794918973468887135764693763443684943519976878883275479627994475945543568925469646251298948277668387666994799155726219796879936457684046775454938516677648938187845959674327585617799474574588617875447629998778598692645914496785786875686787739996746884526934879678386429976981982293875998576237267682467980387857423958669977868977777517576887555287849747978883818778237869896188348789598397666977878825361365875787923798298542866378507945987974533968357370254775958583377154996788699744865515578547695988436573679496047888467547752496896987947675857983547797685522856878283067895496753686892975899847798899184699893938448654907499958389292988732759398859933889568866795708878979857287787188562669794538309859662813730178974479895699583918664764638558786697784996490387689988946494569656757968908579588545483997951698428956568327696988768995647978995996685929647927784578868999875283547898688348536595737646989616696887568912999997937988294285644833874686887956155929696647792127486943567945196752959533659777828143923798726675997676478618898687899758897894077898308848356142656838682986742279868858083677474693766526949868857199539746698844349779177489785967799967938749739594974759952369648797417885979943876723884846699155989849988849974796778399087763483498552799964541367932077389726996847715186796958898892734367899986168676777882743154479928477979497478597499990799969398867993879783876967889461779779584845321087759458996263586338855779798723086594879589427827878987691349396985965847497957989481524788671983988749896998675584399998655885226863966857538769457686976978524675484378758958873885866594515265856927578869815664636567844981499549439806998567864377988696889669196866778936577497858447564347897604594398398684796893491789477878926738864864589769739566978549997697986357929799562755867747772649938596488979829475454727967995866363745925962685768544978588990366736959687559592587759928649476488458998947659855972358425475549979318988938326998594670737577492989439868937847858855935738923845862784975785987597849784837993487886586782829973489837699889232698534889863878859694686589865989962969585896859595983848858367798777738958796944562157882769845392793923699994794779526948955656751636855272536655383433893349895758848848390358887486898993292672968666829296570688672853647752883896746337425699996595796986787998121595586732872417931759709298873698794819967373935665799683855785625727958987153679881958748736635978978734949274381684989979982569898849167449563369879719785826788936323494397277999671566979654837968495699448698573829443968299893776591935862689664537648664536799897639899459657786973281249497869618691644671788899451225838364975987297240879457897699597946788956397956479924748338949778734460677777584749659395979958538977825189916768854999756546586746699964924860857398629953974729979225599853689575763756974184994929236778172868885696299546898796495829899777098486691788744468877675178675975189994687394732853524483483578499576758439885833558567239744475549320796889553495467737055597853079767829885788640486212897957973377947779988752416777889126539934781673784092944778148068330736952977712768368788954169614797889696726499568659573764756965409668384842164999999899145404373646696842788944377399639924377987749357569888465944999533377684758714575951982766938812522998380996035515978994667777543919876598687567885678368696438943986256737769677828967985576386375413297986550214659645892589614884939677546578322136465688432084469588760677585669698787465594496198612366094999975585675695687897828945794248788788979947837795455684469995681653716898279906999495441975262971335848559509488677858596585346348534957630291678975958788368525847949989647993899564594269777249646499366565558396356476939987477459111799555796593989878647918456296833888387949578769760853743796630728972469859625758495889497555669444987559367602544377592989948789768868566699835939679466936473469673642879708859939993858989798868669376841678977778592996639975964579575577659756369336893559878942479805484725079691778499733724595773295558168764868486448377874772695888448586665577895496386455799779833845623296288477526760485979665723493574668768658777576967396348789968145996778654998973827563768985158628679966996929399479789965891479899867996668694749776979655939797665485375492265740697497366916647886573042887595924976587837689972879985376364899964786954138885598688967880368786188384753382684886553533851279849392582685898274619456953747533648427752699659659743659698442346497496575968864953990418799799963793663946693796779879734578959939397675929939639433788165468576638876889324293698879892954792396877766680325473237887899968458574927999887695784799844997977564796758974846286768756857489659954974462777529664635955806797683895517962799984947981945888178899868539639863288749475548429798792689695458286899368959764999469656538929837716898345789945243597978644997786446767484579948924880699635677729886879789893158355996994539599268994479527067755758696883684366356767687587792586696788643825988898987754699789334689665971478784086786488796822347646842948573696768748559997923474697596459646979679599363598243955669847425461262045938576743999986862584792659442979893788457862773369884688988279484237287698442699979648976999728831175765952976575763952487434548789684797379457474842638896446686667937829481499181598629593766598682213699563296756468732879907283857546955897999397695064651873316275429687874579758667468677794945783286157496945747883866666528766329938709999448751122486851892838758851565378578348498868299484966814799699692897978893896769477556587936567539989383966488596448879996798485369944399711356664676798392768224825773899872133685989886666473587438622769883376959888897882369376987465909736713389488243719851158998766766789474985453326666875464828685763867886236953996546968896558678882899838935428885167887458334678574797665276486745626844929754868974378397965574487282923887659675799557813647646245943259645446928958689623679789667399983975542979489737475497076665758902764789214468839376687799479598675675394967149707659897858920984881968997759777716785969158938936828969917993762785877985728636989935865845258489659232799482876669587997328479955667659792986197688737875313852366623583597499649818988739777826389839799268976478988538364998373373747168999047982849876659566679479758583998954141779859769386754942895397871996787591595066881639776647937825979475957373733997987766844477583958299757718967959568810789975882249266678742855788455595865187884177747167482968377791595637456777896535674896949134538777799659878223595698998667483347537849453829881990777893251573943699236747528949626228768598573929914254687698889324757285418493927459856787679594868594689886456986623579931837797967786496569599278834210683545857979553885667893688139944683918898603119649653945588889699549656526539769373988996887978983766794964770162928587398486896167354712727605574765886697793896979328578197677998957951466876994976956977988668386779953768939618626996955257429209586397987784723691771946998829592686976533699982998996582784554228577265739991689594489659629556769696913376733953458848326829983578977959530719349694739149999654946997983749856579989897588769083996398797858357364977876677979952675366264468922379958889899269484682588674365924969878745946568645748468622757967587698749559279359728879897529996799657775753574948458889495976993379987567789789286446499762576738528693884558754968449918417897936497743529338554491576628469977879989863689530687462859473367408958284335198935935999978826587766999535489866797593757951657361775379477388988149897699766968719334173377769144789165494865664359198959957765939965276919889765689878417457478844436924723895988967448786895948959747783292198596795973866897868874873847686828474888313664635076665568664997469498286737271468267689677958693625994776796679994758787668467776579775976666435973614789248454946883778996978359479679922389246789612572689756297939859295738889797906979726897787766585388198787507775699499456865739679378957789867892753886162773883746868835674832598947279942956699779487726898377199934969213575152987069270957468069234841655917784749903671637174168967984549978978986889328869527779819866924858494885867394790968945667584873872737727964996597853779897728804728858777747636597468958823428798213946298463779888595969784345683949755737615265679626867359934695784988896336941887464998927554499928861782999498618279175299976849769498996984565796699395598668447184720987768625694593829169769696279234888479875788357965245784927956889675788936649789450816491858369896477735937547634859892334764752793678395437958766867854861297664399898658988696987798668746839681964778484152759458659717686653697899985928598818989766848491859359682587782576586476638594953556438779541663987949979686626984929896774582810874834479983898887778692767884954309945674807749869897696687481723874539969434664869544636663983189658989788997963296955789861374798486873943116134685639878739699829974798587685676695487942667279795425296272877979855479478483798687845628888937995479579895239778876395898289876745839982997527495753974269189762744849839558312989457965617937553839789667828525398998499840772766955899884887198818055978124579599538888745818594841397848376683999947589597965835899264771999787686696674897159793197955458790651975599685958897776569889863584258899956866797298992349897929519995322975666890694229498752897987787875899199297582922539147399496725967733674288582869649797879956592949848615189782677553854685763788784533297916898685847695558669184814991724739988247486398795456893456898365649776948958649945867126489582875757787978899386554984699958259272580649276896972294998839981673695685849996895875486776974948592945888879948577898738929888757587159778883217265438967786675797380982705522986892773413948775997794965963739718999975896874892485867116856962368966877758858799458878660866769678916883697357585349367589919586577336696184996785387690899737547694898977996338573587139639992376895195893998486587784564968357588374857641296537619298665749728832545579555915884458899848997299746752678778858747874895996117639327523469396958749884357983676791587936887927898438997725864956665957677879665757774583588998875528637974773769987575116898563996937246548628298746849378784869450767995797465399447746893763871944996684787594979765783544799977676694895176896376284892696611258949997474749989247659989683962754489889764985584687886699177693792765885798862699684789853843987668314488578949797932975849797388362996859383854939666886586364188698778859638768972445777927356999276447149899193428346458539986756863559879474724729355359958736638976885967148948823964239688974699596888733979630848328338888245939728262988851721194784948797691527585947839925686422276354486866487363587869989238749836957552663876628247783669675869546679639683845983488989695097969324489969876868469364798971926885459498879837643861486874995614814589924984953876849641294576478699676337488659548875797565599853640999888949792682985388749157973729376782788987685189392508668389198794694107478843954856694569898769447589685678549958537779748984268799999389855573756689889448358448699884543685902549724955718656445936963271179797949944869318889968799597447677566664574976368696189357987684269592765395888778557398567797948460829774788098686615686628595518768883927868559682387668427867783760697595769219533463556888742629513765568698752995888999854788468778559989959287885999557879633986699618772654536666986366799482887865536362948238588482618379185497847965132494888814289688878144447948673789627889594692819873165828966965591274388498943156711794136389672952799217747763848967879427375869969547872979772679586948389918136497285881385921633874469158587653447969986886896867876894635599399996359939996364869938605885238759927809567956196379944624572737899858990584431896398006398735678452552757946658696334797492761956456768539356570958998092649966869669567688829685867989987994985356882395937788978681594896798849973879196586668754364833744824288577699598599758795307449559577656898892986889685279793552841355798855249693278423696900782898866998934599868869778877499979686848948673699799253865536717599498399853365967655592957812394969769478578884895663788287433174808774859938078757861658674798566723759485838898248298498899885556867267889797642467988476739395066896543376875848988935685799971474999997459557966532655385666269545743144596889854879796279925687538462673698796385858766974524999479845988844364449284277768488695289964918778539739788999842918967864642739988957796851733788989961776088338577675594928655279427498887175554799835688522999838896758995797569259852687119799462857569214678458049972668999169567686399299392358115939759576869456994782686879659316946769797996968178698948955493374735748896858346695854969855891882743794369454499798729488245397869668978783489875795394887983162548864455559069532888965772857597758369750979795544746373757665725626685678899895657771545727967893921785649579476749962543958689734118557897914496537938626935779758469553345996768865886887954577827774943899968699989177362099914896998727849579724878491985599638639657986559656856644767897569789389686439780777387788229268978999468545789586491677999888653596694729519989687869693539542496595696819969035192974783506979595996798997776358724279876724596688289778439589857778889949765032559888583497777975996786933939464876857167859596954724688553496631397887299799337318854789677456957858763566645466983697787243947878154532679994498881669534193838789248669937539877566632884445961795357565556847395541977784889646596955733976828953942159667173659972977998269872861804598646575363688899686625999366596577946884554986891989996755599978687969395804979875798438877295449419591859989592887993329340783999298387936743989884778947957875759408898946354955477299883364657997784995788977666767758889604879984849736497287799868104367968051567748767893465478648775689968929536993247698483228899496644496997086994698358999228582787688777993888779797389558988589932559127674562292467424978685692896688854377496859432349495848396954289979896925351597564889519381751688553773945757969785777375888959259855443735757665775997662797954264976584378793466854875838999378289699745285593340388786637222497555673727426945578269293835867876468927771576746997986328843467344828831938487776788869879389689779789399721196586994559448885975858613799435944786488228799788966968632789592646394223854561699858699983268816925868257945665988753994244717568899487954539969286669972779488399478967776584269397677888597854673944189559499795965743939673668577852283895692988581587778799888777471155997881625645891743988998797339595455168929919646793488766896344239759677989838339478728453855398499547875666491929195678588387567926945387497574186986486895968455436724719954629865854773329690229564796434840625444559666763815689986848599993248699748260781877384304878946346959798875574242568775994969478796895667227584779673289378755737799236238984376486265478497558873657568964781786940894829565585859544313682899537946417685299552816827946728879699926198855597861949496569894787197843747388568788989385678948947676985838828495467486679948281497857511967170387288876976515559683586873977934564999578687787989999797536958384978279277398115276394613878587998676988778369766778968251927872557687994863684899778514539977688078919467646552648497888986249674989856597674798577075973253887469984164982429543643756959524983741437627987999869478748689868649773759356997265272797385352937856952942338589689789284469646687955545895801299697524789552422583347768618563519965785947604998937512899798764972656989346378799696221896798942744537596863879158696992788983179887576282963246698998578697575954455369197374835529988883539734856986969674499340477385875994855988767327549334747836469695778953318869593475732426858702596695999577247973688777799895688978478487892689977698787446988733393639899237874644497257719972607356954827999873799683318768579699458258517668399444267886895729198916585327497783496946857298867799760478529972588791674993792689963463858669779999238768919499756772595874858559782775987778607689376795784668993954776855739779964792768811885623747666789074747453867676663686873498878839416853894998968585869995629588494590884859996856999679251521644845439947512686987776658795265868896704753679586979874896757199879799672764599824931776252885646383170776297882769875947866937996267877169932988326955894883796829788397744436042264887736824963628719558658687362063426632897987590956846508255395691384371452598438197378779887497865566276727155923656987986837792435466519772787774899658542988599617697579438548496659959789496707649672877288879599947659883654589674232496994999895741595988547885129444348587495446815847465843894673428780678286664689374668892659267773389669968217985469999965774666495852336867583398567594746529734747482774775657693983778695946847995763848772923481263732476889860695578753988376951867849899566864376697639799994984959876179988339537429018197849485877558599999193843684797759811189333598976851678774984577843798628918859123488453799594326743978878494287967656376985499577729981885267899999796969981649341641678246748651848162876545753686372584959851755606887188994397335989689773544665896778478589672525929768329695577625944478643598979692847793479085589448642389855855397797999767991825977666477498773994948676907476677788799563816790398458393889611595266708866883987885780598539457887887638798879878847712791684489498059987936288589576837557477937627497832689755436985591688723955698577675888434994313588845885789395886588141932995976498492479846478667859872888778474295398745646826398835599927337589643396877769375376968996977896699942447577429962899368947974899515887894321741969559811468098889955946694868754496775685176884892798886963749855978269677886717989185895956639794877999348042985896796394394887842693417478756791897646857738879999878428985417291189534367738934598667256984753762597382869865666889648998998373897518626689482978589786899247932369289855566958267871676879598138644999518825459483858835598675878898899579976999269882330888358489283696879891648133315745369956795785988697649172795655877767973898789798373787746886722929565580775688649969849974893947879963806896868975899676984988197859993982645775285599855227792867889857464477828966686758579188459568846617948575729317945588678524968888775636899787789874739688938445824804458962785085578969674756679499986586925967669840388351804676789194763852664914645793985951469599586996874446834594684477694678861958445794343941567999918663955288729672439489888834653575676896973454197969986249068778956014879786895969632608892629437888979682993978888745959452649963634999495757392748950799932797893178838699797597909865285744786999893763875919977966837794886685796768357337885854685967695889877159536879976229764745888468599586377278668482779439899978744637844677769458106891966724937095998855495787895394327557848778927789497698692834385545823834118658946947246594772796657547886985693779686586754816779844548999597587776999982683734977480779849588984887409697291564548285986498656443987199647858986474782656687791634899888896654897968878988958879902984873338577495778884399997582398589796935885858488843546978813995578578843628709506958368687479998974873985987968465686823962839483996533987558756768297963165578475669866971373542237668592724898487279564898978967947523987895679477536689185676834977995673388489557953872467494648559528409640175857377975999557676486675295845984659665784924632888982886581058889668292663922535668459789585595728134939398167399932869787577968997778407857748544519899483332533998485847863859471809263459512467989736389688769535979656778794898595893198879926997715751397754338666349999578487888689780368376691822398569858779763958967664928688927343677788738429659994869564782747448822785892175739955866965298929637945409589689674691374827236559742998287945377595654859949744856884957566593298586878954167778686518826187064469486595725985823969718754859698964986859636089495283688986363987426583217080686548292697765958916929698968737367693428197428857758897197248363938909969399949899686776741258975887718833570596445479089999676149579483696669798577597957837996788834349689298806896796696939523849898597969586648924284665755838865971586346583952591777945563577006589889595993495596893586744996215469388765438246547955394707247837898892926757247568936648739637598298185372795598480599685952474585679788779794992993878526359677378976549237813756248445538348674855469796849997974349976656679388965835913895977964497885698395998968579994756882712599248693697826377447877857581495553777957998685716879897684475797793333828693069895549547567886297381135177872296676789972877998477228196975695359967168838945956927598837899589589749879798919846678973667968659798654837367856919578088799878685896832348665894778882736989378788986669726883216328513947867829569916597237567733878942896987855797728998955898998729799390849888878978791963578887668169995866984666891412498656448759955596888189896786798896289993987949676696912847435743968496997685163985869689537485255557454455995669948373984448879799765966755798685678088617639679369699783897386252969347948546868284477895492473718844449816573948799958677344835956399479949595551835497896654338846548886888399634777557862305995694495664965675995843579835468386955784952485896398556988246199559688848196899239393735995767668674778638699587651768598911532946116617599889948875665782484999597774431977446295758937796466228496682365395378658366737856582998398387229977286997888953452439329568514785665847676296318596974568857887982835885843656265678847786897864572895566743498781698807387824711267965946722059784314617364233954775947496719485955832969615528977847049999684569793934757828757238585934671750799947798859996869987759998763965487499577543759636584595988779597587544683847452765565831365717987975889879946675775395570596587285274988939276599349895833354884496669814977108337869149859954879949582867749855284844743877372278635878930746656749748436666465648268972889396898729517735476794848476238869766399265896959737359284331863097574529375422663524297688616628592979835398835359549768791788847549076732867359576888836987695494537272659482493414587925568879579876824735855575869833989957297899588894885878949896398317676499864164853477989789588875588779589511959826179968881887778855964497969699689619771688399898848899819588299989748218266789662899873622353996793436546475999397259374767798298591489465874596888895738689948468984899669767975798425634397489587946994876982299898986686384568324873789697978887472988994596989679597963474396691622785585579745697194774371334287879775088968678988999894627588649488867636468579589597698998888782666665594923875692596389365678537493998976684166634494638988754998886579327999185867696799839377663867764796945838984918229687774783854568598845434342782748731499446799693188528678666428788577989787377899852892352954247764516879364379767799219978884876998948389796373627831385455472738295666396822666488579819662978882671374884932785689788339983555919087766969984099539939866359572142498887574188529879935769774737966834489481547836166995685496987807765973759575847786499953964758949997546868412982988285977789947289717928983919273271785799583297496752642964139933985783569492677578324911269999499687899619951881349362471992657679378519295257997887474546199583669960438479762744948593284646457347885898614486965578792486894889883665698857979683779889551379977998468959679677680319373931967947698765465979953769491756697896197999859849867958674817276899959968886398879192949895775859959859961338220592755596452442552433889774998496844963647784315969976976365874649573997869775974224949989894743366889958875751699579487959999838838665890683805898368947893687968576759809978958761496846694964568448894359987961399988784758376676959603785659978667857794921937569549096880759757966783744761699554975555875878597349886860996663057945863816927157766498965546758888639874275659937975848647859699859959844886477198769367458791699587994517626872296695412851895997868896956844799339798636965929546887573641960749719937559757748995167475295781496887916939412557494849796580718899599936827986968469513688238648995276993652678267964994888449432567774795694696977451527468854897948587178347587367396889925765895487753784399473654904986286779236558697947555776789888191920789996749973469547396864286309462399793789542992185761988878798369546707555641564685836827938289876689937284822977927673765949586663665778578899688849598758929636769682771286945876976994889489489835648739347559595995969826299794352859796693238217967991663869979738737597694996812573896318728067898349928986896895992911699996188888366679789674656899087645913288656989628298064549367984873386599912639697959554878262588893879706587957989328554928877895969574478799785886961983559655867997862698896929567965265445332854353892969307466648968966774855963848518799867965576575448775812679955955873266897386438854779439925870771965955811575895640648973977465058777429954363656617779232595314649982759768899792268624855608678976266888826997662647975786893676893827775847878678587217446932918673789886925859647683455747319468988973969841247987598694355979384721753869848115477575678647464516789975987659956279329878448355867459997872665587989479853978978694979636948457899195989338653879797350829568799353286605769906391988299598967495232974176334988889857986677389839717553356579576969986366896897655749571897147754198874367648379245759298758877967874873776634976715293399864669375896759997564685663709558768987875959698956977373586813797256595979772572439275273479457556335758942958494648648996987386585967897754961632889599859981997836639137994789995946967936677995867226938274986399626995849196476542366947779648655993960846284289955763674579487721736757969964259859857748988309295323677791804879873997457896343293692959288889735475765786892347372467995833855761998728537128685856475575985687381969857391497185889467994787838267792395539355981744975498746935985786793959976417878798693737995585635534559997997835489593752878967398497636266689978599947956889368375995166575769986529368732639793671683378735579289759877656658777354833637639278698927671677584974759937467484535948996764396656773854716846691557871737894779665755994717913879955373519798659874728877869945727783326897096695634891377922365878198767878777758924079936285559469578157799887766878484658142863658462977977658927755864789799483487977288938979838389963528735961439898557669987682937883729679959579673699389459399578832848944579879896826898644898998499872352097888146673844727986724879525656597496988528346487989766498888778716994547187395589787863967987826225742494673685996955242762653775879789795562698635489807748256659968698298761798639969387399636195573938926653283255739777076396555999636776753489713558751846977175098428646628838627797079859695398917858528769557764238196895639875538737652775481986257478859598998278797969625652833986978799716698469535985564846338147943484952696618482455787389868762865508447978542386798583797359093573439936795877563756648737618568997989637166554862045865936554667889379679615822473459788858599626566763875669942656946269763389599734196876882196375759988985489848965999965934587577698483353716263612990198864988775767974837898466874687764779877761639697337694822936836739588587264217197937999478139999367998484529972764837734395556645637065595486852791375941797187337768466798396844904366986852672683587973375873477936998689278685974299348475844669998175753067496953782936991767883938880685366734948536588514784946373485386592390663778928578396799999039358986158556139457794689789773655653543866941983364595747453477546671764996763594872356787427796865877756374283025936839898968229637488468785428435949736468846836244497487375582929864677799990954993887788865117930264481767436958775923398869797399787368257672688961989778594757975479975489495687728655539978595666276746395285494647321879576998989748065257592336669539688589599218973297695258989648725439992927673795945995937478228587868178739667949942098554987362768932954793675940384976499927854947858289984137884788476339888695279779892295485978439459676755745796878783888779478876597577698838115772717988667957756795894737799249859337678444933659145039289488989996727664789633866749052647757756989552196949375284682985876956665703798479959593697969788759570468994874896779772374435894938734692686958284498767258484789999963547347479658373380986533697545857378794998765994387295488785899563777899997497483929849847661895775579695444089496686776478958486467936899354327878775869963299949793696832793979779886588588985192659916615477752896676979987677938891996745055731457494871955819969766186268797983898233696378064984474885767695996396956589843976962879692971695738993988199187865979768690573429585767564278886639958899892945426341994975749055449877644492847766987946457989886987570387549837377719478387279221434924885593855936883833668641469729299371994489088689963756481592496999695925475466443226937838935751990897313897738849587895784658797811977398476969659836775479751679865934386271999687877789829268795989848898858870989237374563856698925726537765279935688796557352788666797855439445888429894558692824967476764991264547655964579797738976883899619561187565265897972199984158397569593686305869882774496893459456080879265277798475649897526778297695985993878927966771869887827234645249933744797785467284862595254395422798547143851698239958137627347518274625973452878779804883258197698645029333167277988786974627375608569863378753985275932326698553398487676789565454479845876399999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999".to_string());
-                println!("Result: {}", output);
-                // You can add assertions here if you know the expected output
-            }
-            Err(e) => {
-                println!("Error reading file: {}", e);
-                // The test will pass even if file doesn't exist, for flexibility
-            }
-        }
+        let report = fixture::run(&HighestValuePalindrome).unwrap();
+        assert!(report.passed(), "{}", report);
     }
 
     #[test]
-    fn test_highest_value_palindrome_06() {
-        // Test with the input file that demonstrates the bug
-        let result = parse_and_run("src/algorithm/highest_value_palindrome_02.txt");
-        match result {
-            Ok(output) => {
-                assert_eq!(output, "9999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999998920287988595239528965094467996983649405494876799695338598977569297693737179898758988355469482652569225883564998844675256666958869694498389415766998788799423696267847472395499477815798953899842277626875685968349642757668756773381759889715579778395567559834883399746978787588168449837834287898085379245753793849679559474269558959819724957612975849596938427336622797485715783826969165576888589347752979517291884759708978858489884932687766232859699539659984838299947774499685645299679378567898399716888223887925695969569184595554657989889261879816552438998549967765998245765786889477957284899688379536437757654779895271529689939677693689796685666946957594287868799966286867490887887754945989797894998479338799885777496559976693389985745659699943471772397479540196538837654398449787686934773892695996792189763989985779579415389578756383135981629439679655787197646652799848689245839464998374977543695847857569789968896953958293378859439528990896576947689485339979549289969455675977648766994936533779325593439976158852690965685887649997742884765806959726017514589849964988369994062773304739196457488361884383957389795646567773768998584986965869976965947973543854687785878695974637663767688697298981963655794665946437159185949953144695967364837879597869274578295985617928559172385611839162846479689796599669581787497863828869898967278458646548967595187496675955757699884989988677797646875198856969404929768857987799297968927377567261235716739994235868818794342363756758981548199172498599981677382386659539537264819741789383957588945876847648887848345577397864949684947379878673824397758335559848383482348289635976391689646468771345383678869858686276955496722865893574755948956699778999768556944897628274628897757697952539385593982884867788965266785525857255866884643470582753242597819894698374763966995479657872876689352579739269834998879497839159179875632858838893489899857988656743665899579685366657888588288757987823875495459373695934876899626966597723478384854766797776978447558797347485877877597382352469419562798835675569791796561364998838789779597593536698999275587782582479897956589347889416264898699509968879469967669276215966256712726723650144786854865467983846796959757679284998789777398149775679566195742378593532846829587663868598934876787938668948279748578958677696677794717594858636789995579484397958799759468549868618686988095785338748569599363126745989566441792475999975698999695717269590731524978486785459962979395865277397778792759891794897724569597895882519748444979936437896968488696779382356277188784956955294663776729587947531186418697953866998797857993629028388788932957694896789422758716128856969757958896489963738896949989755760949987999891767895287779182748555755987486949071489892962588775662256751575476689832768576969779474993749964782746988988328718197485955769791885802464269983895547786295957966284458433856669787876849858779883757387996375759822763549130775896079534759277995838958556448699356854879227966577843487437877682629935757987807953488857455799172769838653849699985473235758189674494474879299892868825782865786296735462874476847498948799778566797498298592699267789954957489974618822683995950644476697476392727768979818485437397198688688657695678848743237808638458849736993908579528718967808619869644497896659918959798838756568881394519894649566836694984675959998497965878793988586826599926799257639937578620757259557586964886395998956786319826573792558549561945157579444967758991853457684597778686739756987267975255887297462948589967147576863399662247692919777664656628288888785699982598508867997499576316576988559878798694386855692973972752789677658497935739832735664888684292766368675698486966678299165999924879596692638268987597564975498799777795837787999664788873697728772509897496644969938597692685973815458889199589792743790999354999977846964276784879865348788871365676766488334855273579338932588856522748578937946796386892466580694671688599377384965533387869515364623811717578267835348455865697555885538986427737769667647864276687889855565235167858849898978593989888946899198885849739516738957585521679759777936995848487837694329559472647353694847717456361558499633438757315899569998998963868585815359739883878566864565668782696795676289897503974879204995221331946449985537785488699869182266946669966955765994687798818380785857753785823559892793949858666985767329844485589584455186476918989787588656728658463745698923755556037998884898859639957593195028474996464826784492169375578864946793875325893899978553799923468548657787646487563869989721747705677799989891755951754987790378983894375587597875884997858517348698786799587775699499889839956692596779986577527883154979687557676784856987396816823697777127992989476981898653798929837487597765659885949290298996558646493384784781789879474782787978147922344814797468938556986815886988988567797996608598498649765687793593555358489628986669655739458697884796836867588744643707666856778476558598998986934244287733791156887897697555687576844793999685467834544893024889576563189511865969999477159626491566799968206448669478929338876456379696899994669985245989448585774978586588965877979879895998160895352966473354786988886839428543668498443996977425099899427159778616452999589827387891956492581911191852946591987837289859992546168779517249989905247796993448948663458249386888896874533746692535980618995989789797785698856858794775858449895425899664999986969736546788339298749668446028699976651946269517749999695681159813656759884203984454387645869993974486757865557967987886511973377824424396898998958556748776586667073464478857686386974887968549375569666898269848535553953977865679468948958066997977658898896885186896558398647974184432297418797872874749789871874874833946468556998920929495889565677957847389298973568981896749892997217777963286186937896584876767557869794513887257756899776952966599389889949965777859976878968437158587994885787957855734983898730977894571595571989899977765077471279899683657846467877568458643299973558799983985235783976494688755739612944876284646994748205913957599369588984888997306555573298965473648568276568857879898196746815544859855844489237675896668589493972989553285873577585870838188977864995675596699666496622819689968845877355899446491331225994029784793057989826765976962878665654686658783889379535185858683698998999659985137578343369948551636547177484963537462749559234967387848485996397779579761255857598376159379485888919986498889893958798989488587615325655589887866724687467669677377246898355885557965685548435387628757171183264635159687833355694837739958861764960856642986836976497398758472256588852398339753725584338846676765631788878435689784876724696487799994539990973472979859919888545183795862967958399694466947989052778277963788874669997877385977779978945794657957898628362966959784299995619928766696848965768636672924868884665372389375397948567769872572793792965586834968978789558896756136759947997688058952899965878888828266564667779192967422669933686757417699858492647927885525797627896579376868777954867543581998577694449757515491659458552973756289136876598995936884696857559527570268757399367529976299956286858893978785697948999595764894966386659464989154931888656578388979598199566987944469689168087698178259758093996379488548368087323478488765967568868868917937345848189798677272936747966744460595993862288164799847594599877629962958928947976658779978498947486744782645376926875682875288682989929784744944769818575323745899969483568389672719975547588843597087897575399262867787347843487756697229784586539968446558598385997729574359706985770319453672289575736997837573889778589486787879666583348544826697595926877455983899624642085881979675595847918178238898896472874699473994749779696758672389866745751576522665778852692989841709496847895575558472819777825987671989997899490675579899496988373699846988597579696588216178572249876984967592398878838209263997587978996683597968146811357497859276773664925596594878817726532839776968848696987346399794448479152885987959654277984971989572978777937725685939792699545876848794251370959627175969998965799995742971446659895476213639959658478335875908896868168689458649579978597934849755999876368584957174977766967768598758479728498668397876784398958683667859286482353958732475916659765779418937779878994829767579596976483897645684586874410563276272176526695126729667699649788699059968984626149887439856597989742852877855729998966353957959779878388994631656971979655765388972659149642532837957787785847437978557448796777976674584838743277956696269986784395963739545945783287897578828858887566635869759985663476568897589989843988388582365789719519387949788994389629379752539866782787569745996693674738964989187952423572850743464886685527585255876625698877684882893955839352597967577988264728267984496558679998779966598495574753985682276945596726868589688763835431778646469861936795369828432843838489555338577934283768789737494869494687937755438487888467486785498857593839871479184627359359566832837761899958942719918451898576573632434978188685324999376175321627657737298697929977897588679294049696588915786467977768899894889967575595766947815957698456468548727698989688283687947871859669956979869746482619381165832719558297165895928754729687959787384637695964413599495819517346495664975563691898927968867673667364795968785877864583453797495696799685696894858998673777656465979837593834881638847546919374033772604999638894699489854157106279596085674882477999467885865690962588516799343955239773356394996678467795765549699829459799335849867496756980998259349588733928593596988699879657587485963457794738994649385429868489972566467917875569769349261895313836578759835149759775899893679812976995962983774396867879448934567388356910459747932771743499969565475899833966799556947775889978339748994987979895494577887880947686826699978687824957596496665866979863967769399869251725989774567577346359738869984827597749886875675428995677699458998342556189781629889897564555954819659695965297883228886179938987658739769925465869944777499928384899569359969582326677862394889848588798079574881927159792577439858886755619696283875175847972266337248396959485792167594279189598559624749559769483973575429735808987824387389448618857878796479933884389557655938779755179889571833776578667572469438695865786267722489983598975187749945932747487626963249978878996675149838944969688596666525764488994653885229652562849645538898578989717373967929657798958335969976784945049463896997644905698259325958897820298999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999".to_string());
-                println!("Result: {}", output);
-                // You can add assertions here if you know the expected output
-            }
-            Err(e) => {
-                println!("Error reading file: {}", e);
-                // The test will pass even if file doesn't exist, for flexibility
-            }
-        }
+    fn test_highest_value_palindrome_07() {
+        let result = highest_value_palindrome("12321", 5, 1).unwrap();
+        assert_eq!(result.as_deref(), Some("12921"));
     }
 
     #[test]
-    fn test_highest_value_palindrome_07() {
-        let result = highest_value_palindrome("12321", 5, 1);
-        assert_eq!(result, "12921");
+    fn test_highest_value_palindrome_08() {
+        let result = highest_value_palindrome("3943", 4, 4).unwrap();
+        assert_eq!(result.as_deref(), Some("9999"));
     }
 
     #[test]
-    fn test_highest_value_palindrome_08() {
-        let result = highest_value_palindrome("3943", 4, 4);
-        assert_eq!(result, "9999");
+    fn test_highest_value_palindrome_09() {
+        let error = highest_value_palindrome("12a4", 4, 1).unwrap_err();
+        assert_eq!(error.exit_code(), 6);
+
+        let error = highest_value_palindrome("1234", 5, 1).unwrap_err();
+        assert_eq!(error.exit_code(), 5);
     }
 }
@@ -1,4 +1,21 @@
-use std::{fs::read_to_string, io::Result};
+use std::sync::LazyLock;
+
+use crate::{
+    bench::{Solution, Solutions},
+    cases::Cases,
+    complexity::Complexity,
+    error::Result,
+    generate::{self, Generate, LOWERCASE, Rng, Shape},
+    layout::{Fields, Layout},
+    meta::{Constraint, Difficulty, Meta},
+    problem::Problem,
+    scanner::Scanner,
+    shrink::{self, Shrink},
+    stress::Reference,
+};
+
+/// Array to sort after its length, as many times as there are cases
+static CASE: LazyLock<Layout> = LazyLock::new(|| Layout::new("n:usize; arr:[i32;n]"));
 
 fn insertion_sort(arr: &[i32]) -> u64 {
     if arr.len() <= 1 {
@@ -10,6 +27,24 @@ fn insertion_sort(arr: &[i32]) -> u64 {
     merge_sort_and_count(&mut arr_, 0, arr.len() - 1)
 }
 
+/// Shifts made by an actual insertion sort, O(n²)
+fn insertion_sort_naive(arr: &[i32]) -> u64 {
+    let mut arr = arr.to_vec();
+    let mut shifts = 0;
+
+    for i in 1..arr.len() {
+        let mut j = i;
+
+        while j > 0 && arr[j - 1] > arr[j] {
+            arr.swap(j - 1, j);
+            shifts += 1;
+            j -= 1;
+        }
+    }
+
+    shifts
+}
+
 fn merge_sort_and_count(arr: &mut [i32], start: usize, end: usize) -> u64 {
     if start >= end {
         return 0;
@@ -32,7 +67,7 @@ fn merge_and_count(arr: &mut [i32], start: usize, mid: usize, end: usize) -> u64
     let mut l = 0;
     let mut r = 0;
     let mut k = start;
-    let mut count = 0 as u64;
+    let mut count = 0_u64;
 
     while l < left.len() && r < right.len() {
         if left[l] <= right[r] {
@@ -64,32 +99,118 @@ fn merge_and_count(arr: &mut [i32], start: usize, mid: usize, end: usize) -> u64
     count
 }
 
-fn parse_and_run(file_path: &str) -> Result<Vec<u64>> {
-    let content = read_to_string(file_path)?;
-    let mut lines = content.lines();
-
-    let n = lines.next().unwrap().trim().parse::<i32>().unwrap();
-    let mut results = Vec::new();
-
-    for _ in 0..n {
-        let _ = lines.next().unwrap().trim().parse::<i32>().unwrap();
-        let s = lines
-            .next()
-            .unwrap()
-            .trim_end()
-            .split(' ')
-            .map(|s| s.to_string().parse::<i32>().unwrap())
+pub struct InsertionSortAnalysis;
+
+impl Cases for InsertionSortAnalysis {
+    type Case = Vec<i32>;
+    type Answer = u64;
+
+    const SLUG: &'static str = "insertion_sort_analysis";
+
+    const META: Meta = Meta {
+        title: "Insertion Sort Advanced Analysis",
+        url: "https://www.hackerrank.com/challenges/insertion-sort/problem",
+        domain: "sorting",
+        difficulty: Difficulty::Advanced,
+        tags: &["sorting", "merge sort", "inversions"],
+        constraints: &[
+            Constraint::new("t", 1, 15),
+            Constraint::new("n", 1, 100_000),
+            Constraint::new("arr[i]", 1, 10_000_000),
+        ],
+    };
+
+    const COUNT: &'static str = "t";
+
+    fn parse_case(scanner: &mut Scanner) -> Result<Self::Case> {
+        CASE.reader(scanner).field("arr")
+    }
+
+    fn case_values(arr: &Self::Case) -> Vec<(&'static str, i64)> {
+        CASE.values(
+            &Fields::default().with("arr", arr),
+            <Self as Cases>::META.constraints,
+        )
+    }
+
+    fn solve_case(arr: Self::Case) -> Result<Self::Answer> {
+        Ok(insertion_sort(&arr))
+    }
+}
+
+impl Generate for InsertionSortAnalysis {
+    const MAX_SIZE: usize = 100_000;
+
+    /// A few arrays with values drawn from a range no wider than the arrays
+    /// so duplicates are common. Uniform arrays repeat one value, worst ones
+    /// are strictly decreasing, the most shifts an array can take.
+    fn generate(rng: &mut Rng, size: usize, shape: Shape) -> Self::Input {
+        let constraints = [
+            Constraint::new("n", 1, size as i64),
+            Constraint::new("arr[i]", 1, size as i64),
+        ];
+
+        (0..rng.size(1..=3))
+            .map(|_| match shape {
+                Shape::Random => generate::layout(&CASE, rng, size, &constraints, LOWERCASE)
+                    .field("arr")
+                    .expect("arrays fit the case layout"),
+                Shape::Uniform => vec![rng.int(1..=10_000_000) as i32; rng.size(1..=size)],
+                Shape::Worst => (1..=rng.size(1..=size) as i32).rev().collect(),
+            })
+            .collect()
+    }
+
+    fn write(arrays: &Self::Input) -> String {
+        arrays
+            .iter()
+            .fold(format!("{}\n", arrays.len()), |text, arr| {
+                text + &CASE.write(&Fields::default().with("arr", arr))
+            })
+    }
+}
+
+impl Shrink for InsertionSortAnalysis {
+    /// Drop whole arrays, then elements of a single array
+    fn shrink(input: &Self::Input) -> Vec<Self::Input> {
+        let mut candidates = shrink::removals(input)
+            .into_iter()
+            .filter(|arrays| !arrays.is_empty())
             .collect::<Vec<_>>();
 
-        results.push(insertion_sort(&s));
+        for (index, arr) in input.iter().enumerate() {
+            for arr in shrink::removals(arr) {
+                if !arr.is_empty() {
+                    let mut arrays = input.clone();
+
+                    arrays[index] = arr;
+                    candidates.push(arrays);
+                }
+            }
+        }
+
+        candidates
     }
+}
+
+impl Reference for InsertionSortAnalysis {
+    fn reference(input: Self::Input) -> Result<Self::Output> {
+        Ok(input.iter().map(|arr| insertion_sort_naive(arr)).collect())
+    }
+}
 
-    Ok(results)
+impl Solutions for InsertionSortAnalysis {
+    const COMPLEXITY: Complexity = Complexity::Linearithmic;
+
+    fn solutions() -> Vec<(&'static str, Solution<Self>)> {
+        vec![("optimized", Self::solve), ("reference", Self::reference)]
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::fixture;
 
     #[test]
     fn insertion_sort_analysis_01() {
@@ -125,6 +246,7 @@ mod test {
     fn insertion_sort_analysis_06() {
         // Reverse sorted (maximum inversions)
         assert_eq!(insertion_sort(&[5, 4, 3, 2, 1]), 10);
+        assert_eq!(insertion_sort_naive(&[5, 4, 3, 2, 1]), 10);
     }
 
     #[test]
@@ -135,15 +257,7 @@ mod test {
 
     #[test]
     fn insertion_sort_analysis_08() {
-        let result = parse_and_run("src/algorithm/insertion_sort_analysis_01.txt");
-        match result {
-            Ok(output) => {
-                assert_eq!(output, vec![0, 0, 0, 4999950000, 4999950000])
-            }
-            Err(e) => {
-                println!("Error reading file: {}", e);
-                // The test will pass even if file doesn't exist, for flexibility
-            }
-        }
+        let report = fixture::run(&InsertionSortAnalysis).unwrap();
+        assert!(report.passed(), "{}", report);
     }
 }
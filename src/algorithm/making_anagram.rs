@@ -1,3 +1,14 @@
+use crate::{
+    bench::Solutions,
+    complexity::Complexity,
+    error::Result,
+    generate::{Generate, LOWERCASE, Rng, Shape},
+    meta::{self, Constraint, Difficulty, Meta},
+    problem::Problem,
+    scanner::Scanner,
+    trace::{Level, trace},
+};
+
 fn making_anagrams(s1: &str, s2: &str) -> i32 {
     let mut answer = 0;
 
@@ -27,9 +38,7 @@ fn making_anagrams(s1: &str, s2: &str) -> i32 {
         },
     );
 
-    println!("---");
-    println!("left: {:?}", chunk_l);
-    println!("right: {:?}", chunk_r);
+    trace!(Level::Debug, "chunks", left = chunk_l, right = chunk_r);
 
     for index in 0..alphabet.count() {
         let diff = chunk_l[index].len() as i32 - chunk_r[index].len() as i32;
@@ -40,6 +49,75 @@ fn making_anagrams(s1: &str, s2: &str) -> i32 {
     answer
 }
 
+pub struct MakingAnagrams;
+
+impl Problem for MakingAnagrams {
+    type Input = (String, String);
+    type Output = i32;
+
+    const SLUG: &'static str = "making_anagram";
+
+    const META: Meta = Meta {
+        title: "Making Anagrams",
+        url: "https://www.hackerrank.com/challenges/making-anagrams/problem",
+        domain: "strings",
+        difficulty: Difficulty::Easy,
+        tags: &["strings", "counting"],
+        constraints: &[
+            Constraint::new("|s1|", 1, 10_000),
+            Constraint::new("|s2|", 1, 10_000),
+        ],
+    };
+
+    fn parse(input: &[u8]) -> Result<Self::Input> {
+        let mut scanner = Scanner::new(input);
+        let s1 = scanner.next::<String>()?;
+        let s2 = scanner.next::<String>()?;
+
+        Ok((s1, s2))
+    }
+
+    fn values((s1, s2): &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![("|s1|", s1.len() as i64), ("|s2|", s2.len() as i64)]
+    }
+
+    fn check((s1, s2): &Self::Input) -> Result<()> {
+        meta::lowercase(s1)?;
+        meta::lowercase(s2)
+    }
+
+    fn solve((s1, s2): Self::Input) -> Result<Self::Output> {
+        Ok(making_anagrams(&s1, &s2))
+    }
+}
+
+impl Generate for MakingAnagrams {
+    const MAX_SIZE: usize = 10_000;
+
+    /// Two strings of up to `size` letters. Worst strings share no letter so
+    /// everything has to go.
+    fn generate(rng: &mut Rng, size: usize, shape: Shape) -> Self::Input {
+        let (len1, len2) = (rng.size(1..=size), rng.size(1..=size));
+
+        match shape {
+            Shape::Random => (rng.string(len1, LOWERCASE), rng.string(len2, LOWERCASE)),
+            Shape::Uniform => ("a".repeat(len1), "a".repeat(len2)),
+            Shape::Worst => (
+                rng.string(size, &LOWERCASE[..13]),
+                rng.string(size, &LOWERCASE[13..]),
+            ),
+        }
+    }
+
+    fn write((s1, s2): &Self::Input) -> String {
+        format!("{}\n{}\n", s1, s2)
+    }
+}
+
+impl Solutions for MakingAnagrams {
+    const COMPLEXITY: Complexity = Complexity::Linear;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
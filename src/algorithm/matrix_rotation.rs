@@ -1,4 +1,17 @@
-use crate::algorithm::spiral_data::SpiralData;
+use crate::{
+    algorithm::spiral_data::SpiralData,
+    bench::{Solution, Solutions},
+    checker::Checker,
+    complexity::Complexity,
+    error::{HackerankError, Result},
+    generate::{self, Generate, Rng, Shape},
+    meta::{Constraint, Difficulty, Meta},
+    problem::Problem,
+    scanner::Scanner,
+    shrink::{self, Shrink},
+    stress::Reference,
+    trace::{Level, trace},
+};
 
 fn matrix_rotation(matrix: &[Vec<i32>], r: i32) -> Vec<Vec<i32>> {
     let mut spiral = SpiralData::from_matrix(matrix.to_vec());
@@ -7,19 +20,77 @@ fn matrix_rotation(matrix: &[Vec<i32>], r: i32) -> Vec<Vec<i32>> {
 
     let rotated = spiral.to_matrix_unsafe();
 
-    for row in &rotated {
-        println!(
-            "{}",
-            row.iter()
-                .map(|n| n.to_string())
-                .collect::<Vec<_>>()
-                .join(" ")
-        );
+    trace!(Level::Debug, "rotated", rows = rotated);
+
+    rotated
+}
+
+/// Same as [`matrix_rotation`] with the bounds checked copy back
+fn matrix_rotation_checked(matrix: &[Vec<i32>], r: i32) -> Vec<Vec<i32>> {
+    let mut spiral = SpiralData::from_matrix(matrix.to_vec());
+
+    spiral.slide(r as usize);
+    spiral.to_matrix()
+}
+
+/// Rotate the rings [`spiral_traversal`] peels off, putting values back at the
+/// positions the same traversal of a matrix of positions gives
+fn matrix_rotation_layers(matrix: &[Vec<i32>], r: i32) -> Vec<Vec<i32>> {
+    let positions = (0..matrix.len())
+        .map(|row| (0..matrix[row].len()).map(|col| (row, col)).collect())
+        .collect();
+    let mut rotated = matrix.to_vec();
+
+    for (mut layer, positions) in spiral_traversal(matrix.to_vec())
+        .into_iter()
+        .zip(spiral_traversal(positions))
+    {
+        let len = layer.len();
+
+        layer.rotate_left(r as usize % len);
+
+        for (value, (row, col)) in layer.into_iter().zip(positions) {
+            rotated[row][col] = value;
+        }
+    }
+
+    rotated
+}
+
+/// Rotate every ring on its own, reading each value from the position `r`
+/// steps further along the ring
+fn matrix_rotation_naive(matrix: &[Vec<i32>], r: i32) -> Vec<Vec<i32>> {
+    let (m, n) = (matrix.len(), matrix[0].len());
+    let mut rotated = matrix.to_vec();
+
+    for layer in 0..m.min(n) / 2 {
+        let (top, left, bottom, right) = (layer, layer, m - 1 - layer, n - 1 - layer);
+
+        // Positions of the ring, clockwise from its top left corner
+        let mut ring = Vec::new();
+
+        ring.extend((left..right).map(|col| (top, col)));
+        ring.extend((top..bottom).map(|row| (row, right)));
+        ring.extend((left + 1..=right).rev().map(|col| (bottom, col)));
+        ring.extend((top + 1..=bottom).rev().map(|row| (row, left)));
+
+        let steps = r as usize % ring.len();
+
+        for (index, &(row, col)) in ring.iter().enumerate() {
+            let (from_row, from_col) = ring[(index + steps) % ring.len()];
+
+            rotated[row][col] = matrix[from_row][from_col];
+        }
     }
 
     rotated
 }
 
+/// HackerRank guarantees `min(m, n) % 2 == 0`, so every ring is a full one
+fn is_valid_size(m: usize, n: usize) -> bool {
+    m >= 2 && n >= 2 && m.min(n).is_multiple_of(2)
+}
+
 fn spiral_traversal<T: Clone>(matrix: Vec<Vec<T>>) -> Vec<Vec<T>> {
     if matrix.is_empty() || matrix[0].is_empty() {
         return vec![];
@@ -37,12 +108,12 @@ fn spiral_traversal<T: Clone>(matrix: Vec<Vec<T>>) -> Vec<Vec<T>> {
     while t <= b && l <= r {
         let mut layer = Vec::new();
 
-        for x in l..=r {
-            layer.push(matrix[t][x].to_owned());
+        for item in &matrix[t][l..=r] {
+            layer.push(item.to_owned());
         }
 
-        for y in t + 1..=b {
-            layer.push(matrix[y][r].to_owned());
+        for row in &matrix[t + 1..=b] {
+            layer.push(row[r].to_owned());
         }
 
         if b > t {
@@ -68,6 +139,182 @@ fn spiral_traversal<T: Clone>(matrix: Vec<Vec<T>>) -> Vec<Vec<T>> {
     layers
 }
 
+pub struct MatrixRotation;
+
+impl Problem for MatrixRotation {
+    type Input = (Vec<Vec<i32>>, i32);
+    type Output = Vec<Vec<i32>>;
+
+    const SLUG: &'static str = "matrix_rotation";
+
+    const META: Meta = Meta {
+        title: "Matrix Layer Rotation",
+        url: "https://www.hackerrank.com/challenges/matrix-rotation-algo/problem",
+        domain: "implementation",
+        difficulty: Difficulty::Hard,
+        tags: &["matrix", "simulation"],
+        constraints: &[
+            Constraint::new("m", 2, 300),
+            Constraint::new("n", 2, 300),
+            Constraint::new("r", 1, 1_000_000_000),
+            Constraint::new("matrix[i][j]", 1, 100_000_000),
+        ],
+    };
+
+    /// Rows are compared value by value, whatever the spacing
+    const CHECKER: Checker = Checker::Tokens;
+
+    fn parse(input: &[u8]) -> Result<Self::Input> {
+        let mut scanner = Scanner::new(input);
+
+        let m = scanner.next::<usize>()?;
+        let n = scanner.next::<usize>()?;
+        let r = scanner.next::<i32>()?;
+        let matrix = scanner.matrix::<i32>(m, n)?;
+
+        Ok((matrix, r))
+    }
+
+    fn values((matrix, r): &Self::Input) -> Vec<(&'static str, i64)> {
+        let n = matrix.first().map_or(0, Vec::len);
+        let mut values = vec![
+            ("m", matrix.len() as i64),
+            ("n", n as i64),
+            ("r", *r as i64),
+        ];
+
+        values.extend(
+            matrix
+                .iter()
+                .flatten()
+                .map(|&value| ("matrix[i][j]", value as i64)),
+        );
+        values
+    }
+
+    fn check((matrix, _): &Self::Input) -> Result<()> {
+        let n = matrix.first().map_or(0, Vec::len);
+
+        if matrix.iter().any(|row| row.len() != n) {
+            return Err(HackerankError::constraint("n", "rows differ in length"));
+        }
+
+        match is_valid_size(matrix.len(), n) {
+            true => Ok(()),
+            false => Err(HackerankError::constraint(
+                "min(m, n)",
+                format!("found {}, expected an even number", matrix.len().min(n)),
+            )),
+        }
+    }
+
+    fn solve((matrix, r): Self::Input) -> Result<Self::Output> {
+        Ok(matrix_rotation(&matrix, r))
+    }
+}
+
+impl Generate for MatrixRotation {
+    const MAX_SIZE: usize = 300;
+
+    /// Matrices with an even shorter side, rotated either a few times or up
+    /// to the 10^9 of the constraints. Uniform repeats a single value, worst
+    /// is the largest square, with the most rings, turned 10^9 times.
+    fn generate(rng: &mut Rng, size: usize, shape: Shape) -> Self::Input {
+        let short = 2 * rng.size(1..=(size / 2).max(1));
+        let long = rng.size(short..=size.max(short));
+        let (m, n) = match (shape, rng.below(2)) {
+            (Shape::Worst, _) => ((size & !1).max(2), (size & !1).max(2)),
+            (_, 0) => (short, long),
+            _ => (long, short),
+        };
+        let value = rng.int(1..=99) as i32;
+        let matrix = (0..m)
+            .map(|_| {
+                (0..n)
+                    .map(|_| match shape {
+                        Shape::Uniform => value,
+                        _ => rng.int(1..=99) as i32,
+                    })
+                    .collect()
+            })
+            .collect();
+        let r = match (shape, rng.below(2)) {
+            (Shape::Worst, _) => 1_000_000_000,
+            (_, 0) => rng.int(1..=10),
+            _ => rng.int(1..=1_000_000_000),
+        };
+
+        (matrix, r as i32)
+    }
+
+    fn write((matrix, r): &Self::Input) -> String {
+        let mut text = format!("{} {} {}\n", matrix.len(), matrix[0].len(), r);
+
+        for row in matrix {
+            text.push_str(&generate::join(row));
+            text.push('\n');
+        }
+
+        text
+    }
+}
+
+impl Shrink for MatrixRotation {
+    /// Rotate fewer times, then drop rows or columns keeping the shorter side
+    /// even
+    fn shrink((matrix, r): &Self::Input) -> Vec<Self::Input> {
+        let mut candidates = [1, r / 2]
+            .into_iter()
+            .filter(|&fewer| 1 <= fewer && fewer < *r)
+            .map(|fewer| (matrix.clone(), fewer))
+            .collect::<Vec<_>>();
+        let n = matrix[0].len();
+
+        for rows in shrink::removals(matrix) {
+            if is_valid_size(rows.len(), n) {
+                candidates.push((rows, *r));
+            }
+        }
+
+        for removed in shrink::removal_ranges(n) {
+            if is_valid_size(matrix.len(), n - removed.len()) {
+                let columns = matrix
+                    .iter()
+                    .map(|row| [&row[..removed.start], &row[removed.end..]].concat())
+                    .collect();
+
+                candidates.push((columns, *r));
+            }
+        }
+
+        candidates
+    }
+}
+
+impl Reference for MatrixRotation {
+    fn reference((matrix, r): Self::Input) -> Result<Self::Output> {
+        Ok(matrix_rotation_naive(&matrix, r))
+    }
+}
+
+impl Solutions for MatrixRotation {
+    /// Each element moves once, however many times the matrix turns
+    const COMPLEXITY: Complexity = Complexity::Linear;
+
+    fn solutions() -> Vec<(&'static str, Solution<Self>)> {
+        vec![
+            ("optimized", Self::solve),
+            ("to_matrix", |(matrix, r)| {
+                Ok(matrix_rotation_checked(&matrix, r))
+            }),
+            ("spiral_traversal", |(matrix, r)| {
+                Ok(matrix_rotation_layers(&matrix, r))
+            }),
+            ("reference", Self::reference),
+        ]
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -123,4 +370,17 @@ mod test {
         let result = matrix_rotation(&[vec![1, 1], vec![1, 1]], 3);
         assert_eq!(result, vec![vec![1, 1], vec![1, 1]]);
     }
+    #[test]
+    fn matrix_rotation_04() {
+        let matrix = (0..4)
+            .map(|row| (0..6).map(|col| row * 6 + col).collect())
+            .collect::<Vec<Vec<i32>>>();
+
+        for r in [1, 7, 16] {
+            let expected = matrix_rotation_naive(&matrix, r);
+
+            assert_eq!(matrix_rotation_checked(&matrix, r), expected);
+            assert_eq!(matrix_rotation_layers(&matrix, r), expected);
+        }
+    }
 }
@@ -1,6 +1,12 @@
-use std::env;
-use std::fs::File;
-use std::io::{self, BufRead, Write};
+use crate::{
+    bench::Solutions,
+    complexity::Complexity,
+    error::{HackerankError, Result},
+    generate::{Generate, LOWERCASE, Rng, Shape},
+    meta::{self, Constraint, Difficulty, Meta},
+    problem::Problem,
+    scanner::Scanner,
+};
 
 /*
  * MAXIMUM PALINDROMES PROBLEM SOLUTION
@@ -22,12 +28,18 @@ use std::io::{self, BufRead, Write};
  */
 
 const MOD: i64 = 1000000007; // Required modulo value
-// PREFIX_COUNTS[i][j] = count of character i in string[0..j-1]
-static mut PREFIX_COUNTS: Vec<Vec<i32>> = Vec::new();
-// Precomputed factorials for combinatorial calculations
-static mut FACTORIALS: Vec<i64> = Vec::new();
-// Precomputed modular inverses of factorials for division in modular arithmetic
-static mut INV_FACTORIALS: Vec<i64> = Vec::new();
+
+/*
+ * Tables built once per string by `initialize` and read by every query
+ */
+struct Tables {
+    // prefix_counts[i][j] = count of character i in string[0..j-1]
+    prefix_counts: Vec<Vec<i32>>,
+    // Precomputed factorials for combinatorial calculations
+    factorials: Vec<i64>,
+    // Precomputed modular inverses of factorials for division in modular arithmetic
+    inv_factorials: Vec<i64>,
+}
 
 /*
  * Fast modular exponentiation using binary exponentiation
@@ -43,7 +55,7 @@ fn mod_pow(base: i64, exp: i64, modulo: i64) -> i64 {
         if exp % 2 == 1 {
             result = (result * base) % modulo;
         }
-        exp = exp >> 1;
+        exp >>= 1;
         base = (base * base) % modulo;
     }
     result
@@ -64,7 +76,7 @@ fn mod_inverse(a: i64, modulo: i64) -> i64 {
  * The function accepts STRING s as parameter.
  */
 
-fn initialize(s: &str) {
+fn initialize(s: &str) -> Tables {
     /*
      * INITIALIZATION PHASE - Called once before all queries
      *
@@ -74,50 +86,54 @@ fn initialize(s: &str) {
      */
     let n = s.len();
 
-    unsafe {
-        // Initialize prefix counts for each character (a-z)
-        // PREFIX_COUNTS[char][pos] = count of 'char' in s[0..pos-1]
-        PREFIX_COUNTS = vec![vec![0; n + 1]; 26];
+    // Initialize prefix counts for each character (a-z)
+    // prefix_counts[char][pos] = count of 'char' in s[0..pos-1]
+    let mut prefix_counts = vec![vec![0; n + 1]; 26];
 
-        // Build prefix sums for character counts
-        // For each position, store cumulative count of each character
-        for (i, c) in s.chars().enumerate() {
-            let char_idx = (c as u8 - b'a') as usize; // Convert 'a'-'z' to 0-25
+    // Build prefix sums for character counts
+    // For each position, store cumulative count of each character
+    for (i, c) in s.chars().enumerate() {
+        let char_idx = (c as u8 - b'a') as usize; // Convert 'a'-'z' to 0-25
 
-            // Copy all previous counts to current position
-            for j in 0..26 {
-                PREFIX_COUNTS[j][i + 1] = PREFIX_COUNTS[j][i];
-            }
-
-            // Increment count for current character
-            PREFIX_COUNTS[char_idx][i + 1] += 1;
+        // Copy all previous counts to current position
+        for counts in prefix_counts.iter_mut() {
+            counts[i + 1] = counts[i];
         }
 
-        /*
-         * Precompute factorials and their modular inverses
-         * This allows O(1) calculation of multinomial coefficients:
-         * C(n; k1,k2,...,km) = n! / (k1! * k2! * ... * km!)
-         *
-         * In modular arithmetic: division by x = multiplication by x^(-1)
-         */
-        let max_fact = 100001; // Upper bound for factorial calculations
-        FACTORIALS = vec![1; max_fact];
-        INV_FACTORIALS = vec![1; max_fact];
-
-        // Compute factorials: FACTORIALS[i] = i! mod MOD
-        for i in 1..max_fact {
-            FACTORIALS[i] = (FACTORIALS[i - 1] * i as i64) % MOD;
-        }
+        // Increment count for current character
+        prefix_counts[char_idx][i + 1] += 1;
+    }
 
-        // Compute modular inverses: INV_FACTORIALS[i] = (i!)^(-1) mod MOD
-        for i in 1..max_fact {
-            INV_FACTORIALS[i] = mod_inverse(FACTORIALS[i], MOD);
-        }
+    /*
+     * Precompute factorials and their modular inverses
+     * This allows O(1) calculation of multinomial coefficients:
+     * C(n; k1,k2,...,km) = n! / (k1! * k2! * ... * km!)
+     *
+     * In modular arithmetic: division by x = multiplication by x^(-1)
+     */
+    let max_fact = 100001; // Upper bound for factorial calculations
+    let mut factorials = vec![1; max_fact];
+    let mut inv_factorials = vec![1; max_fact];
+
+    // Compute factorials: factorials[i] = i! mod MOD
+    for i in 1..max_fact {
+        factorials[i] = (factorials[i - 1] * i as i64) % MOD;
+    }
+
+    // Compute modular inverses: inv_factorials[i] = (i!)^(-1) mod MOD
+    for i in 1..max_fact {
+        inv_factorials[i] = mod_inverse(factorials[i], MOD);
+    }
+
+    Tables {
+        prefix_counts,
+        factorials,
+        inv_factorials,
     }
 }
 
 /*
- * Complete the 'answerQuery' function below.
+ * Complete the 'answer_query' function below.
  *
  * The function is expected to return an INTEGER.
  * The function accepts following parameters:
@@ -125,7 +141,7 @@ fn initialize(s: &str) {
  *  2. INTEGER r
  */
 
-fn answerQuery(l: i32, r: i32) -> i32 {
+fn answer_query(tables: &Tables, l: i32, r: i32) -> i32 {
     /*
      * QUERY PROCESSING - Answer each query in O(1) time
      *
@@ -139,100 +155,207 @@ fn answerQuery(l: i32, r: i32) -> i32 {
      * - multinomial_coefficient = total_pairs! / (pairs_of_a! * pairs_of_b! * ...)
      * - middle_choices = number of characters with odd counts
      */
-    unsafe {
-        // Convert to 0-indexed ranges for internal processing
-        let left = (l - 1) as usize;
-        let right = r as usize;
-
-        // Extract character frequencies in range [l, r] using prefix sums
-        // char_counts[i] = frequency of character (i + 'a') in the substring
-        let mut char_counts = vec![0; 26];
-        for i in 0..26 {
-            char_counts[i] = PREFIX_COUNTS[i][right] - PREFIX_COUNTS[i][left];
+    // Convert to 0-indexed ranges for internal processing
+    let left = (l - 1) as usize;
+    let right = r as usize;
+
+    // Extract character frequencies in range [l, r] using prefix sums
+    // char_counts[i] = frequency of character (i + 'a') in the substring
+    let char_counts: Vec<i32> = tables
+        .prefix_counts
+        .iter()
+        .map(|counts| counts[right] - counts[left])
+        .collect();
+
+    // Analyze character frequencies for palindrome construction
+    let mut total_pairs = 0; // Total pairs that can be used symmetrically
+    let mut odd_count = 0; // Characters with odd counts (candidates for middle)
+
+    for &count in &char_counts {
+        total_pairs += count / 2; // Each pair contributes 2 characters to palindrome
+        if count % 2 == 1 {
+            odd_count += 1; // Track characters that could go in middle
         }
+    }
+
+    // Edge case: empty substring
+    if total_pairs == 0 && odd_count == 0 {
+        return 0;
+    }
 
-        // Analyze character frequencies for palindrome construction
-        let mut total_pairs = 0; // Total pairs that can be used symmetrically
-        let mut odd_count = 0; // Characters with odd counts (candidates for middle)
+    /*
+     * COMBINATORIAL CALCULATION
+     *
+     * We need to count arrangements of pairs in the first half of palindrome
+     * This is a multinomial coefficient: total_pairs! / (pairs_a! * pairs_b! * ...)
+     *
+     * Example: "aabbc" has pairs: a=1, b=1, c=0
+     * Arrangements of "ab_" = 2!/(1!*1!) = 2 ways: "ab" and "ba"
+     * Full palindromes: "abcba" and "bacab" if c goes in middle
+     */
 
-        for &count in &char_counts {
-            total_pairs += count / 2; // Each pair contributes 2 characters to palindrome
-            if count % 2 == 1 {
-                odd_count += 1; // Track characters that could go in middle
-            }
+    // Start with total_pairs! (numerator of multinomial coefficient)
+    let mut result = tables.factorials[total_pairs as usize];
+
+    // Divide by factorial of each character's pair count (denominator)
+    for &count in &char_counts {
+        let pairs = count / 2;
+        if pairs > 0 {
+            // Multiply by modular inverse instead of dividing
+            result = (result * tables.inv_factorials[pairs as usize]) % MOD;
         }
+    }
+
+    // Multiply by choices for middle character (if palindrome has odd length)
+    if odd_count > 0 {
+        // Any character with odd count can be placed in the middle
+        result = (result * odd_count as i64) % MOD;
+    }
 
-        // Edge case: empty substring
-        if total_pairs == 0 && odd_count == 0 {
-            return 0;
+    result as i32
+}
+
+pub struct MaximumPalindromes;
+
+impl Problem for MaximumPalindromes {
+    type Input = (String, Vec<(i32, i32)>);
+    type Output = Vec<i32>;
+
+    const SLUG: &'static str = "maximum_palindromes";
+
+    const META: Meta = Meta {
+        title: "Maximum Palindromes",
+        url: "https://www.hackerrank.com/challenges/maximum-palindromes/problem",
+        domain: "strings",
+        difficulty: Difficulty::Medium,
+        tags: &[
+            "strings",
+            "combinatorics",
+            "modular arithmetic",
+            "prefix sums",
+            "palindromes",
+        ],
+        constraints: &[
+            Constraint::new("|s|", 1, 100_000),
+            Constraint::new("q", 1, 100_000),
+            Constraint::new("l", 1, 100_000),
+            Constraint::new("r", 1, 100_000),
+        ],
+    };
+
+    fn parse(input: &[u8]) -> Result<Self::Input> {
+        let mut scanner = Scanner::new(input);
+
+        let s = scanner.line()?.to_string();
+        let q = scanner.next::<usize>()?;
+
+        // Bound the count before reading that many queries
+        meta::validate(Self::META.constraints, &[("q", q as i64)])?;
+
+        let mut queries = Vec::new();
+
+        for _ in 0..q {
+            let l = scanner.next::<i32>()?;
+            let r = scanner.next::<i32>()?;
+
+            queries.push((l, r));
         }
 
-        /*
-         * COMBINATORIAL CALCULATION
-         *
-         * We need to count arrangements of pairs in the first half of palindrome
-         * This is a multinomial coefficient: total_pairs! / (pairs_a! * pairs_b! * ...)
-         *
-         * Example: "aabbc" has pairs: a=1, b=1, c=0
-         * Arrangements of "ab_" = 2!/(1!*1!) = 2 ways: "ab" and "ba"
-         * Full palindromes: "abcba" and "bacab" if c goes in middle
-         */
-
-        // Start with total_pairs! (numerator of multinomial coefficient)
-        let mut result = FACTORIALS[total_pairs as usize];
-
-        // Divide by factorial of each character's pair count (denominator)
-        for &count in &char_counts {
-            let pairs = count / 2;
-            if pairs > 0 {
-                // Multiply by modular inverse instead of dividing
-                result = (result * INV_FACTORIALS[pairs as usize]) % MOD;
-            }
+        Ok((s, queries))
+    }
+
+    fn values((s, queries): &Self::Input) -> Vec<(&'static str, i64)> {
+        let mut values = vec![("|s|", s.len() as i64), ("q", queries.len() as i64)];
+
+        for &(l, r) in queries {
+            values.extend([("l", l as i64), ("r", r as i64)]);
         }
 
-        // Multiply by choices for middle character (if palindrome has odd length)
-        if odd_count > 0 {
-            // Any character with odd count can be placed in the middle
-            result = (result * odd_count as i64) % MOD;
+        values
+    }
+
+    fn check((s, queries): &Self::Input) -> Result<()> {
+        for (index, &(l, r)) in queries.iter().enumerate() {
+            if l > r || r as usize > s.len() {
+                return Err(HackerankError::constraint(
+                    "r",
+                    format!(
+                        "query {} is {} to {}, expected l <= r <= |s| = {}",
+                        index,
+                        l,
+                        r,
+                        s.len()
+                    ),
+                ));
+            }
         }
 
-        result as i32
+        meta::lowercase(s)
     }
-}
 
-fn main() {
-    let stdin = io::stdin();
-    let mut stdin_iterator = stdin.lock().lines();
+    fn solve((s, queries): Self::Input) -> Result<Self::Output> {
+        let tables = initialize(&s);
 
-    let mut fptr = File::create(env::var("OUTPUT_PATH").unwrap()).unwrap();
+        Ok(queries
+            .into_iter()
+            .map(|(l, r)| answer_query(&tables, l, r))
+            .collect())
+    }
+}
 
-    let s = stdin_iterator.next().unwrap().unwrap();
+impl Generate for MaximumPalindromes {
+    const MAX_SIZE: usize = 100_000;
+
+    /// A string of up to `size` letters with up to `size` queries. Worst
+    /// cycles through the whole alphabet and always asks for the full string.
+    fn generate(rng: &mut Rng, size: usize, shape: Shape) -> Self::Input {
+        let len = rng.size(1..=size);
+        let s = match shape {
+            Shape::Random => rng.string(len, LOWERCASE),
+            Shape::Uniform => "a".repeat(len),
+            Shape::Worst => (0..len).map(|i| LOWERCASE[i % 26] as char).collect(),
+        };
+        let queries = (0..rng.size(1..=size))
+            .map(|_| match shape {
+                Shape::Worst => (1, len as i32),
+                _ => {
+                    let l = rng.size(1..=len);
+                    (l as i32, rng.size(l..=len) as i32)
+                }
+            })
+            .collect();
 
-    initialize(&s);
+        (s, queries)
+    }
 
-    let q = stdin_iterator
-        .next()
-        .unwrap()
-        .unwrap()
-        .trim()
-        .parse::<i32>()
-        .unwrap();
+    fn write((s, queries): &Self::Input) -> String {
+        let mut text = format!("{}\n{}\n", s, queries.len());
 
-    for _ in 0..q {
-        let first_multiple_input: Vec<String> = stdin_iterator
-            .next()
-            .unwrap()
-            .unwrap()
-            .split(' ')
-            .map(|s| s.to_string())
-            .collect();
+        for (l, r) in queries {
+            text.push_str(&format!("{} {}\n", l, r));
+        }
+
+        text
+    }
+}
 
-        let l = first_multiple_input[0].trim().parse::<i32>().unwrap();
+impl Solutions for MaximumPalindromes {
+    const COMPLEXITY: Complexity = Complexity::Linear;
+}
 
-        let r = first_multiple_input[1].trim().parse::<i32>().unwrap();
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        let result = answerQuery(l, r);
+    #[test]
+    fn maximum_palindromes_01() {
+        let result = MaximumPalindromes::solve(("week".to_string(), vec![(1, 4), (2, 3)])).unwrap();
+        assert_eq!(result, vec![2, 1]);
+    }
 
-        writeln!(&mut fptr, "{}", result).ok();
+    #[test]
+    fn maximum_palindromes_02() {
+        let error = MaximumPalindromes::run(b"abc\n99999999999999\n").unwrap_err();
+        assert_eq!(error.exit_code(), 5);
     }
 }
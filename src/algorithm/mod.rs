@@ -1,3 +1,13 @@
+use std::hash::Hash;
+
+use crate::{
+    bench::{Bench, Solutions},
+    generate::{Generate, Generator},
+    problem::{Problem, Runnable},
+    shrink::Shrink,
+    stress::{Reference, Stress},
+};
+
 pub mod acm_icpc_team;
 pub mod anagram;
 pub mod dna_health;
@@ -15,3 +25,135 @@ pub mod sherlock_and_the_valid_string;
 pub mod spiral_data;
 pub mod string_construction;
 pub mod two_strings;
+
+/// A registered problem along with the local tooling it supports, so that
+/// one entry in [`PROBLEMS`] is all a problem needs
+#[derive(Clone, Copy)]
+pub struct Registration {
+    pub problem: &'static dyn Runnable,
+    /// Random inputs, for `generate`
+    pub generator: Option<&'static dyn Generator>,
+    /// Timed solutions, for `bench`, `complexity`, `reading` and
+    /// `test --variants`
+    pub bench: Option<&'static dyn Bench>,
+    /// Reference solution, for `stress`
+    pub stress: Option<&'static dyn Stress>,
+}
+
+impl Registration {
+    /// A problem without tooling, the way `hackerank new` scaffolds it
+    pub const fn problem<P: Problem + Sync>(problem: &'static P) -> Self {
+        Registration {
+            problem,
+            generator: None,
+            bench: None,
+            stress: None,
+        }
+    }
+
+    /// A problem with random inputs
+    pub const fn generator<P: Generate + Sync>(problem: &'static P) -> Self {
+        Registration {
+            generator: Some(problem),
+            ..Registration::problem(problem)
+        }
+    }
+
+    /// A problem with random inputs and timed solutions
+    pub const fn bench<P>(problem: &'static P) -> Self
+    where
+        P: Solutions + Sync,
+        P::Input: Clone,
+    {
+        Registration {
+            bench: Some(problem),
+            ..Registration::generator(problem)
+        }
+    }
+
+    /// A problem with random inputs, timed solutions and a reference solution
+    /// to stress the optimized one against
+    pub const fn stress<P>(problem: &'static P) -> Self
+    where
+        P: Solutions + Reference + Shrink + Sync,
+        P::Input: Clone + Eq + Hash,
+    {
+        Registration {
+            stress: Some(problem),
+            ..Registration::bench(problem)
+        }
+    }
+
+    /// Whether the problem is still the way `hackerank new` scaffolds it,
+    /// with no tooling and its catalog entry and sample left to be filled in
+    #[cfg(test)]
+    pub fn is_scaffold(&self) -> bool {
+        self.generator.is_none() && self.bench.is_none() && self.stress.is_none()
+    }
+}
+
+/// Every problem in the algorithm domain, in module order
+pub static PROBLEMS: &[Registration] = &[
+    Registration::bench(&acm_icpc_team::AcmIcpcTeam),
+    Registration::bench(&anagram::Anagram),
+    Registration::stress(&dna_health::DnaHealth),
+    Registration::bench(&extra_long_factorials::ExtraLongFactorials),
+    Registration::bench(&forming_magic_square::FormingMagicSquare),
+    Registration::bench(&game_of_thrones_i::GameOfThrones),
+    Registration::bench(&highest_value_palindrome::HighestValuePalindrome),
+    Registration::stress(&insertion_sort_analysis::InsertionSortAnalysis),
+    Registration::bench(&making_anagram::MakingAnagrams),
+    Registration::stress(&matrix_rotation::MatrixRotation),
+    Registration::bench(&maximum_palindromes::MaximumPalindromes),
+    Registration::bench(&palindrome_index::PalindromeIndex),
+    Registration::stress(&queens_attack_ii::QueensAttack),
+    Registration::bench(&sherlock_and_the_valid_string::ValidString),
+    Registration::bench(&string_construction::StringConstruction),
+    Registration::bench(&two_strings::TwoStrings),
+];
+
+/// Registration of a problem by its slug
+pub fn registration(slug: &str) -> Option<&'static Registration> {
+    PROBLEMS
+        .iter()
+        .find(|registration| registration.problem.slug() == slug)
+}
+
+/// Every registered problem
+pub fn problems() -> impl Iterator<Item = &'static dyn Runnable> {
+    PROBLEMS.iter().map(|registration| registration.problem)
+}
+
+/// Problems whose solutions can be timed
+pub fn benches() -> impl Iterator<Item = &'static dyn Bench> {
+    PROBLEMS
+        .iter()
+        .filter_map(|registration| registration.bench)
+}
+
+/// Problems with a reference solution to stress test the optimized one against
+pub fn stress_tests() -> impl Iterator<Item = &'static dyn Stress> {
+    PROBLEMS
+        .iter()
+        .filter_map(|registration| registration.stress)
+}
+
+/// Look up a registered problem by its slug
+pub fn find(slug: &str) -> Option<&'static dyn Runnable> {
+    registration(slug).map(|registration| registration.problem)
+}
+
+/// Look up the generator of a problem by its slug
+pub fn find_generator(slug: &str) -> Option<&'static dyn Generator> {
+    registration(slug)?.generator
+}
+
+/// Look up the timed solutions of a problem by its slug
+pub fn find_bench(slug: &str) -> Option<&'static dyn Bench> {
+    registration(slug)?.bench
+}
+
+/// Look up the stress test of a problem by its slug
+pub fn find_stress(slug: &str) -> Option<&'static dyn Stress> {
+    registration(slug)?.stress
+}
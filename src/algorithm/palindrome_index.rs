@@ -1,82 +1,181 @@
+use crate::{
+    bench::Solutions,
+    cases::Cases,
+    checker::Checker,
+    complexity::Complexity,
+    error::Result,
+    generate::{Generate, LOWERCASE, Rng, Shape},
+    meta::{self, Constraint, Difficulty, Meta},
+    problem::Problem,
+    scanner::Scanner,
+    trace::{Level, trace},
+};
+
 fn palindrome_index(s: &str) -> i32 {
-    let left_len = (s.len() as f32 / 2.0).ceil() as usize;
-    let (left, right) = s.split_at(left_len);
-    let right_len = right.len();
-    let left = left.chars().collect::<Vec<_>>();
-    let right = right.chars().collect::<Vec<_>>();
-
-    let mut cursor_l = 0;
-    let mut cursor_r = right_len as i32 - 1;
-    let mut miss_l = 0;
-    let mut miss_r = 0;
-    let mut answer_l = -1;
-    let mut answer_r = -1;
-
-    for _ in 0..left_len {
-        if miss_l < 2 {
-            let cursor_r_ = right_len as i32 - 1 - cursor_l - miss_l;
-
-            if cursor_r_ >= 0 {
-                let left_char = left[cursor_l as usize];
-                let right_char = right[cursor_r_ as usize];
-
-                println!(
-                    "checking left char at {}({}) against right char {}({})",
-                    cursor_l, left_char, cursor_r_, right_char
-                );
-
-                if left_char != right_char {
-                    if miss_l < 1 {
-                        answer_l = cursor_l;
-                    }
+    let chars = s.chars().collect::<Vec<_>>();
 
-                    miss_l += 1;
-                } else {
-                    cursor_l += 1;
-                }
-            } else {
-                miss_l = 3;
+    if chars.len() < 2 {
+        return -1;
+    }
+
+    let mut left = 0;
+    let mut right = chars.len() - 1;
+
+    while left < right {
+        let left_char = chars[left];
+        let right_char = chars[right];
+
+        trace!(
+            Level::Trace,
+            "compare",
+            left = left,
+            left_char = left_char,
+            right = right,
+            right_char = right_char
+        );
+
+        if left_char != right_char {
+            // Only one of the two mismatched characters can be the one to remove
+            if is_palindrome(&chars[left + 1..=right]) {
+                return left as i32;
+            }
+
+            if is_palindrome(&chars[left..right]) {
+                return right as i32;
             }
+
+            return -1;
         }
 
-        if miss_r < 2 && cursor_r >= 0 {
-            let cursor_l_ = right_len as i32 - cursor_r - 1 + miss_r;
+        left += 1;
+        right -= 1;
+    }
+
+    -1
+}
+
+fn is_palindrome(chars: &[char]) -> bool {
+    chars.iter().eq(chars.iter().rev())
+}
+
+/// Accepts any index whose removal leaves a palindrome, and -1 when the string
+/// already is one or no removal works
+fn judge(input: &str, _: &str, actual: &str) -> bool {
+    let Ok(strings) = PalindromeIndex::parse(input.as_bytes()) else {
+        return false;
+    };
+    let answers = actual
+        .split_whitespace()
+        .map(|answer| answer.parse::<i64>().ok())
+        .collect::<Option<Vec<_>>>();
+
+    answers.is_some_and(|answers| {
+        answers.len() == strings.len()
+            && strings
+                .iter()
+                .zip(answers)
+                .all(|(s, answer)| is_valid_index(&s.chars().collect::<Vec<_>>(), answer))
+    })
+}
+
+fn is_valid_index(chars: &[char], answer: i64) -> bool {
+    let without = |index: usize| is_palindrome(&[&chars[..index], &chars[index + 1..]].concat());
+    let mismatch = (0..chars.len() / 2).find(|&i| chars[i] != chars[chars.len() - 1 - i]);
+
+    match (answer, mismatch) {
+        (-1, None) => true,
+        (-1, Some(i)) => !without(i) && !without(chars.len() - 1 - i),
+        (_, None) => false,
+        (index, Some(_)) => usize::try_from(index).is_ok_and(|i| i < chars.len() && without(i)),
+    }
+}
+
+pub struct PalindromeIndex;
+
+impl Cases for PalindromeIndex {
+    type Case = String;
+    type Answer = i32;
 
-            if cursor_l_ < left_len as i32 {
-                let left_char = left[cursor_l_ as usize];
-                let right_char = right[cursor_r as usize];
+    const SLUG: &'static str = "palindrome_index";
 
-                println!(
-                    "checking right char at {}({}) against left char {}({})",
-                    cursor_r, right_char, cursor_l_, left_char
-                );
+    const META: Meta = Meta {
+        title: "Palindrome Index",
+        url: "https://www.hackerrank.com/challenges/palindrome-index/problem",
+        domain: "strings",
+        difficulty: Difficulty::Easy,
+        tags: &["strings", "two pointers", "palindromes"],
+        constraints: &[
+            Constraint::new("q", 1, 20),
+            Constraint::new("|s|", 1, 100_005),
+        ],
+    };
 
-                if right_char != left_char {
-                    if miss_r < 1 {
-                        answer_r = cursor_r;
+    const COUNT: &'static str = "q";
+
+    /// Either end of the first mismatch can be the one to remove
+    const CHECKER: Checker = Checker::Custom(judge);
+
+    fn parse_case(scanner: &mut Scanner) -> Result<Self::Case> {
+        Ok(scanner.next::<String>()?)
+    }
+
+    fn case_values(s: &Self::Case) -> Vec<(&'static str, i64)> {
+        vec![("|s|", s.len() as i64)]
+    }
+
+    fn check_case(s: &Self::Case) -> Result<()> {
+        meta::lowercase(s)
+    }
+
+    fn solve_case(s: Self::Case) -> Result<Self::Answer> {
+        Ok(palindrome_index(&s))
+    }
+}
+
+impl Generate for PalindromeIndex {
+    const MAX_SIZE: usize = 100_000;
+
+    /// Up to 20 palindromes of up to `size` letters, half of them with one
+    /// extra letter inserted, so a valid answer always exists. Worst inserts
+    /// next to the middle of a long run of `a`s, the furthest a scan goes.
+    fn generate(rng: &mut Rng, size: usize, shape: Shape) -> Self::Input {
+        (0..rng.size(1..=size.min(20)))
+            .map(|_| {
+                let len = rng.size(1..=size);
+                let mut chars = match shape {
+                    Shape::Random => {
+                        let half = rng.string(len / 2, LOWERCASE);
+                        let middle = rng.string(len % 2, LOWERCASE);
+
+                        half.clone() + &middle + &half.chars().rev().collect::<String>()
                     }
+                    Shape::Uniform | Shape::Worst => "a".repeat(len),
+                }
+                .into_bytes();
 
-                    miss_r += 1;
-                } else {
-                    cursor_r -= 1;
+                match shape {
+                    Shape::Random if rng.below(2) == 0 => {
+                        let at = rng.size(0..=chars.len());
+                        chars.insert(at, LOWERCASE[rng.size(0..=25)]);
+                    }
+                    Shape::Worst => chars.insert(len / 2 + 1, b'b'),
+                    _ => {}
                 }
-            }
-        }
-    }
 
-    println!("misses: {}, {}", miss_l, miss_r);
-    println!("answers: {}, {}", answer_l, answer_r);
+                chars.into_iter().map(char::from).collect()
+            })
+            .collect()
+    }
 
-    match ((miss_l, miss_r), (answer_l, answer_r)) {
-        ((2, 0 | 1), (answer, _)) => answer,
-        ((0 | 1, 2), (_, answer)) => answer + left_len as i32,
-        ((1, 1), (answer, _)) => answer,
-        ((3, 0 | 1), (answer, _)) => answer,
-        ((3, 2), (_, answer)) => answer + left_len as i32,
-        _ => -1,
+    fn write(input: &Self::Input) -> String {
+        format!("{}\n{}\n", input.len(), input.join("\n"))
     }
 }
 
+impl Solutions for PalindromeIndex {
+    const COMPLEXITY: Complexity = Complexity::Linear;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,4 +282,16 @@ mod tests {
         let result = palindrome_index("hgygsvlfcwnswtuhmyaljkqlqjjqlqkjlaymhutwsnwcwflvsgygh");
         assert_eq!(result, 44);
     }
+
+    #[test]
+    fn panindrome_index_17() {
+        let input = "4\nabab\nraceacar\naba\nabcdef\n";
+        let checker = <PalindromeIndex as Problem>::CHECKER;
+
+        assert!(checker.check(input, "0\n3\n-1\n-1", "0\n3\n-1\n-1"));
+        assert!(checker.check(input, "0\n3\n-1\n-1", "3 4 -1 -1"));
+        assert!(!checker.check(input, "0\n3\n-1\n-1", "1\n3\n-1\n-1"));
+        assert!(!checker.check(input, "0\n3\n-1\n-1", "0\n3\n1\n-1"));
+        assert!(!checker.check(input, "0\n3\n-1\n-1", "0\n3\n-1"));
+    }
 }
@@ -1,4 +1,91 @@
-use std::cmp::{max, min};
+use std::{
+    cmp::{max, min},
+    collections::HashSet,
+    fmt,
+};
+
+use crate::{
+    bench::{Solution, Solutions},
+    complexity::Complexity,
+    error::{HackerankError, Result},
+    explain::explain,
+    generate::{Generate, Rng, Shape},
+    meta::{Constraint, Difficulty, Meta},
+    problem::Problem,
+    scanner::Scanner,
+    shrink::{self, Shrink},
+    stress::Reference,
+    trace::{Level, trace},
+};
+
+/// Names of the eight directions a queen moves in, in [`DIRECTIONS`] order
+const DIRECTION_NAMES: [&str; 8] = [
+    "north",
+    "north-east",
+    "east",
+    "south-east",
+    "south",
+    "south-west",
+    "west",
+    "north-west",
+];
+
+/// Steps `hackerank explain` shows
+enum Step {
+    Clipped {
+        obstacle: (i32, i32),
+        direction: &'static str,
+        squares: i32,
+    },
+    Reach {
+        squares: [i32; 8],
+    },
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Clipped {
+                obstacle: (r, c),
+                direction,
+                squares,
+            } => write!(
+                f,
+                "obstacle ({}, {}) clips {} to {} square(s)",
+                r, c, direction, squares
+            ),
+            Step::Reach { squares } => {
+                let reach = DIRECTION_NAMES
+                    .iter()
+                    .zip(squares)
+                    .map(|(direction, squares)| format!("{} {}", direction, squares))
+                    .collect::<Vec<_>>();
+
+                write!(f, "the queen reaches {}", reach.join(", "))
+            }
+        }
+    }
+}
+
+/// Shorten the reach in `direction` to the `squares` before `obstacle` when it
+/// is the closest obstacle yet
+fn clip(reach: &mut i32, squares: i32, direction: &'static str, obstacle: (i32, i32)) {
+    if squares < *reach {
+        *reach = squares;
+
+        explain!(Step::Clipped {
+            obstacle,
+            direction,
+            squares,
+        });
+        trace!(
+            Level::Debug,
+            "reduced",
+            direction = direction,
+            squares = squares
+        );
+    }
+}
 
 fn queens_attack(n: i32, _k: i32, r_q: i32, c_q: i32, obstacles: &[Vec<i32>]) -> i32 {
     if n <= 1 {
@@ -25,17 +112,15 @@ fn queens_attack(n: i32, _k: i32, r_q: i32, c_q: i32, obstacles: &[Vec<i32>]) ->
             let atop = r > r_q;
             let below = r < r_q;
 
-            println!("obstacle ({}, {})", r, c);
+            trace!(Level::Trace, "obstacle", row = r, col = c);
 
             if same_row {
                 if right {
                     // east
-                    acc.2 = min(acc.2, c - c_q - 1);
-                    println!("east reduced to {}", acc.2);
+                    clip(&mut acc.2, c - c_q - 1, "east", (r, c));
                 } else if left {
                     // west
-                    acc.6 = min(acc.6, c_q - c - 1);
-                    println!("west reduced to {}", acc.6);
+                    clip(&mut acc.6, c_q - c - 1, "west", (r, c));
                 }
             } else {
                 let same_col = c == c_q;
@@ -44,34 +129,28 @@ fn queens_attack(n: i32, _k: i32, r_q: i32, c_q: i32, obstacles: &[Vec<i32>]) ->
                 if atop {
                     if same_col {
                         // north
-                        acc.0 = min(acc.0, r - r_q - 1);
-                        println!("north reduced to {}", acc.0);
+                        clip(&mut acc.0, r - r_q - 1, "north", (r, c));
                     } else if is_diag {
                         if right {
                             // north-east
-                            acc.1 = min(acc.1, r - r_q - 1);
-                            println!("northeast reduced to {}", acc.1);
+                            clip(&mut acc.1, r - r_q - 1, "north-east", (r, c));
                         } else if left {
                             // north-west
-                            acc.7 = min(acc.7, r - r_q - 1);
-                            println!("northwest reduced to {}", acc.7);
+                            clip(&mut acc.7, r - r_q - 1, "north-west", (r, c));
                         }
                     }
                 } else if below {
                     if same_col {
                         // south
-                        acc.4 = min(acc.4, r_q - r - 1);
-                        println!("south reduced to {}", acc.4);
+                        clip(&mut acc.4, r_q - r - 1, "south", (r, c));
                     } else if is_diag {
                         if right {
                             // south-east
-                            acc.3 = min(acc.3, r_q - r - 1);
-                            println!("southeast reduced to {}", acc.3);
+                            clip(&mut acc.3, r_q - r - 1, "south-east", (r, c));
                         }
                         if left {
                             // south-west
-                            acc.5 = min(acc.5, r_q - r - 1);
-                            println!("southwest reduced to {}", acc.5);
+                            clip(&mut acc.5, r_q - r - 1, "south-west", (r, c));
                         }
                     }
                 }
@@ -81,9 +160,224 @@ fn queens_attack(n: i32, _k: i32, r_q: i32, c_q: i32, obstacles: &[Vec<i32>]) ->
         },
     );
 
+    explain!(Step::Reach {
+        squares: [n, ne, e, se, s, sw, w, nw],
+    });
+
     n + ne + e + se + s + sw + w + nw
 }
 
+/// Row and column steps of the eight directions a queen moves in
+const DIRECTIONS: [(i32, i32); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+
+/// Walk from the queen in all eight directions until the edge of the board or
+/// an obstacle, O(n) per direction
+fn queens_attack_naive(n: i32, r_q: i32, c_q: i32, obstacles: &[Vec<i32>]) -> i32 {
+    let blocked = obstacles
+        .iter()
+        .map(|obstacle| (obstacle[0], obstacle[1]))
+        .collect::<HashSet<_>>();
+    let mut squares = 0;
+
+    for (dr, dc) in DIRECTIONS {
+        let (mut r, mut c) = (r_q + dr, c_q + dc);
+
+        while (1..=n).contains(&r) && (1..=n).contains(&c) && !blocked.contains(&(r, c)) {
+            squares += 1;
+            r += dr;
+            c += dc;
+        }
+    }
+
+    squares
+}
+
+pub struct QueensAttack;
+
+impl Problem for QueensAttack {
+    type Input = (i32, i32, i32, i32, Vec<Vec<i32>>);
+    type Output = i32;
+
+    const SLUG: &'static str = "queens_attack_ii";
+
+    const META: Meta = Meta {
+        title: "Queen's Attack II",
+        url: "https://www.hackerrank.com/challenges/queens-attack-2/problem",
+        domain: "implementation",
+        difficulty: Difficulty::Medium,
+        tags: &["geometry", "simulation"],
+        constraints: &[
+            Constraint::new("n", 1, 100_000),
+            Constraint::new("k", 0, 100_000),
+            Constraint::new("r_q", 1, 100_000),
+            Constraint::new("c_q", 1, 100_000),
+        ],
+    };
+
+    fn parse(input: &[u8]) -> Result<Self::Input> {
+        let mut scanner = Scanner::new(input);
+
+        let n = scanner.next::<i32>()?;
+        let k = scanner.next::<i32>()?;
+        let r_q = scanner.next::<i32>()?;
+        let c_q = scanner.next::<i32>()?;
+        let obstacles = scanner.matrix::<i32>(k.max(0) as usize, 2)?;
+
+        Ok((n, k, r_q, c_q, obstacles))
+    }
+
+    fn values((n, k, r_q, c_q, _): &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![
+            ("n", *n as i64),
+            ("k", *k as i64),
+            ("r_q", *r_q as i64),
+            ("c_q", *c_q as i64),
+        ]
+    }
+
+    fn check((n, k, r_q, c_q, obstacles): &Self::Input) -> Result<()> {
+        let on_board = |square: &[i32]| square.iter().all(|position| (1..=*n).contains(position));
+
+        if !on_board(&[*r_q, *c_q]) {
+            return Err(HackerankError::constraint(
+                "r_q",
+                format!(
+                    "the queen at ({}, {}) is off the {}x{} board",
+                    r_q, c_q, n, n
+                ),
+            ));
+        }
+
+        if obstacles.len() != *k as usize {
+            return Err(HackerankError::constraint(
+                "k",
+                format!("k is {} but {} obstacles are given", k, obstacles.len()),
+            ));
+        }
+
+        match obstacles
+            .iter()
+            .position(|obstacle| obstacle.len() != 2 || !on_board(obstacle))
+        {
+            Some(index) => Err(HackerankError::constraint(
+                "k",
+                format!(
+                    "obstacle {} at {:?} is off the board",
+                    index, obstacles[index]
+                ),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    fn solve((n, k, r_q, c_q, obstacles): Self::Input) -> Result<Self::Output> {
+        Ok(queens_attack(n, k, r_q, c_q, &obstacles))
+    }
+}
+
+impl Generate for QueensAttack {
+    const MAX_SIZE: usize = 100_000;
+
+    /// A board of up to `size` squares a side with up to `size` distinct
+    /// obstacles, none on the queen. Uniform is an empty board with the queen
+    /// in the middle, worst puts every obstacle on one of the queen's lines.
+    fn generate(rng: &mut Rng, size: usize, shape: Shape) -> Self::Input {
+        let n = rng.size(1..=size) as i32;
+        let (r_q, c_q) = match shape {
+            Shape::Uniform => (n / 2 + 1, n / 2 + 1),
+            _ => (rng.int(1..=n as i64) as i32, rng.int(1..=n as i64) as i32),
+        };
+        let free = match shape {
+            Shape::Random => n as usize * n as usize - 1,
+            Shape::Uniform => 0,
+            Shape::Worst => queens_attack_naive(n, r_q, c_q, &[]) as usize,
+        };
+        let k = rng.size(0..=size.min(free));
+        let mut taken = HashSet::from([(r_q, c_q)]);
+        let mut obstacles = Vec::with_capacity(k);
+
+        while obstacles.len() < k {
+            let (r, c) = match shape {
+                Shape::Worst => {
+                    let (dr, dc) = DIRECTIONS[rng.size(0..=7)];
+                    let distance = rng.int(1..=n as i64) as i32;
+
+                    (r_q + dr * distance, c_q + dc * distance)
+                }
+                _ => (rng.int(1..=n as i64) as i32, rng.int(1..=n as i64) as i32),
+            };
+
+            if (1..=n).contains(&r) && (1..=n).contains(&c) && taken.insert((r, c)) {
+                obstacles.push(vec![r, c]);
+            }
+        }
+
+        (n, k as i32, r_q, c_q, obstacles)
+    }
+
+    fn write((n, k, r_q, c_q, obstacles): &Self::Input) -> String {
+        let mut text = format!("{} {}\n{} {}\n", n, k, r_q, c_q);
+
+        for obstacle in obstacles {
+            text.push_str(&format!("{} {}\n", obstacle[0], obstacle[1]));
+        }
+
+        text
+    }
+}
+
+impl Shrink for QueensAttack {
+    /// Remove obstacles, then cut the board down to the smallest one still
+    /// holding the queen and every obstacle
+    fn shrink((n, _, r_q, c_q, obstacles): &Self::Input) -> Vec<Self::Input> {
+        let mut candidates = shrink::removals(obstacles)
+            .into_iter()
+            .map(|obstacles| (*n, obstacles.len() as i32, *r_q, *c_q, obstacles))
+            .collect::<Vec<_>>();
+        let smallest = obstacles
+            .iter()
+            .flatten()
+            .fold(max(*r_q, *c_q), |smallest, &coordinate| {
+                max(smallest, coordinate)
+            });
+
+        if smallest < *n {
+            candidates.push((
+                smallest,
+                obstacles.len() as i32,
+                *r_q,
+                *c_q,
+                obstacles.clone(),
+            ));
+        }
+
+        candidates
+    }
+}
+
+impl Reference for QueensAttack {
+    fn reference((n, _, r_q, c_q, obstacles): Self::Input) -> Result<Self::Output> {
+        Ok(queens_attack_naive(n, r_q, c_q, &obstacles))
+    }
+}
+
+impl Solutions for QueensAttack {
+    const COMPLEXITY: Complexity = Complexity::Linear;
+
+    fn solutions() -> Vec<(&'static str, Solution<Self>)> {
+        vec![("optimized", Self::solve), ("reference", Self::reference)]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,4 +1,15 @@
-fn valid_string(s: &str) -> String {
+use crate::{
+    bench::Solutions,
+    complexity::Complexity,
+    error::Result,
+    generate::{Generate, LOWERCASE, Rng, Shape},
+    meta::{self, Constraint, Difficulty, Meta},
+    problem::Problem,
+    scanner::Scanner,
+    trace::{Level, trace},
+};
+
+fn valid_string(s: &str) -> bool {
     let mut chars = s.chars().collect::<Vec<_>>();
 
     chars.sort();
@@ -22,8 +33,7 @@ fn valid_string(s: &str) -> String {
         acc
     });
 
-    println!("---");
-    println!("chunks: {:?}", chunks);
+    trace!(Level::Debug, "chunks", chunks = chunks);
 
     let lengths =
         chunks
@@ -40,20 +50,82 @@ fn valid_string(s: &str) -> String {
                 acc
             });
 
-    println!("lengths: {:?}", lengths);
+    trace!(Level::Debug, "lengths", lengths = lengths);
 
     match lengths.len() {
-        0 | 1 => "YES",
-        2 => match lengths.iter().any(|chunk| {
+        0 | 1 => true,
+        2 => lengths.iter().any(|chunk| {
             chunk.len() == 1
                 && (chunk[0] == 1 || (lengths[0][0] as i32 - lengths[1][0] as i32).abs() == 1)
-        }) {
-            true => "YES",
-            _ => "NO",
-        },
-        _ => "NO",
+        }),
+        _ => false,
     }
-    .to_string()
+}
+
+pub struct ValidString;
+
+impl Problem for ValidString {
+    type Input = String;
+    type Output = bool;
+
+    const SLUG: &'static str = "sherlock_and_the_valid_string";
+
+    const META: Meta = Meta {
+        title: "Sherlock and the Valid String",
+        url: "https://www.hackerrank.com/challenges/sherlock-and-valid-string/problem",
+        domain: "strings",
+        difficulty: Difficulty::Medium,
+        tags: &["strings", "counting"],
+        constraints: &[Constraint::new("|s|", 1, 100_000)],
+    };
+
+    fn parse(input: &[u8]) -> Result<Self::Input> {
+        Ok(Scanner::new(input).line()?.to_string())
+    }
+
+    fn values(s: &Self::Input) -> Vec<(&'static str, i64)> {
+        vec![("|s|", s.len() as i64)]
+    }
+
+    fn check(s: &Self::Input) -> Result<()> {
+        meta::lowercase(s)
+    }
+
+    fn solve(input: Self::Input) -> Result<Self::Output> {
+        Ok(valid_string(&input))
+    }
+}
+
+impl Generate for ValidString {
+    const MAX_SIZE: usize = 100_000;
+
+    /// Up to `size` letters. Worst gives every letter the same count but one,
+    /// which gets one more, right on the edge between yes and no.
+    fn generate(rng: &mut Rng, size: usize, shape: Shape) -> Self::Input {
+        let len = rng.size(1..=size);
+
+        match shape {
+            Shape::Random => rng.string(len, LOWERCASE),
+            Shape::Uniform => "a".repeat(len),
+            Shape::Worst => {
+                let letters = rng.size(1..=26.min(len));
+                let count = len / letters;
+                let mut chars = LOWERCASE[..letters].repeat(count.max(1));
+
+                chars.push(LOWERCASE[rng.size(0..=letters - 1)]);
+                rng.shuffle(&mut chars);
+                chars.into_iter().map(char::from).collect()
+            }
+        }
+    }
+
+    fn write(input: &Self::Input) -> String {
+        format!("{}\n", input)
+    }
+}
+
+impl Solutions for ValidString {
+    const COMPLEXITY: Complexity = Complexity::Linear;
 }
 
 #[cfg(test)]
@@ -63,25 +135,25 @@ mod test {
     #[test]
     fn valid_string_01() {
         let result = valid_string("aabbcd");
-        assert_eq!(result, "NO");
+        assert!(!result);
     }
 
     #[test]
     fn valid_string_02() {
         let result = valid_string("aabbccddeefghi");
-        assert_eq!(result, "NO");
+        assert!(!result);
     }
 
     #[test]
     fn valid_string_03() {
         let result = valid_string("abcdefghhgfedecba");
-        assert_eq!(result, "YES");
+        assert!(result);
     }
 
     #[test]
     fn valid_string_04() {
         let result = valid_string("aaaabbcc");
-        assert_eq!(result, "NO");
+        assert!(!result);
     }
 
     #[test]
@@ -89,6 +161,6 @@ mod test {
         let result = valid_string(
             "ibfdgaeadiaefgbhbdghhhbgdfgeiccbiehhfcggchgghadhdhagfbahhddgghbdehidbibaeaagaeeigffcebfbaieggabcfbiiedcabfihchdfabifahcbhagccbdfifhghcadfiadeeaheeddddiecaicbgigccageicehfdhdgafaddhffadigfhhcaedcedecafeacbdacgfgfeeibgaiffdehigebhhehiaahfidibccdcdagifgaihacihadecgifihbebffebdfbchbgigeccahgihbcbcaggebaaafgfedbfgagfediddghdgbgehhhifhgcedechahidcbchebheihaadbbbiaiccededchdagfhccfdefigfibifabeiaccghcegfbcghaefifbachebaacbhbfgfddeceababbacgffbagidebeadfihaefefegbghgddbbgddeehgfbhafbccidebgehifafgbghafacgfdccgifdcbbbidfifhdaibgigebigaedeaaiadegfefbhacgddhchgcbgcaeaieiegiffchbgbebgbehbbfcebciiagacaiechdigbgbghefcahgbhfibhedaeeiffebdiabcifgccdefabccdghehfibfiifdaicfedagahhdcbhbicdgibgcedieihcichadgchgbdcdagaihebbabhibcihicadgadfcihdheefbhffiageddhgahaidfdhhdbgciiaciegchiiebfbcbhaeagccfhbfhaddagnfieihghfbaggiffbbfbecgaiiidccdceadbbdfgigibgcgchafccdchgifdeieicbaididhfcfdedbhaadedfageigfdehgcdaecaebebebfcieaecfagfdieaefdiedbcadchabhebgehiidfcgahcdhcdhgchhiiheffiifeegcfdgbdeffhgeghdfhbfbifgidcafbfcd",
         );
-        assert_eq!(result, "YES");
+        assert!(result);
     }
 }
@@ -32,14 +32,14 @@ impl<T: Clone> SpiralData<T> {
             let mut layer_positions = Vec::new();
 
             // Top row: left to right
-            for col in left..=right {
-                layer.push(matrix[top][col].clone());
+            for (col, item) in (left..=right).zip(&matrix[top][left..=right]) {
+                layer.push(item.clone());
                 layer_positions.push((top, col));
             }
 
             // Right column: top to bottom (excluding top corner)
-            for row in (top + 1)..=bottom {
-                layer.push(matrix[row][right].clone());
+            for (row, cells) in ((top + 1)..=bottom).zip(&matrix[(top + 1)..=bottom]) {
+                layer.push(cells[right].clone());
                 layer_positions.push((row, right));
             }
 
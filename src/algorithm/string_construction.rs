@@ -1,14 +1,19 @@
-fn string_construction(s: &str) -> i32 {
+use crate::{
+    bench::Solutions,
+    cases::Cases,
+    complexity::Complexity,
+    error::{self, Result},
+    generate::{Generate, LOWERCASE, Rng, Shape},
+    meta::{self, Constraint, Difficulty, Meta},
+    scanner::Scanner,
+};
+
+fn string_construction(s: &str) -> Result<i32> {
     let mut cost = 0;
     let mut free = [false; 26];
 
-    let alphabet = "abcdefghijklmnopqrstuvwxyz".chars();
-
-    for char in s.chars() {
-        let index = match alphabet.clone().position(|letter| letter == char) {
-            Some(index) => index,
-            _ => panic!("Expect letter index to be valid."),
-        };
+    for (position, char) in s.chars().enumerate() {
+        let index = error::letter_index(char, position)?;
 
         if !free[index] {
             free[index] = true;
@@ -16,7 +21,74 @@ fn string_construction(s: &str) -> i32 {
         }
     }
 
-    cost
+    Ok(cost)
+}
+
+pub struct StringConstruction;
+
+impl Cases for StringConstruction {
+    type Case = String;
+    type Answer = i32;
+
+    const SLUG: &'static str = "string_construction";
+
+    const META: Meta = Meta {
+        title: "String Construction",
+        url: "https://www.hackerrank.com/challenges/string-construction/problem",
+        domain: "strings",
+        difficulty: Difficulty::Easy,
+        tags: &["strings", "greedy"],
+        constraints: &[
+            Constraint::new("n", 1, 5),
+            Constraint::new("|s|", 1, 100_000),
+        ],
+    };
+
+    const COUNT: &'static str = "n";
+
+    fn parse_case(scanner: &mut Scanner) -> Result<Self::Case> {
+        Ok(scanner.next::<String>()?)
+    }
+
+    fn case_values(s: &Self::Case) -> Vec<(&'static str, i64)> {
+        vec![("|s|", s.len() as i64)]
+    }
+
+    fn check_case(s: &Self::Case) -> Result<()> {
+        meta::lowercase(s)
+    }
+
+    fn solve_case(s: Self::Case) -> Result<Self::Answer> {
+        string_construction(&s)
+    }
+}
+
+impl Generate for StringConstruction {
+    const MAX_SIZE: usize = 100_000;
+
+    /// Up to 5 strings of up to `size` letters. Worst cycles through the
+    /// alphabet so every letter costs once.
+    fn generate(rng: &mut Rng, size: usize, shape: Shape) -> Self::Input {
+        (0..rng.size(1..=size.min(5)))
+            .map(|_| {
+                let len = rng.size(1..=size);
+
+                match shape {
+                    Shape::Random => rng.string(len, LOWERCASE),
+                    Shape::Uniform => "a".repeat(len),
+                    Shape::Worst => (0..len).map(|i| LOWERCASE[i % 26] as char).collect(),
+                }
+            })
+            .collect()
+    }
+
+    fn write(input: &Self::Input) -> String {
+        format!("{}\n{}\n", input.len(), input.join("\n"))
+    }
+}
+
+impl Solutions for StringConstruction {
+    const COMPLEXITY: Complexity = Complexity::Linear;
 }
 
 #[cfg(test)]
@@ -25,13 +97,19 @@ mod test {
 
     #[test]
     fn string_construction_01() {
-        let result = string_construction("abcd");
+        let result = string_construction("abcd").unwrap();
         assert_eq!(result, 4);
     }
 
     #[test]
     fn string_construction_02() {
-        let result = string_construction("abab");
+        let result = string_construction("abab").unwrap();
         assert_eq!(result, 2);
     }
+
+    #[test]
+    fn string_construction_03() {
+        let error = string_construction("abC").unwrap_err();
+        assert_eq!(error.exit_code(), 6);
+    }
 }
@@ -1,5 +1,15 @@
-fn two_strings(s1: &str, s2: &str) -> String {
-    let mut answer = "NO";
+use crate::{
+    bench::Solutions,
+    cases::Cases,
+    complexity::Complexity,
+    error::Result,
+    generate::{Generate, LOWERCASE, Rng, Shape},
+    meta::{self, Constraint, Difficulty, Meta},
+    scanner::Scanner,
+};
+
+fn two_strings(s1: &str, s2: &str) -> bool {
+    let mut answer = false;
 
     let s1 = s1.chars().collect::<Vec<_>>();
     let s2 = s2.chars().collect::<Vec<_>>();
@@ -25,13 +35,94 @@ fn two_strings(s1: &str, s2: &str) -> String {
 
     for index in 0..alphabet.count() {
         if !chunk_l[index].is_empty() && !chunk_r[index].is_empty() {
-            answer = "YES";
+            answer = true;
 
             break;
         }
     }
 
-    answer.to_string()
+    answer
+}
+
+pub struct TwoStrings;
+
+impl Cases for TwoStrings {
+    type Case = (String, String);
+    type Answer = bool;
+
+    const SLUG: &'static str = "two_strings";
+
+    const META: Meta = Meta {
+        title: "Two Strings",
+        url: "https://www.hackerrank.com/challenges/two-strings/problem",
+        domain: "strings",
+        difficulty: Difficulty::Easy,
+        tags: &["strings", "sets"],
+        constraints: &[
+            Constraint::new("p", 1, 10),
+            Constraint::new("|s1|", 1, 100_000),
+            Constraint::new("|s2|", 1, 100_000),
+        ],
+    };
+
+    const COUNT: &'static str = "p";
+
+    fn parse_case(scanner: &mut Scanner) -> Result<Self::Case> {
+        let s1 = scanner.next::<String>()?;
+        let s2 = scanner.next::<String>()?;
+
+        Ok((s1, s2))
+    }
+
+    fn case_values((s1, s2): &Self::Case) -> Vec<(&'static str, i64)> {
+        vec![("|s1|", s1.len() as i64), ("|s2|", s2.len() as i64)]
+    }
+
+    fn check_case((s1, s2): &Self::Case) -> Result<()> {
+        meta::lowercase(s1)?;
+        meta::lowercase(s2)
+    }
+
+    fn solve_case((s1, s2): Self::Case) -> Result<Self::Answer> {
+        Ok(two_strings(&s1, &s2))
+    }
+}
+
+impl Generate for TwoStrings {
+    const MAX_SIZE: usize = 100_000;
+
+    /// Up to 10 pairs of strings of up to `size` letters. Worst pairs share no
+    /// letter, so nothing ends the search early.
+    fn generate(rng: &mut Rng, size: usize, shape: Shape) -> Self::Input {
+        (0..rng.size(1..=size.min(10)))
+            .map(|_| {
+                let (len1, len2) = (rng.size(1..=size), rng.size(1..=size));
+
+                match shape {
+                    Shape::Random => (rng.string(len1, LOWERCASE), rng.string(len2, LOWERCASE)),
+                    Shape::Uniform => ("a".repeat(len1), "a".repeat(len2)),
+                    Shape::Worst => (
+                        rng.string(size, &LOWERCASE[..13]),
+                        rng.string(size, &LOWERCASE[13..]),
+                    ),
+                }
+            })
+            .collect()
+    }
+
+    fn write(input: &Self::Input) -> String {
+        let mut text = format!("{}\n", input.len());
+
+        for (s1, s2) in input {
+            text.push_str(&format!("{}\n{}\n", s1, s2));
+        }
+
+        text
+    }
+}
+
+impl Solutions for TwoStrings {
+    const COMPLEXITY: Complexity = Complexity::Linear;
 }
 
 #[cfg(test)]
@@ -41,12 +132,12 @@ mod test {
     #[test]
     fn two_strings_01() {
         let result = two_strings("hello", "world");
-        assert_eq!(result, "YES");
+        assert!(result);
     }
 
     #[test]
     fn two_strings_02() {
         let result = two_strings("hi", "world");
-        assert_eq!(result, "NO");
+        assert!(!result);
     }
 }
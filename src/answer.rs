@@ -0,0 +1,152 @@
+//! Judge formats of answers.
+//!
+//! Solutions return plain values and the runner alone writes them the way
+//! HackerRank expects through [`FormatAnswer`], implemented for the shapes
+//! answers come in:
+//!
+//! - numbers and strings as they are, booleans as `YES` or `NO`,
+//! - `None` as `-1`, the answer of problems without a solution,
+//! - tuples on a single line, as in `min max`,
+//! - lists one element per line, such as the answers of several queries,
+//!   lists of lists one row per line, such as matrices.
+//!
+//! Answers are written into one growing buffer, so a list of 10^5 numbers
+//! costs no string per number.
+
+use std::fmt::Write;
+
+/// An answer the judge reads
+pub trait FormatAnswer {
+    /// Append the answer as the whole output to `out`
+    fn write_answer(&self, out: &mut String);
+
+    /// Append the answer as a single line of a larger output, which only
+    /// differs for lists, space separated rather than one element per line
+    fn write_line(&self, out: &mut String) {
+        self.write_answer(out);
+    }
+
+    /// The answer as the whole output
+    fn format_answer(&self) -> String {
+        let mut out = String::new();
+
+        self.write_answer(&mut out);
+        out
+    }
+}
+
+macro_rules! display_answer {
+    ($($ty:ty),*) => {
+        $(
+            impl FormatAnswer for $ty {
+                fn write_answer(&self, out: &mut String) {
+                    write!(out, "{}", self).expect("writing to a String cannot fail");
+                }
+            }
+        )*
+    };
+}
+
+display_answer!(
+    i8, i16, i32, i64, u8, u16, u32, u64, usize, isize, f64, str, String
+);
+
+impl FormatAnswer for bool {
+    fn write_answer(&self, out: &mut String) {
+        out.push_str(match self {
+            true => "YES",
+            _ => "NO",
+        });
+    }
+}
+
+impl<T: FormatAnswer> FormatAnswer for Option<T> {
+    fn write_answer(&self, out: &mut String) {
+        match self {
+            Some(answer) => answer.write_answer(out),
+            None => out.push_str("-1"),
+        }
+    }
+
+    fn write_line(&self, out: &mut String) {
+        match self {
+            Some(answer) => answer.write_line(out),
+            None => out.push_str("-1"),
+        }
+    }
+}
+
+/// Lines of `items` separated by `separator`
+fn write_items<T: FormatAnswer>(items: &[T], separator: char, out: &mut String) {
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 {
+            out.push(separator);
+        }
+
+        item.write_line(out);
+    }
+}
+
+impl<T: FormatAnswer> FormatAnswer for [T] {
+    fn write_answer(&self, out: &mut String) {
+        write_items(self, '\n', out);
+    }
+
+    fn write_line(&self, out: &mut String) {
+        write_items(self, ' ', out);
+    }
+}
+
+impl<T: FormatAnswer> FormatAnswer for Vec<T> {
+    fn write_answer(&self, out: &mut String) {
+        self.as_slice().write_answer(out);
+    }
+
+    fn write_line(&self, out: &mut String) {
+        self.as_slice().write_line(out);
+    }
+}
+
+macro_rules! tuple_answer {
+    ($first:ident $(, $ty:ident)*) => {
+        impl<$first: FormatAnswer $(, $ty: FormatAnswer)*> FormatAnswer for ($first, $($ty,)*) {
+            #[allow(non_snake_case)]
+            fn write_answer(&self, out: &mut String) {
+                let ($first, $($ty,)*) = self;
+
+                $first.write_line(out);
+                $(
+                    out.push(' ');
+                    $ty.write_line(out);
+                )*
+            }
+        }
+    };
+}
+
+tuple_answer!(A, B);
+tuple_answer!(A, B, C);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_answer_01() {
+        assert_eq!(42.format_answer(), "42");
+        assert_eq!("abc".format_answer(), "abc");
+        assert_eq!(true.format_answer(), "YES");
+        assert_eq!(None::<String>.format_answer(), "-1");
+        assert_eq!(Some(3993).format_answer(), "3993");
+        assert_eq!((-3, 7).format_answer(), "-3 7");
+    }
+
+    #[test]
+    fn format_answer_02() {
+        assert_eq!(vec![1, 2, 3].format_answer(), "1\n2\n3");
+        assert_eq!(vec![true, false].format_answer(), "YES\nNO");
+        assert_eq!(vec![vec![1, 2], vec![3, 4]].format_answer(), "1 2\n3 4");
+        assert_eq!(vec![(1, 2), (3, 4)].format_answer(), "1 2\n3 4");
+        assert_eq!(Vec::<i32>::new().format_answer(), "");
+    }
+}
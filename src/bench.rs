@@ -0,0 +1,665 @@
+//! Timing of solutions on growing inputs.
+//!
+//! Every [`Solutions`] entry of a problem is timed on generated inputs of
+//! doubling size, each run being repeated to report the median and the 90th
+//! percentile. Medians are saved as a baseline in `baselines/<slug>.txt`, and a
+//! later run fails when a solution got slower than its baseline by more than a
+//! threshold.
+//!
+//! Reading is timed on its own as well, splitting the largest inputs problems
+//! generate into tokens with the byte [`Scanner`] and parsing them. Its tests
+//! time the line splitting tokenizer the scanner replaced against it.
+
+use std::{
+    fmt, fs,
+    hint::black_box,
+    io,
+    ops::Range,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answer::FormatAnswer,
+    complexity::Complexity,
+    error::Result,
+    generate::{Generate, Rng, Shape},
+    problem::Problem,
+    scanner::Scanner,
+};
+
+/// Directory holding one baseline file per problem
+pub const BASELINES_DIR: &str = "baselines";
+
+/// Slowdowns below this are noise whatever the threshold, which keeps tiny
+/// sizes timed in microseconds from failing a run
+pub const NOISE: Duration = Duration::from_micros(200);
+
+/// A solution of a problem taking the parsed input
+pub type Solution<P> = fn(<P as Problem>::Input) -> Result<<P as Problem>::Output>;
+
+/// Formatted answer of a named solution and the time it took to solve
+pub type Answer = (&'static str, Result<String>, Duration);
+
+/// A problem whose solutions can be timed
+pub trait Solutions: Generate {
+    /// Growth of the optimized solution in the [`Solutions::measure`] of its
+    /// input, checked by [`complexity`](crate::complexity)
+    const COMPLEXITY: Complexity;
+
+    /// The `n` of [`Solutions::COMPLEXITY`] for an input, the length of its
+    /// text unless the problem grows with something else
+    fn measure(input: &Self::Input) -> usize {
+        Self::write(input).len()
+    }
+
+    /// Named solutions to time, the optimized [`Problem::solve`] first
+    fn solutions() -> Vec<(&'static str, Solution<Self>)> {
+        vec![("optimized", Self::solve)]
+    }
+}
+
+/// Object safe view of a [`Solutions`] problem for the bench registry
+pub trait Bench: Sync {
+    fn slug(&self) -> &'static str;
+
+    fn max_size(&self) -> usize;
+
+    fn complexity(&self) -> Complexity;
+
+    /// Total [`Solutions::measure`] of the inputs of all `seeds` and total
+    /// time of the optimized solution on them
+    fn time(&self, size: usize, seeds: Range<u64>, shape: Shape) -> Result<(usize, Duration)>;
+
+    /// Time every solution at every size of `options`
+    fn measure(&self, options: Options) -> Result<Vec<Sample>>;
+
+    /// Names of the solutions, the optimized one first
+    fn variants(&self) -> Vec<&'static str>;
+
+    /// Answer of every solution to a raw input, which fails when the input
+    /// does not parse or breaks a constraint
+    fn answers(&self, input: &[u8]) -> Result<Vec<Answer>>;
+
+    /// Time reading a generated input of `options.size`, clamped to the
+    /// largest size the problem allows
+    fn reading(&self, options: Options) -> Result<Reading>;
+}
+
+impl<P> Bench for P
+where
+    P: Solutions + Sync,
+    P::Input: Clone,
+{
+    fn slug(&self) -> &'static str {
+        P::SLUG
+    }
+
+    fn max_size(&self) -> usize {
+        P::MAX_SIZE
+    }
+
+    fn complexity(&self) -> Complexity {
+        P::COMPLEXITY
+    }
+
+    fn time(&self, size: usize, seeds: Range<u64>, shape: Shape) -> Result<(usize, Duration)> {
+        let mut measure = 0;
+        let mut total = Duration::ZERO;
+
+        for seed in seeds {
+            let input = P::generate(&mut Rng::new(seed), size.clamp(1, P::MAX_SIZE), shape);
+
+            measure += P::measure(&input);
+
+            let start = Instant::now();
+            let output = black_box(P::solve(input));
+
+            total += start.elapsed();
+            output?;
+        }
+
+        Ok((measure, total))
+    }
+
+    fn measure(&self, options: Options) -> Result<Vec<Sample>> {
+        let mut samples = Vec::new();
+
+        for size in sizes(options.size.clamp(1, P::MAX_SIZE), options.steps) {
+            let mut rng = Rng::new(options.seed.wrapping_add(size as u64));
+            let input = P::generate(&mut rng, size, options.shape);
+
+            for (name, solution) in P::solutions() {
+                let mut timings = Vec::with_capacity(options.runs);
+
+                for _ in 0..options.runs.max(1) {
+                    let input = input.clone();
+                    let start = Instant::now();
+                    let output = black_box(solution(input));
+
+                    timings.push(start.elapsed());
+                    output?;
+                }
+
+                samples.push(Sample::new(name, size, timings));
+            }
+        }
+
+        Ok(samples)
+    }
+
+    fn variants(&self) -> Vec<&'static str> {
+        P::solutions().into_iter().map(|(name, _)| name).collect()
+    }
+
+    fn answers(&self, input: &[u8]) -> Result<Vec<Answer>> {
+        let input = P::parse(input)?;
+
+        P::validate(&input)?;
+
+        Ok(P::solutions()
+            .into_iter()
+            .map(|(name, solution)| {
+                let input = input.clone();
+                let start = Instant::now();
+                let output = black_box(solution(input));
+                let time = start.elapsed();
+
+                (name, output.map(|output| output.format_answer()), time)
+            })
+            .collect())
+    }
+
+    fn reading(&self, options: Options) -> Result<Reading> {
+        let size = options.size.clamp(1, P::MAX_SIZE);
+        let text = P::write(&P::generate(
+            &mut Rng::new(options.seed),
+            size,
+            options.shape,
+        ));
+        let (scanner, tokens) = median(options.runs, || count_tokens(text.as_bytes()));
+        let (parse, input) = median(options.runs, || P::parse(text.as_bytes()));
+
+        input?;
+
+        Ok(Reading {
+            slug: P::SLUG,
+            size,
+            bytes: text.len(),
+            tokens,
+            scanner,
+            parse,
+        })
+    }
+}
+
+/// Sizes to time and how often
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// Seed of the inputs, each size deriving its own from it
+    pub seed: u64,
+    /// Largest size, the smaller ones halving it
+    pub size: usize,
+    pub steps: usize,
+    /// Runs of each solution at each size
+    pub runs: usize,
+    pub shape: Shape,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            seed: 0,
+            size: 1000,
+            steps: 5,
+            runs: 9,
+            shape: Shape::Random,
+        }
+    }
+}
+
+/// Timings of one solution at one size
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    pub solution: &'static str,
+    pub size: usize,
+    pub median: Duration,
+    pub p90: Duration,
+}
+
+impl Sample {
+    fn new(solution: &'static str, size: usize, mut timings: Vec<Duration>) -> Self {
+        timings.sort();
+
+        Sample {
+            solution,
+            size,
+            median: percentile(&timings, 50),
+            p90: percentile(&timings, 90),
+        }
+    }
+}
+
+/// Samples printed as a table, one row per solution and size
+pub struct Table<'a>(pub &'a [Sample]);
+
+impl fmt::Display for Table<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:<12} {:>8} {:>12} {:>12}",
+            "solution", "size", "median", "p90"
+        )?;
+
+        for sample in self.0 {
+            write!(
+                f,
+                "\n{:<12} {:>8} {:>12.1?} {:>12.1?}",
+                sample.solution, sample.size, sample.median, sample.p90
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A solution whose median got slower than its baseline
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub solution: String,
+    pub size: usize,
+    pub baseline: Duration,
+    pub median: Duration,
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at size {}: {:.1?} against {:.1?} ({:+.0}%)",
+            self.solution,
+            self.size,
+            self.median,
+            self.baseline,
+            (self.median.as_secs_f64() / self.baseline.as_secs_f64() - 1.0) * 100.0
+        )
+    }
+}
+
+/// Median time to read one generated input
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reading {
+    pub slug: &'static str,
+    pub size: usize,
+    pub bytes: usize,
+    pub tokens: usize,
+    /// Splitting every token with the byte [`Scanner`]
+    pub scanner: Duration,
+    /// Parsing the whole input, values included
+    pub parse: Duration,
+}
+
+/// Readings printed as a table, one row per problem
+pub struct ReadingTable<'a>(pub &'a [Reading]);
+
+impl fmt::Display for ReadingTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:<32} {:>8} {:>10} {:>9} {:>10} {:>10}",
+            "problem", "size", "bytes", "tokens", "scanner", "parse"
+        )?;
+
+        for reading in self.0 {
+            write!(
+                f,
+                "\n{:<32} {:>8} {:>10} {:>9} {:>10.1?} {:>10.1?}",
+                reading.slug,
+                reading.size,
+                reading.bytes,
+                reading.tokens,
+                reading.scanner,
+                reading.parse
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Number of tokens the scanner splits `input` into
+fn count_tokens(input: &[u8]) -> usize {
+    let mut scanner = Scanner::new(input);
+
+    (0..).take_while(|_| scanner.token().is_ok()).count()
+}
+
+/// Median time of `runs` calls to `f`, along with what the last one returned
+fn median<T>(runs: usize, mut f: impl FnMut() -> T) -> (Duration, T) {
+    let mut timings = Vec::with_capacity(runs.max(1));
+    let mut result = None;
+
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let value = black_box(f());
+
+        timings.push(start.elapsed());
+        // Dropping the result of the previous run is not part of this one
+        result = Some(value);
+    }
+
+    timings.sort();
+
+    (percentile(&timings, 50), result.expect("at least one run"))
+}
+
+/// `steps` sizes doubling up to `max`, without repeats
+pub fn sizes(max: usize, steps: usize) -> Vec<usize> {
+    let mut sizes = (0..steps.max(1))
+        .rev()
+        .map(|step| max.checked_shr(step as u32).unwrap_or(0).max(1))
+        .collect::<Vec<_>>();
+
+    sizes.dedup();
+    sizes
+}
+
+/// Value below which `percent` of the sorted `timings` fall
+pub fn percentile(timings: &[Duration], percent: usize) -> Duration {
+    match timings.len() {
+        0 => Duration::ZERO,
+        len => timings[(len - 1) * percent / 100],
+    }
+}
+
+/// Location of the baseline of a problem
+pub fn baseline_path(slug: &str) -> PathBuf {
+    [BASELINES_DIR, &format!("{}.txt", slug)].iter().collect()
+}
+
+/// Baseline text: the number of entries, then one `solution size nanoseconds`
+/// line per sample
+pub fn write_baseline(samples: &[Sample]) -> String {
+    let mut text = format!("{}\n", samples.len());
+
+    for sample in samples {
+        text += &format!(
+            "{} {} {}\n",
+            sample.solution,
+            sample.size,
+            sample.median.as_nanos()
+        );
+    }
+
+    text
+}
+
+/// Entries of a baseline text as `(solution, size, median)`
+pub fn parse_baseline(text: &str) -> Result<Vec<(String, usize, Duration)>> {
+    let mut scanner = Scanner::new(text);
+    let n = scanner.next::<usize>()?;
+
+    (0..n)
+        .map(|_| {
+            let solution = scanner.next::<String>()?;
+            let size = scanner.next::<usize>()?;
+            let nanos = scanner.next::<u64>()?;
+
+            Ok((solution, size, Duration::from_nanos(nanos)))
+        })
+        .collect()
+}
+
+/// Saved baseline of a problem, `None` when there is none yet
+pub fn load_baseline(slug: &str) -> Result<Option<Vec<(String, usize, Duration)>>> {
+    match fs::read_to_string(baseline_path(slug)) {
+        Ok(text) => Ok(Some(parse_baseline(&text)?)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub fn save_baseline(slug: &str, samples: &[Sample]) -> io::Result<PathBuf> {
+    let path = baseline_path(slug);
+
+    fs::create_dir_all(BASELINES_DIR)?;
+    fs::write(&path, write_baseline(samples))?;
+
+    Ok(path)
+}
+
+/// Samples slower than their baseline entry by more than `threshold` percent
+/// and by more than [`NOISE`]. Samples missing from the baseline are skipped.
+pub fn regressions(
+    samples: &[Sample],
+    baseline: &[(String, usize, Duration)],
+    threshold: f64,
+) -> Vec<Regression> {
+    samples
+        .iter()
+        .filter_map(|sample| {
+            let (_, _, before) = baseline
+                .iter()
+                .find(|(solution, size, _)| solution == sample.solution && *size == sample.size)?;
+            let limit = before.mul_f64(1.0 + threshold / 100.0).max(*before + NOISE);
+
+            (sample.median > limit).then(|| Regression {
+                solution: sample.solution.to_string(),
+                size: sample.size,
+                baseline: *before,
+                median: sample.median,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::Lines;
+
+    use super::*;
+    use crate::algorithm;
+
+    fn millis(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    /// Tokens split the way the scanner did before working on bytes: line by
+    /// line, trimming and searching for Unicode whitespace
+    struct LineTokens<'a> {
+        lines: Lines<'a>,
+        rest: &'a str,
+    }
+
+    impl<'a> LineTokens<'a> {
+        fn new(text: &'a str) -> Self {
+            LineTokens {
+                lines: text.lines(),
+                rest: "",
+            }
+        }
+    }
+
+    impl<'a> Iterator for LineTokens<'a> {
+        type Item = &'a str;
+
+        fn next(&mut self) -> Option<&'a str> {
+            loop {
+                let rest = self.rest.trim_start();
+
+                if !rest.is_empty() {
+                    let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+
+                    self.rest = &rest[end..];
+
+                    return Some(black_box(&rest[..end]));
+                }
+
+                self.rest = self.lines.next()?;
+            }
+        }
+    }
+
+    #[test]
+    fn reading_01() {
+        let text = "3\r\n ab\tc  \r\n\r\nd e\n";
+        let mut scanner = Scanner::new(text);
+        let tokens = (0..)
+            .map_while(|_| scanner.token().ok())
+            .collect::<Vec<_>>();
+
+        assert_eq!(tokens, LineTokens::new(text).collect::<Vec<_>>());
+
+        for bench in algorithm::benches() {
+            let options = Options {
+                runs: 1,
+                size: 50,
+                ..Options::default()
+            };
+            let reading = bench.reading(options).unwrap();
+
+            assert!(reading.tokens > 0, "{} read no token", bench.slug());
+        }
+    }
+
+    /// The line tokenizer against the scanner on the largest random input of
+    /// every problem, a benchmark rather than a test, run with
+    /// `cargo test --release reading_02 -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn reading_02() {
+        println!(
+            "{:<32} {:>9} {:>10} {:>10}",
+            "problem", "tokens", "lines", "scanner"
+        );
+
+        for registration in algorithm::PROBLEMS {
+            let Some(generator) = registration.generator else {
+                continue;
+            };
+            let text = generator
+                .sample(0, generator.max_size(), Shape::Random)
+                .unwrap();
+            let input = text.into_bytes();
+            // The line tokenizer needs the whole input checked to be UTF-8
+            // first, the scanner only checks its tokens
+            let (lines, tokens) = median(9, || {
+                LineTokens::new(std::str::from_utf8(&input).unwrap()).count()
+            });
+            let (scanner, count) = median(9, || count_tokens(&input));
+
+            assert_eq!(count, tokens);
+            println!(
+                "{:<32} {:>9} {:>10.1?} {:>10.1?}",
+                registration.problem.slug(),
+                tokens,
+                lines,
+                scanner
+            );
+        }
+    }
+
+    #[test]
+    fn sizes_01() {
+        assert_eq!(sizes(1000, 5), vec![62, 125, 250, 500, 1000]);
+        assert_eq!(sizes(3, 5), vec![1, 3]);
+        assert_eq!(sizes(7, 0), vec![7]);
+    }
+
+    #[test]
+    fn percentile_01() {
+        let timings = (1..=10).map(millis).collect::<Vec<_>>();
+
+        assert_eq!(percentile(&timings, 50), millis(5));
+        assert_eq!(percentile(&timings, 90), millis(9));
+        assert_eq!(percentile(&timings[..1], 90), millis(1));
+        assert_eq!(percentile(&[], 50), Duration::ZERO);
+    }
+
+    #[test]
+    fn baseline_01() {
+        let samples = vec![
+            Sample::new("optimized", 10, vec![millis(3), millis(1), millis(2)]),
+            Sample::new("reference", 10, vec![millis(40)]),
+        ];
+        let baseline = parse_baseline(&write_baseline(&samples)).unwrap();
+
+        assert_eq!(
+            baseline,
+            vec![
+                ("optimized".to_string(), 10, millis(2)),
+                ("reference".to_string(), 10, millis(40))
+            ]
+        );
+        assert!(parse_baseline("2\noptimized 10 5\n").is_err());
+    }
+
+    #[test]
+    fn regressions_01() {
+        let baseline = vec![
+            ("optimized".to_string(), 100, millis(10)),
+            ("optimized".to_string(), 200, Duration::from_micros(10)),
+        ];
+        let samples = vec![
+            Sample::new("optimized", 100, vec![millis(13)]),
+            // Three times slower, but within the noise
+            Sample::new("optimized", 200, vec![Duration::from_micros(30)]),
+            Sample::new("reference", 100, vec![millis(100)]),
+        ];
+
+        assert_eq!(regressions(&samples, &baseline, 50.0), vec![]);
+
+        let found = regressions(&samples, &baseline, 20.0);
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].size, 100);
+        assert_eq!(
+            found[0].to_string(),
+            "optimized at size 100: 13.0ms against 10.0ms (+30%)"
+        );
+    }
+
+    #[test]
+    fn bench_01() {
+        // Scaffolded problems get timed solutions once they are solved
+        for registration in algorithm::PROBLEMS {
+            let slug = registration.problem.slug();
+
+            if !registration.is_scaffold() {
+                assert!(
+                    algorithm::find_bench(slug).is_some(),
+                    "{} cannot be benchmarked",
+                    slug
+                );
+            }
+        }
+
+        let options = Options {
+            size: 40,
+            steps: 3,
+            runs: 3,
+            ..Options::default()
+        };
+        let samples = algorithm::find_bench("insertion_sort_analysis")
+            .unwrap()
+            .measure(options)
+            .unwrap();
+        let rows = samples
+            .iter()
+            .map(|sample| (sample.solution, sample.size))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            rows,
+            vec![
+                ("optimized", 10),
+                ("reference", 10),
+                ("optimized", 20),
+                ("reference", 20),
+                ("optimized", 40),
+                ("reference", 40)
+            ]
+        );
+        assert!(samples.iter().all(|sample| sample.median <= sample.p90));
+    }
+}
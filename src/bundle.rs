@@ -0,0 +1,2147 @@
+//! Single file submissions.
+//!
+//! HackerRank takes one file, while solutions share code through the crate.
+//! The problem module and the modules it reaches through `crate::` paths are
+//! copied into one `main.rs`, each nested the way it is in the crate so the
+//! paths keep resolving, and a `main` judging stdin through
+//! [`harness`](crate::harness) is added. Test modules, the impls and imports
+//! serving the local commands only and debug printing are left out, then
+//! whatever that `main` does not reach: items, members of impls and traits,
+//! variants nothing builds, fields nothing reads and the modules left empty.
+//! A debug statement leaves a borrow of the locals it names behind, so they
+//! stay used. The result is compiled on its own with `rustc`, warnings
+//! denied, before being trusted, so code only a stripped debug statement
+//! called fails it.
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    env, fs, io, iter,
+    ops::Range,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Directory the crate sources are read from
+pub const SRC_DIR: &str = "src";
+
+/// Edition the bundle is compiled with, same as the crate
+pub const EDITION: &str = "2024";
+
+/// Modules of the local commands, never part of a submission
+const TOOLING: &[&str] = &[
+    "bench",
+    "complexity",
+    "explain",
+    "fixture",
+    "generate",
+    "shrink",
+    "stress",
+    "trace",
+];
+
+/// Traits of those modules, whose impls are dropped along with them
+const TOOLING_TRAITS: &[&str] = &["Generate", "Reference", "Shrink", "Solutions"];
+
+/// Macros whose statements are debug output, tracing or explanations, the
+/// answer being written by the judge stub only
+const DEBUG_MACROS: &[&str] = &[
+    "dbg", "eprint", "eprintln", "explain", "print", "println", "trace",
+];
+
+/// Keywords that can appear among the arguments of a debug macro
+const KEYWORDS: &[&str] = &[
+    "as", "else", "false", "if", "in", "let", "match", "move", "mut", "ref", "true",
+];
+
+/// Traits of the standard library imported for what their methods and the
+/// macros calling them need rather than by name
+const STD_TRAITS: &[(&str, &str)] = &[
+    ("FromStr", "from_str"),
+    ("Read", "read_to_end"),
+    ("Read", "read_to_string"),
+    ("Write", "flush"),
+    ("Write", "write"),
+    ("Write", "write_all"),
+    ("Write", "writeln"),
+];
+
+const HEADER: &str = "//! `{{slug}}` and the crate code it uses, bundled into a single file by
+//! `hackerank bundle`.
+
+";
+
+/// Judge stub reading stdin and writing the answer to `OUTPUT_PATH`
+const MAIN: &str = "fn main() {
+    let input = harness::read_stdin().expect(\"reading stdin\");
+
+    if let Err(e) = harness::judge(&algorithm::{{slug}}::{{name}}, &input) {
+        eprintln!(\"error: {}\", e);
+        std::process::exit(e.exit_code());
+    }
+}
+";
+
+/// A module stripped down to what a submission needs
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stripped {
+    pub text: String,
+    /// Segments of the `crate::` paths it still uses
+    pub paths: Vec<Vec<String>>,
+}
+
+/// What an item of a module is, as far as bundling cares
+#[derive(Debug, Clone, PartialEq)]
+enum Kind {
+    Use,
+    /// Impl for the type of that name, of a trait unless inherent
+    Impl {
+        trait_name: Option<String>,
+        self_type: String,
+    },
+    Named {
+        name: String,
+        public: bool,
+    },
+    Other,
+}
+
+/// Top level item of a module
+#[derive(Debug, Clone)]
+struct Item {
+    /// Whole text of the item, comments and attributes before it included
+    range: Range<usize>,
+    /// Start of the item proper, past its comments and attributes
+    start: usize,
+    /// End of the item proper, before a comment closing its line
+    end: usize,
+    test: bool,
+    kind: Kind,
+}
+
+/// Module of a bundle cut into its items, those of impls and traits into
+/// their members
+struct Module {
+    text: String,
+    masked: String,
+    header: usize,
+    blocks: Vec<Block>,
+}
+
+/// Top level item, with the members of its body for an impl or a trait
+struct Block {
+    item: Item,
+    /// Positions of the braces around the body of an impl or a trait
+    body: Option<(usize, usize)>,
+    members: Vec<Item>,
+}
+
+/// Bundle a problem of the `algorithm` domain, the modules it uses read from
+/// [`SRC_DIR`]
+pub fn bundle(slug: &str) -> io::Result<String> {
+    let root = vec!["algorithm".to_string(), slug.to_string()];
+    let mut modules = BTreeMap::new();
+    let mut pending = vec![root.clone(), vec!["harness".to_string()]];
+
+    while let Some(path) = pending.pop() {
+        if modules.contains_key(&path) {
+            continue;
+        }
+
+        let file = file(&path);
+        let source = fs::read_to_string(&file)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", file.display(), e)))?;
+        let stripped = strip(&source);
+
+        for used in &stripped.paths {
+            pending.push(resolve(used)?);
+        }
+
+        modules.insert(path, stripped.text);
+    }
+
+    let name = problem_name(&modules[&root]).ok_or_else(|| {
+        invalid(format!(
+            "no `impl Problem for` or `impl Cases for` in {}",
+            file(&root).display()
+        ))
+    })?;
+    let main = MAIN.replace("{{slug}}", slug).replace("{{name}}", &name);
+
+    // Dropping entries can leave items unused and the other way around
+    loop {
+        let pruned = prune_entries(prune(modules.clone(), &main));
+
+        if pruned == modules {
+            break;
+        }
+
+        modules = pruned;
+    }
+
+    let modules = modules
+        .iter()
+        .map(|(path, text)| (path.as_slice(), text.as_str()))
+        .collect::<Vec<_>>();
+    let mut bundle = HEADER.replace("{{slug}}", slug);
+
+    nest(&mut bundle, &modules, 0);
+    bundle += &main;
+
+    Ok(bundle)
+}
+
+/// Compile a bundle on its own into `binary`, failing with the compiler
+/// output as an [`io::ErrorKind::InvalidData`] error when it does not compile
+/// or warns, which is how code only stripped debug statements called shows
+pub fn compile(bundle: &str, binary: &Path) -> io::Result<()> {
+    let source = binary.with_extension("rs");
+
+    fs::write(&source, bundle)?;
+
+    let output = Command::new(env::var_os("RUSTC").unwrap_or("rustc".into()))
+        .args([
+            "--edition",
+            EDITION,
+            "--crate-name",
+            "main",
+            "-D",
+            "warnings",
+            "-o",
+        ])
+        .arg(binary)
+        .arg(&source)
+        .output();
+
+    let _ = fs::remove_file(&source);
+    let output = output?;
+
+    match output.status.success() {
+        true => Ok(()),
+        false => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            String::from_utf8_lossy(&output.stderr)
+                .trim_end()
+                .to_string(),
+        )),
+    }
+}
+
+/// Strip a module source down to what a submission needs: test modules,
+/// tooling impls and imports, private items left unused by that and debug
+/// statements go away
+pub fn strip(source: &str) -> Stripped {
+    let source = strip_debug(&source.replace("\r\n", "\n"));
+    let masked = mask(&source);
+    let header = header_len(&source);
+    let items = split(&masked, header)
+        .into_iter()
+        .map(|range| item(&masked, range))
+        .collect::<Vec<_>>();
+    let mut kept = items
+        .iter()
+        .map(|item| !item.test && !is_tooling_impl(&item.kind))
+        .collect::<Vec<_>>();
+
+    let is_impl_of = |item: &Item, name: &str| matches!(&item.kind, Kind::Impl { self_type, .. } if self_type == name);
+
+    // Dropping an item can leave others unused, so repeat until nothing
+    // changes. A type used by its own impls only goes away with them.
+    loop {
+        let unused = (0..items.len()).find(|&index| {
+            let Kind::Named {
+                name,
+                public: false,
+            } = &items[index].kind
+            else {
+                return false;
+            };
+
+            kept[index]
+                && !items.iter().enumerate().any(|(other, item)| {
+                    other != index
+                        && kept[other]
+                        && item.kind != Kind::Use
+                        && !is_impl_of(item, name)
+                        && contains_word(&masked[item.range.clone()], name)
+                })
+        });
+        let Some(index) = unused else {
+            break;
+        };
+        let Kind::Named { name, .. } = &items[index].kind else {
+            unreachable!("only named items are unused");
+        };
+
+        kept[index] = false;
+
+        for (item, kept) in items.iter().zip(&mut kept) {
+            if is_impl_of(item, name) {
+                *kept = false;
+            }
+        }
+    }
+
+    let mut text = source[..header].to_string();
+
+    for (item, _) in items.iter().zip(&kept).filter(|(_, kept)| **kept) {
+        match item.kind {
+            Kind::Use => text += &use_text(&source, &masked, item, |leaf| !is_tooling_path(leaf)),
+            _ => text += &source[item.range.clone()],
+        }
+    }
+
+    let paths = crate_paths(&mask(&text));
+
+    Stripped {
+        text: text.trim().to_string() + "\n",
+        paths,
+    }
+}
+
+/// Text of a use item keeping the leaves `needed` accepts, one use per leaf
+/// once some are left out
+fn use_text(source: &str, masked: &str, item: &Item, needed: impl Fn(&str) -> bool) -> String {
+    let code = &masked[item.start..item.end];
+    let keyword = code.find("use").unwrap_or(0);
+    let leaves = leaves(code[keyword + "use".len()..].trim_end_matches(';'));
+    let needed = leaves
+        .iter()
+        .filter(|leaf| needed(leaf))
+        .collect::<Vec<_>>();
+
+    if needed.len() == leaves.len() {
+        return source[item.range.clone()].to_string();
+    }
+
+    if needed.is_empty() {
+        return String::new();
+    }
+
+    let visibility = &code[..keyword];
+
+    source[item.range.start..item.start].to_string()
+        + &needed
+            .iter()
+            .map(|leaf| format!("{}use {};", visibility, leaf))
+            .collect::<Vec<_>>()
+            .join("\n")
+        + &source[item.end..item.range.end]
+}
+
+impl Module {
+    fn new(text: String) -> Self {
+        let masked = mask(&text);
+        let header = header_len(&text);
+        let blocks = split(&masked, header)
+            .into_iter()
+            .map(|range| {
+                let item = item(&masked, range);
+                let code = &masked[item.start..item.end];
+                let body = match item.kind {
+                    Kind::Impl { .. } => code.find('{'),
+                    Kind::Named { .. } if keyword(code) == Some("trait") => code.find('{'),
+                    _ => None,
+                }
+                .and_then(|open| Some((item.start + open, matching(&masked, item.start + open)?)));
+                let members = body.map_or(vec![], |(open, close)| {
+                    split(&masked[..close], open + 1)
+                        .into_iter()
+                        .map(|range| self::item(&masked, range))
+                        .collect()
+                });
+
+                Block {
+                    item,
+                    body,
+                    members,
+                }
+            })
+            .collect();
+
+        Module {
+            text,
+            masked,
+            header,
+            blocks,
+        }
+    }
+
+    /// Ranges of the code of a block, its kept members included and the
+    /// others left out
+    fn code(&self, block: &Block, kept: &[bool]) -> Vec<Range<usize>> {
+        let range = block.item.range.clone();
+        let Some((open, close)) = block.body else {
+            return vec![range];
+        };
+        let members = block
+            .members
+            .iter()
+            .zip(kept)
+            .filter(|(_, kept)| **kept)
+            .map(|(member, _)| member.range.clone());
+
+        iter::once(range.start..open + 1)
+            .chain(members)
+            .chain(iter::once(close..range.end))
+            .collect()
+    }
+}
+
+/// Drop the items of `modules` that neither `main` nor any item kept uses,
+/// then the modules left without items. A name on its own refers to an item
+/// of its module or to one it imports, and after `module::` to an item of
+/// that module. An impl stays along with its type and trait, a member of an
+/// impl or a trait once some `.name(` or `Path::name` uses its name, and
+/// anything else along with the other items of its module. A `Path::name`
+/// keeps the members of that type only when it is a struct or an enum of the
+/// modules, and those of traits and their impls only when it is not, so no
+/// `Vec::new` or `Type::new` keeps every `new`.
+fn prune(modules: BTreeMap<Vec<String>, String>, main: &str) -> BTreeMap<Vec<String>, String> {
+    let modules = modules
+        .into_iter()
+        .map(|(path, text)| (path, Module::new(text)))
+        .collect::<Vec<_>>();
+    let defined = modules
+        .iter()
+        .flat_map(|(_, module)| &module.blocks)
+        .filter_map(|block| match &block.item.kind {
+            Kind::Named { name, .. } => Some(name.clone()),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+    let types = modules
+        .iter()
+        .flat_map(|(_, module)| {
+            module
+                .blocks
+                .iter()
+                .filter_map(|block| match &block.item.kind {
+                    Kind::Named { name, .. }
+                        if matches!(
+                            keyword(&module.masked[block.item.start..block.item.end]),
+                            Some("struct" | "enum" | "union")
+                        ) =>
+                    {
+                        Some(name.clone())
+                    }
+                    _ => None,
+                })
+        })
+        .collect::<HashSet<_>>();
+    let mut uses = Uses {
+        items: HashSet::new(),
+        members: HashSet::new(),
+        typed: HashSet::new(),
+        traits: HashSet::new(),
+        types,
+        modules: HashMap::new(),
+    };
+
+    for (index, (path, _)) in modules.iter().enumerate() {
+        uses.modules
+            .entry(path[path.len() - 1].clone())
+            .or_default()
+            .push(index);
+    }
+
+    let imports = modules
+        .iter()
+        .map(|(_, module)| {
+            module
+                .blocks
+                .iter()
+                .filter(|block| block.item.kind == Kind::Use)
+                .flat_map(|block| {
+                    let code = &module.masked[block.item.start..block.item.end];
+                    let tree =
+                        code[code.find("use").unwrap_or(0) + "use".len()..].trim_end_matches(';');
+
+                    leaves(tree)
+                })
+                .filter_map(|leaf| {
+                    let (path, alias) = leaf.split_once(" as ").unwrap_or((&leaf, ""));
+                    let (from, name) = path.rsplit_once("::")?;
+                    let from = from.rsplit("::").next().unwrap_or(from);
+                    let alias = if alias.is_empty() { name } else { alias };
+
+                    Some((alias.to_string(), from.to_string(), name.to_string()))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut kept = modules
+        .iter()
+        .map(|(_, module)| {
+            module
+                .blocks
+                .iter()
+                .map(|block| (false, vec![false; block.members.len()]))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    uses.add(None, &mask(main));
+
+    loop {
+        let count = uses.len();
+        let names = modules
+            .iter()
+            .zip(&kept)
+            .flat_map(|((_, module), kept)| module.blocks.iter().zip(kept))
+            .filter_map(|(block, (kept, _))| match &block.item.kind {
+                Kind::Named { name, .. } if *kept => Some(name.clone()),
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+
+        for (index, (_, module)) in modules.iter().enumerate() {
+            for (alias, from, name) in &imports[index] {
+                if uses.items.contains(&(index, alias.clone())) {
+                    uses.item(from, name);
+                }
+            }
+
+            let any = module
+                .blocks
+                .iter()
+                .zip(&kept[index])
+                .any(|(block, (kept, _))| {
+                    *kept && !matches!(block.item.kind, Kind::Use | Kind::Other)
+                });
+
+            for (block, (keep, kept)) in module.blocks.iter().zip(kept[index].iter_mut()) {
+                *keep = match &block.item.kind {
+                    Kind::Use => false,
+                    Kind::Named { name, .. } => uses.items.contains(&(index, name.clone())),
+                    Kind::Impl {
+                        trait_name,
+                        self_type,
+                    } => [Some(self_type), trait_name.as_ref()]
+                        .into_iter()
+                        .flatten()
+                        .all(|name| !defined.contains(name) || names.contains(name)),
+                    Kind::Other => any,
+                };
+
+                if !*keep {
+                    continue;
+                }
+
+                // Every member of an impl of a trait from elsewhere is needed
+                let foreign = matches!(
+                    &block.item.kind,
+                    Kind::Impl { trait_name: Some(name), .. } if !defined.contains(name)
+                );
+
+                for (member, keep) in block.members.iter().zip(kept.iter_mut()) {
+                    *keep = match &member.kind {
+                        Kind::Named { name, .. } if !foreign => uses.member(&block.item.kind, name),
+                        _ => true,
+                    };
+                }
+
+                // An inherent impl goes with the last of its members
+                let inherent = matches!(
+                    block.item.kind,
+                    Kind::Impl {
+                        trait_name: None,
+                        ..
+                    }
+                );
+
+                if inherent && !block.members.is_empty() && !kept.contains(&true) {
+                    *keep = false;
+                    continue;
+                }
+
+                for range in module.code(block, kept) {
+                    uses.add(Some(index), &module.masked[range]);
+                }
+            }
+        }
+
+        if uses.len() == count {
+            break;
+        }
+    }
+
+    let names = modules
+        .iter()
+        .zip(&kept)
+        .flat_map(|((_, module), kept)| module.blocks.iter().zip(kept))
+        .filter_map(|(block, (kept, _))| match &block.item.kind {
+            Kind::Named { name, .. } if *kept => Some(name.clone()),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+    let traits = traits(&modules);
+
+    modules
+        .into_iter()
+        .zip(kept)
+        .filter(|(_, kept)| kept.iter().any(|(kept, _)| *kept))
+        .map(|((path, module), kept)| {
+            let code = module
+                .blocks
+                .iter()
+                .zip(&kept)
+                .filter(|(block, (kept, _))| *kept && block.item.kind != Kind::Use)
+                .flat_map(|(block, (_, members))| module.code(block, members))
+                .map(|range| &module.masked[range])
+                .collect::<String>();
+            let references = references(&code);
+            let mut text = module.text[..module.header].to_string();
+
+            for (block, (keep, members)) in module.blocks.iter().zip(&kept) {
+                if block.item.kind == Kind::Use {
+                    text += &use_text(&module.text, &module.masked, &block.item, |leaf| {
+                        let name = leaf.rsplit([':', ' ']).next().unwrap_or(leaf);
+
+                        name == "*"
+                            || (!defined.contains(name) || names.contains(name))
+                                && is_imported(name, &references, &traits)
+                    });
+                } else if *keep {
+                    for range in module.code(block, members) {
+                        let code = &module.text[range];
+                        let blank = code.len() - code.trim_start().len();
+
+                        // The first member kept goes right below the brace
+                        // opening the body, without a blank line between
+                        match (text.ends_with('{'), code[..blank].rfind('\n')) {
+                            (true, Some(line)) => text += &code[line..],
+                            _ => text += code,
+                        }
+                    }
+                }
+            }
+
+            (path, text.trim().to_string() + "\n")
+        })
+        .collect()
+}
+
+/// What the code kept so far uses
+struct Uses {
+    /// Items by module and name
+    items: HashSet<(usize, String)>,
+    /// Members of impls and traits by name, through a method call or `Self`
+    members: HashSet<String>,
+    /// Members named after one of the `types`, by type and name
+    typed: HashSet<(String, String)>,
+    /// Members named after anything else, such as a trait, a generic type or
+    /// a type from elsewhere, which only traits and their impls can provide
+    traits: HashSet<String>,
+    /// Structs and enums of the modules
+    types: HashSet<String>,
+    /// Modules by name, which is what a path names them by
+    modules: HashMap<String, Vec<usize>>,
+}
+
+impl Uses {
+    /// Add what masked code of `module` uses, `None` standing for code outside
+    /// of the modules
+    fn add(&mut self, module: Option<usize>, code: &str) {
+        for (reference, word) in references(code) {
+            match reference {
+                Reference::Plain => {
+                    self.items
+                        .extend(module.map(|module| (module, word.to_string())));
+                }
+                Reference::Path(path) => {
+                    self.item(path, word);
+
+                    match path {
+                        "Self" => self.members.insert(word.to_string()),
+                        _ if self.types.contains(path) => {
+                            self.typed.insert((path.to_string(), word.to_string()))
+                        }
+                        _ => self.traits.insert(word.to_string()),
+                    };
+                }
+                Reference::Method => {
+                    self.members.insert(word.to_string());
+                }
+            }
+        }
+    }
+
+    /// Whether the member `name` of the impl or trait of `kind` is used
+    fn member(&self, kind: &Kind, name: &str) -> bool {
+        let (inherent, owner) = match kind {
+            Kind::Impl {
+                trait_name,
+                self_type,
+            } => (
+                trait_name.is_none(),
+                Some(self_type).filter(|&owner| self.types.contains(owner)),
+            ),
+            _ => (false, None),
+        };
+
+        // A member of a trait, or of an impl for a generic type, may be named
+        // after any type
+        self.members.contains(name)
+            || !inherent && self.traits.contains(name)
+            || self
+                .typed
+                .iter()
+                .any(|(path, member)| member == name && owner.is_none_or(|owner| owner == path))
+    }
+
+    /// How much is used, which stops growing once everything reached is
+    fn len(&self) -> usize {
+        self.items.len() + self.members.len() + self.typed.len() + self.traits.len()
+    }
+
+    /// Add the item `name` of the modules named `module`
+    fn item(&mut self, module: &str, name: &str) {
+        for &index in self.modules.get(module).into_iter().flatten() {
+            self.items.insert((index, name.to_string()));
+        }
+    }
+}
+
+/// Field of a struct or variant of an enum
+struct Entry {
+    module: usize,
+    /// Type it belongs to
+    owner: String,
+    name: String,
+    variant: bool,
+    /// Its text in the body of its type, the comma after it included
+    range: Range<usize>,
+    /// Body of its type
+    body: Range<usize>,
+}
+
+/// What code other than its definition does with an entry
+enum Occurrence {
+    /// Match arm of a variant, which goes with it
+    Arm(Range<usize>),
+    /// Field of a struct literal, which goes with it
+    Literal(Range<usize>),
+    /// Building a variant, reading a field, or a pattern other than a whole
+    /// match arm, any of which keeps the entry
+    Use,
+    /// A word of the same name that is not the entry
+    Other,
+}
+
+/// Drop the variants no code builds and the fields no code reads, along with
+/// the match arms and struct literal fields naming them. Items only these
+/// used are left for [`prune`] to drop.
+fn prune_entries(modules: BTreeMap<Vec<String>, String>) -> BTreeMap<Vec<String>, String> {
+    let parsed = modules
+        .values()
+        .map(|text| Module::new(text.clone()))
+        .collect::<Vec<_>>();
+    let mut entries = Vec::new();
+
+    for (index, module) in parsed.iter().enumerate() {
+        for block in &module.blocks {
+            let Kind::Named { name, .. } = &block.item.kind else {
+                continue;
+            };
+            let code = &module.masked[block.item.start..block.item.end];
+            let variant = match keyword(code) {
+                Some("enum") => true,
+                Some("struct") if !code.contains('(') => false,
+                _ => continue,
+            };
+            let Some(open) = code.find('{').map(|open| block.item.start + open) else {
+                continue;
+            };
+            let Some(close) = matching(&module.masked, open) else {
+                continue;
+            };
+
+            for range in comma_separated(&module.masked, open, close, true) {
+                let Some(entry) = entry_name(&module.masked[range.clone()]) else {
+                    continue;
+                };
+
+                entries.push(Entry {
+                    module: index,
+                    owner: name.clone(),
+                    name: entry.to_string(),
+                    variant,
+                    range,
+                    body: open..close,
+                });
+            }
+        }
+    }
+
+    let mut removed = vec![Vec::new(); parsed.len()];
+
+    for entry in &entries {
+        let mut ranges = vec![(entry.module, entry.range.clone())];
+        let needed = parsed.iter().enumerate().any(|(index, module)| {
+            words_at(&module.masked)
+                .into_iter()
+                .filter(|&(start, word)| {
+                    word == entry.name && !(index == entry.module && entry.body.contains(&start))
+                })
+                .any(|(start, _)| match occurrence(module, start, entry) {
+                    Occurrence::Arm(range) | Occurrence::Literal(range) => {
+                        ranges.push((index, range));
+                        false
+                    }
+                    Occurrence::Use => true,
+                    Occurrence::Other => false,
+                })
+        });
+
+        if !needed {
+            for (index, range) in ranges {
+                removed[index].push(range);
+            }
+        }
+    }
+
+    modules
+        .into_iter()
+        .zip(removed)
+        .map(|((path, text), mut removed)| {
+            let mut result = String::new();
+            let mut at = 0;
+
+            removed.sort_by_key(|range| range.start);
+
+            for range in removed {
+                if range.start >= at {
+                    result += &text[at..range.start];
+                    at = range.end;
+                }
+            }
+
+            (path, result + &text[at..])
+        })
+        .collect()
+}
+
+/// What the word at `start` of `module` does with `entry`, whose name it is
+fn occurrence(module: &Module, start: usize, entry: &Entry) -> Occurrence {
+    let masked = &module.masked;
+    let end = start + entry.name.len();
+    let before = &masked[..start];
+    let self_type = module
+        .blocks
+        .iter()
+        .find(|block| block.item.range.contains(&start))
+        .and_then(|block| match &block.item.kind {
+            Kind::Impl { self_type, .. } => Some(self_type),
+            _ => None,
+        });
+    let is_owner =
+        |word: &str| word == entry.owner || word == "Self" && self_type == Some(&entry.owner);
+
+    if entry.variant {
+        // An unqualified variant may be imported, so only paths are followed
+        let Some(path) = before.strip_suffix("::") else {
+            return Occurrence::Use;
+        };
+        let owner = &path[path.trim_end_matches(is_word_char).len()..];
+        let mut path_start = path.len() - owner.len();
+
+        if !is_owner(owner) {
+            return Occurrence::Other;
+        }
+
+        while let Some(path) = masked[..path_start].strip_suffix("::") {
+            path_start = path.trim_end_matches(is_word_char).len();
+        }
+
+        let pattern_end = skip_group(masked, end);
+        let arm = masked[pattern_end..].starts_with("=>")
+            && masked[..path_start].trim_end().ends_with(['{', ',', '}']);
+
+        return match arm {
+            true => Occurrence::Arm(arm_range(masked, path_start, pattern_end + "=>".len())),
+            false => Occurrence::Use,
+        };
+    }
+
+    if before.ends_with('.') && !before.ends_with("..") {
+        return match masked[end..].starts_with('(') {
+            true => Occurrence::Other,
+            false => Occurrence::Use,
+        };
+    }
+
+    let Some(open) = opening(masked, start) else {
+        return Occurrence::Other;
+    };
+    let owner = masked[..open].trim_end();
+    let owner = &owner[owner.trim_end_matches(is_word_char).len()..];
+
+    if masked.as_bytes()[open] != b'{'
+        || !is_owner(owner)
+        || !before.trim_end().ends_with(['{', ','])
+    {
+        return Occurrence::Other;
+    }
+
+    let Some(close) = matching(masked, open) else {
+        return Occurrence::Use;
+    };
+    let rest = masked[close + 1..].trim_start();
+    let pattern = rest.starts_with("=>")
+        || rest.starts_with('=') && !rest.starts_with("==")
+        || rest.starts_with('|') && !rest.starts_with("||");
+
+    match pattern {
+        true => Occurrence::Use,
+        false => comma_separated(masked, open, close, false)
+            .into_iter()
+            .find(|range| range.contains(&start))
+            .map_or(Occurrence::Use, Occurrence::Literal),
+    }
+}
+
+/// Ranges of the comma separated entries between the brackets at `open` and
+/// `close` of masked code, each taking the comma after it. Commas between
+/// `<` and `>` are skipped in `types`.
+fn comma_separated(masked: &str, open: usize, close: usize, types: bool) -> Vec<Range<usize>> {
+    let bytes = masked.as_bytes();
+    let mut entries = Vec::new();
+    let mut depth = 0;
+    let mut begin = open + 1;
+
+    for index in open + 1..close {
+        match bytes[index] {
+            b'(' | b'[' | b'{' => depth += 1,
+            b'<' if types => depth += 1,
+            b')' | b']' | b'}' => depth -= 1,
+            b'>' if types && bytes[index - 1] != b'-' => depth -= 1,
+            b',' if depth == 0 => {
+                entries.push(begin..index + 1);
+                begin = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    if !masked[begin..close].trim().is_empty() {
+        entries.push(begin..close);
+    }
+
+    entries
+}
+
+/// Name of a field or variant out of its masked code
+fn entry_name(code: &str) -> Option<&str> {
+    let mut code = code.trim_start();
+
+    while code.starts_with('#') {
+        code = code[matching(code, 1)? + 1..].trim_start();
+    }
+
+    words_at(code)
+        .into_iter()
+        .map(|(_, word)| word)
+        .find(|word| !["pub", "crate", "super"].contains(word))
+}
+
+/// Position past the whitespace at `at` of masked code, and past the group in
+/// brackets opening there and the whitespace after it
+fn skip_group(masked: &str, at: usize) -> usize {
+    let skip_space = |at: usize| at + masked[at..].len() - masked[at..].trim_start().len();
+    let at = skip_space(at);
+
+    match masked[at..].starts_with(['(', '{']) {
+        true => matching(masked, at).map_or(masked.len(), |close| skip_space(close + 1)),
+        false => at,
+    }
+}
+
+/// Position of the bracket left open before `position` in masked code
+fn opening(masked: &str, position: usize) -> Option<usize> {
+    let mut depth = 0;
+
+    for (index, &byte) in masked.as_bytes()[..position].iter().enumerate().rev() {
+        match byte {
+            b')' | b']' | b'}' => depth += 1,
+            b'(' | b'[' | b'{' if depth == 0 => return Some(index),
+            b'(' | b'[' | b'{' => depth -= 1,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Range of the match arm of masked code whose pattern starts at `start` and
+/// whose `=>` ends at `arrow`, with its lines when it has them to itself
+fn arm_range(masked: &str, start: usize, arrow: usize) -> Range<usize> {
+    let bytes = masked.as_bytes();
+    let body = arrow + masked[arrow..].len() - masked[arrow..].trim_start().len();
+    let mut end = match bytes.get(body) {
+        Some(b'{') => matching(masked, body).map_or(masked.len(), |close| close + 1),
+        _ => {
+            let mut depth = 0;
+            let mut end = body;
+
+            while end < bytes.len() {
+                match bytes[end] {
+                    b'(' | b'[' | b'{' => depth += 1,
+                    b')' | b']' | b'}' | b',' if depth == 0 => break,
+                    b')' | b']' | b'}' => depth -= 1,
+                    _ => {}
+                }
+
+                end += 1;
+            }
+
+            end
+        }
+    };
+
+    if masked[end..].starts_with(',') {
+        end += 1;
+    }
+
+    let line_start = masked[..start].rfind('\n').map_or(0, |n| n + 1);
+    let line_end = masked[end..].find('\n').map_or(masked.len(), |n| end + n);
+
+    match masked[line_start..start].trim().is_empty() && masked[end..line_end].trim().is_empty() {
+        true => line_start..(line_end + 1).min(masked.len()),
+        false => start..end,
+    }
+}
+
+/// Members of a trait, those taking `self` and reached through a value apart
+/// from those reached through a path
+#[derive(Debug, Default)]
+struct Members {
+    methods: HashSet<String>,
+    associated: HashSet<String>,
+}
+
+/// Members of the traits of `modules` by trait name
+fn traits(modules: &[(Vec<String>, Module)]) -> HashMap<String, Members> {
+    let mut traits = HashMap::<String, Members>::new();
+
+    for (_, module) in modules {
+        for block in &module.blocks {
+            let Kind::Named { name, .. } = &block.item.kind else {
+                continue;
+            };
+
+            if keyword(&module.masked[block.item.start..block.item.end]) != Some("trait") {
+                continue;
+            }
+
+            let members = traits.entry(name.clone()).or_default();
+
+            for member in &block.members {
+                let Kind::Named { name, .. } = &member.kind else {
+                    continue;
+                };
+                let code = &module.masked[member.start..member.end];
+                let signature = code.split('{').next().unwrap_or(code);
+                let methods = references(signature).contains(&(Reference::Plain, "self"));
+
+                match methods {
+                    true => members.methods.insert(name.clone()),
+                    false => members.associated.insert(name.clone()),
+                };
+            }
+        }
+    }
+
+    traits
+}
+
+/// True when code with `references` needs the import of `name`: it names
+/// it, or it is a trait whose members the code uses
+fn is_imported(
+    name: &str,
+    references: &[(Reference, &str)],
+    traits: &HashMap<String, Members>,
+) -> bool {
+    references.iter().any(|&(reference, word)| {
+        let by_value = |members: &Members| members.methods.contains(word);
+        let by_path = |members: &Members| members.associated.contains(word);
+
+        match reference {
+            _ if word == name => reference != Reference::Method,
+            Reference::Plain => STD_TRAITS.contains(&(name, word)),
+            Reference::Method => {
+                traits.get(name).is_some_and(by_value) || STD_TRAITS.contains(&(name, word))
+            }
+            // `<Type as Trait>::member` names its trait
+            Reference::Path("") => false,
+            Reference::Path(_) => {
+                traits.get(name).is_some_and(by_path) || STD_TRAITS.contains(&(name, word))
+            }
+        }
+    })
+}
+
+/// How code names a word
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Reference<'a> {
+    /// On its own, an item in scope or a local
+    Plain,
+    /// After `path::`, an item of a module or a member of a type or trait
+    Path(&'a str),
+    /// After a `.` and before its arguments, a method
+    Method,
+}
+
+/// Words of masked code with how they are named, field accesses left out
+fn references(masked: &str) -> Vec<(Reference<'_>, &str)> {
+    let mut references = Vec::new();
+
+    for (start, word) in words_at(masked) {
+        let (before, after) = (&masked[..start], &masked[start + word.len()..]);
+        let reference = if let Some(path) = before.strip_suffix("::") {
+            let segment = path.len() - path.trim_end_matches(is_word_char).len();
+
+            Reference::Path(&path[path.len() - segment..])
+        } else if before.ends_with('.') && !before.ends_with("..") {
+            match after.starts_with('(') || after.starts_with("::") {
+                true => Reference::Method,
+                false => continue,
+            }
+        } else {
+            Reference::Plain
+        };
+
+        references.push((reference, word));
+    }
+
+    references
+}
+
+/// Words of masked code with their positions
+fn words_at(masked: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut at = 0;
+
+    while let Some(offset) = masked[at..].find(is_word_char) {
+        let start = at + offset;
+        let end = masked[start..]
+            .find(|c: char| !is_word_char(c))
+            .map_or(masked.len(), |n| start + n);
+
+        words.push((start, &masked[start..end]));
+        at = end;
+    }
+
+    words
+}
+
+/// Keyword of the masked code of an item, `trait` out of `pub unsafe trait`
+fn keyword(code: &str) -> Option<&str> {
+    code.split(|c: char| !is_word_char(c))
+        .find(|word| !["", "pub", "crate", "super", "unsafe", "auto"].contains(word))
+}
+
+/// `source` with comments blanked out and the contents of string and char
+/// literals replaced by `~`, byte for byte and newlines kept, so positions in
+/// one are positions in the other and only code is left to look at
+fn mask(source: &str) -> String {
+    let bytes = source.as_bytes();
+    let mut masked = bytes.to_vec();
+    let mut fill = |range: Range<usize>, with: u8| {
+        for byte in &mut masked[range] {
+            if *byte != b'\n' {
+                *byte = with;
+            }
+        }
+    };
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let rest = &bytes[i..];
+        let raw = match rest {
+            [b'b', b'r', ..] => 2,
+            [b'r', ..] => 1,
+            _ => 0,
+        };
+        let hashes = rest[raw..].iter().take_while(|&&byte| byte == b'#').count();
+
+        if rest.starts_with(b"//") {
+            let end = source[i..].find('\n').map_or(bytes.len(), |n| i + n);
+
+            fill(i..end, b' ');
+            i = end;
+        } else if rest.starts_with(b"/*") {
+            let (mut end, mut depth) = (i + 2, 1);
+
+            while end < bytes.len() && depth > 0 {
+                match &bytes[end..] {
+                    [b'/', b'*', ..] => (depth, end) = (depth + 1, end + 2),
+                    [b'*', b'/', ..] => (depth, end) = (depth - 1, end + 2),
+                    _ => end += 1,
+                }
+            }
+
+            fill(i..end, b' ');
+            i = end;
+        } else if raw > 0
+            && rest.get(raw + hashes) == Some(&b'"')
+            && (i == 0 || !is_word_byte(bytes[i - 1]))
+        {
+            let start = i + raw + hashes + 1;
+            let close = format!("\"{}", "#".repeat(hashes));
+            let end = source[start..]
+                .find(&close)
+                .map_or(bytes.len(), |n| start + n);
+
+            fill(start..end, b'~');
+            i = (end + close.len()).min(bytes.len());
+        } else if rest[0] == b'"' {
+            let mut end = i + 1;
+
+            while end < bytes.len() && bytes[end] != b'"' {
+                end += if bytes[end] == b'\\' { 2 } else { 1 };
+            }
+
+            let end = end.min(bytes.len());
+
+            fill(i + 1..end, b'~');
+            i = end + 1;
+        } else if rest[0] == b'\'' {
+            // A char literal, or else a lifetime or a label
+            let end = match source[i + 1..].chars().next() {
+                Some('\\') => source[i + 3..].find('\'').map(|n| i + 3 + n),
+                Some(c) if bytes.get(i + 1 + c.len_utf8()) == Some(&b'\'') => {
+                    Some(i + 1 + c.len_utf8())
+                }
+                _ => None,
+            };
+
+            match end {
+                Some(end) => {
+                    fill(i + 1..end, b'~');
+                    i = end + 1;
+                }
+                None => i += 1,
+            }
+        } else {
+            i += 1;
+        }
+    }
+
+    String::from_utf8(masked).expect("whole characters are masked")
+}
+
+/// Length of the inner doc comments and attributes opening a source, which
+/// belong to the module rather than to its first item
+fn header_len(source: &str) -> usize {
+    source
+        .split_inclusive('\n')
+        .take_while(|line| {
+            let line = line.trim();
+
+            line.is_empty() || line.starts_with("//!") || line.starts_with("#![")
+        })
+        .map(str::len)
+        .sum()
+}
+
+/// Byte ranges of the top level items of a masked source from `start` on,
+/// each taking the comments and attributes before it and a comment closing
+/// its last line
+fn split(masked: &str, start: usize) -> Vec<Range<usize>> {
+    let bytes = masked.as_bytes();
+    let mut items = Vec::new();
+    let mut depth = 0;
+    let mut begin = start;
+    let mut i = start;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth -= 1,
+            _ => {}
+        }
+
+        let closes = depth == 0 && matches!(bytes[i], b';' | b'}');
+
+        i += 1;
+
+        if !closes {
+            continue;
+        }
+
+        // `use a::{b, c};` and `const A: B = B {};` go on past their brace
+        let next = i + masked[i..].len() - masked[i..].trim_start().len();
+
+        if bytes[i - 1] == b'}' && bytes.get(next) == Some(&b';') {
+            i = next + 1;
+        }
+
+        let line_end = masked[i..].find('\n').map_or(bytes.len(), |n| i + n);
+
+        if masked[i..line_end].trim().is_empty() {
+            i = (line_end + 1).min(bytes.len());
+        }
+
+        items.push(begin..i);
+        begin = i;
+    }
+
+    items
+}
+
+/// Classify the item of a masked source at `range`
+fn item(masked: &str, range: Range<usize>) -> Item {
+    let bytes = masked.as_bytes();
+    let skip_space = |i: usize| i + masked[i..].len() - masked[i..].trim_start().len();
+    let mut start = skip_space(range.start);
+
+    // Attributes, `#[...]`, whose brackets are balanced in masked code
+    while bytes.get(start) == Some(&b'#') {
+        let mut depth = 0;
+        let mut i = start + 1;
+
+        while i < range.end {
+            match bytes[i] {
+                b'[' => depth += 1,
+                b']' => depth -= 1,
+                _ => {}
+            }
+
+            i += 1;
+
+            if depth == 0 && bytes[i - 1] == b']' {
+                break;
+            }
+        }
+
+        start = skip_space(i);
+    }
+
+    let end = start + masked[start..range.end].trim_end().len();
+    let attributes = masked[range.start..start]
+        .split_whitespace()
+        .collect::<String>();
+
+    Item {
+        test: attributes.contains("#[cfg(test)]"),
+        kind: kind(&masked[start..end]),
+        range,
+        start,
+        end,
+    }
+}
+
+fn kind(code: &str) -> Kind {
+    let public = code.starts_with("pub");
+    let mut words = code
+        .split(|c: char| !is_word_char(c))
+        .filter(|word| !word.is_empty())
+        .peekable();
+
+    while let Some(word) = words.next() {
+        let name = match word {
+            "use" => return Kind::Use,
+            "macro_rules" => return Kind::Other,
+            "impl" => return impl_kind(code),
+            // `const fn` and `const unsafe fn` are functions
+            "const" if matches!(words.peek(), Some(&("fn" | "unsafe" | "async" | "extern"))) => {
+                continue;
+            }
+            "static" => words.find(|&word| word != "mut"),
+            "fn" | "const" | "struct" | "enum" | "union" | "type" | "trait" | "mod" => words.next(),
+            _ => continue,
+        };
+
+        return match name {
+            Some(name) => Kind::Named {
+                name: name.to_string(),
+                public,
+            },
+            None => Kind::Other,
+        };
+    }
+
+    Kind::Other
+}
+
+/// Trait and type of an impl of masked code, `Trait` and `Type` out of
+/// `impl<T> path::Trait<T> for path::Type<T> where T: Clone {`
+fn impl_kind(code: &str) -> Kind {
+    let start = code.find("impl").map_or(0, |n| n + "impl".len());
+    let end = code.find('{').unwrap_or(code.len());
+    let header = code[start..end].trim_start();
+    let header = header.split(" where ").next().unwrap_or(header);
+    let header = match header.starts_with('<') {
+        true => {
+            let mut depth = 0;
+            let end = header.find(|c| {
+                match c {
+                    '<' => depth += 1,
+                    '>' => depth -= 1,
+                    _ => {}
+                }
+
+                depth == 0
+            });
+
+            &header[end.map_or(header.len(), |end| end + 1)..]
+        }
+        false => header,
+    };
+    let name = |path: &str| {
+        let path = path.split('<').next().unwrap_or(path).trim();
+
+        path.rsplit("::").next().unwrap_or(path).to_string()
+    };
+
+    match header.split_once(" for ") {
+        Some((trait_path, self_type)) => Kind::Impl {
+            trait_name: Some(name(trait_path)),
+            self_type: name(self_type),
+        },
+        None => Kind::Impl {
+            trait_name: None,
+            self_type: name(header),
+        },
+    }
+}
+
+fn is_tooling_impl(kind: &Kind) -> bool {
+    matches!(
+        kind,
+        Kind::Impl { trait_name: Some(name), .. } if TOOLING_TRAITS.contains(&name.as_str())
+    )
+}
+
+/// True for a `crate::` path into a tooling module
+fn is_tooling_path(path: &str) -> bool {
+    path.strip_prefix("crate::")
+        .and_then(|path| path.split("::").next())
+        .is_some_and(|module| TOOLING.contains(&module))
+}
+
+/// Paths of the leaves of a masked use tree, `a::{b, c::{self, D}}` giving
+/// `a::b`, `a::c` and `a::c::D`
+fn leaves(tree: &str) -> Vec<String> {
+    // Spaces only matter between words, as in `A as B`
+    let tree = tree
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace(" ::", "::")
+        .replace(":: ", "::")
+        .replace(", ", ",")
+        .replace(" ,", ",")
+        .replace("{ ", "{")
+        .replace(" {", "{")
+        .replace(" }", "}");
+    let mut leaves = Vec::new();
+
+    tree_leaves(&tree, &mut leaves);
+    leaves
+}
+
+fn tree_leaves(tree: &str, leaves: &mut Vec<String>) {
+    let Some(open) = tree.find('{') else {
+        leaves.push(tree.trim_end_matches("::self").to_string());
+        return;
+    };
+    let (prefix, inner) = (&tree[..open], &tree[open + 1..tree.len() - 1]);
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (index, c) in inner.char_indices().chain([(inner.len(), ',')]) {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                if !inner[start..index].is_empty() {
+                    tree_leaves(&format!("{}{}", prefix, &inner[start..index]), leaves);
+                }
+
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Statements of masked code that are debug macros, replaced by a borrow of
+/// the locals their arguments name, `let _ = (&a, &b);`, or removed with
+/// their line when they name none and have it to themselves
+fn strip_debug(source: &str) -> String {
+    let masked = mask(source);
+    let bytes = masked.as_bytes();
+    let mut removed = Vec::new();
+
+    for name in DEBUG_MACROS {
+        let call = format!("{}!", name);
+
+        for (start, _) in masked.match_indices(&call) {
+            let before = masked[..start].trim_end();
+
+            if start > 0 && is_word_byte(bytes[start - 1])
+                || !(before.is_empty() || before.ends_with([';', '{', '}']))
+            {
+                continue;
+            }
+
+            let open = start + call.len();
+            let open = open + masked[open..].len() - masked[open..].trim_start().len();
+            let Some(close) = matching(&masked, open) else {
+                continue;
+            };
+            let after = close + 1;
+            let semicolon = after + masked[after..].len() - masked[after..].trim_start().len();
+
+            if bytes.get(semicolon) != Some(&b';') {
+                continue;
+            }
+
+            let line_start = masked[..start].rfind('\n').map_or(0, |n| n + 1);
+            let line_end = masked[semicolon..]
+                .find('\n')
+                .map_or(bytes.len(), |n| semicolon + n);
+            let borrows = match locals(&masked[open + 1..close])[..] {
+                [] => String::new(),
+                [local] => format!("let _ = &{};", local),
+                ref locals => format!("let _ = (&{});", locals.join(", &")),
+            };
+
+            match borrows.is_empty()
+                && masked[line_start..start].trim().is_empty()
+                && masked[semicolon + 1..line_end].trim().is_empty()
+            {
+                true => removed.push((line_start..(line_end + 1).min(bytes.len()), borrows)),
+                false => removed.push((start..semicolon + 1, borrows)),
+            }
+        }
+    }
+
+    removed.sort_by_key(|(range, _)| range.start);
+
+    let mut text = String::new();
+    let mut at = 0;
+
+    for (range, borrows) in removed {
+        if range.start >= at {
+            text += &source[at..range.start];
+            text += &borrows;
+            at = range.end;
+        }
+    }
+
+    text + &source[at..]
+}
+
+/// Locals named by the masked arguments of a macro call, each once: words on
+/// their own in lower case that are neither keywords, called, field names of
+/// a struct literal nor the keys of `key = value` pairs
+fn locals(arguments: &str) -> Vec<&str> {
+    let mut locals = Vec::new();
+
+    for (start, word) in words_at(arguments) {
+        let before = arguments[..start].trim_end();
+        let after = arguments[start + word.len()..].trim_start();
+        let member = before.ends_with("::") || before.ends_with('.') && !before.ends_with("..");
+        let called = after.starts_with(['!', '(']);
+        let field = after.starts_with(':') && !after.starts_with("::");
+        let key = after.starts_with('=') && !after.starts_with("==");
+        let local = word.starts_with(|c: char| c.is_ascii_lowercase() || c == '_')
+            && word != "_"
+            && !KEYWORDS.contains(&word);
+
+        if local && !(member || called || field || key) && !locals.contains(&word) {
+            locals.push(word);
+        }
+    }
+
+    locals
+}
+
+/// Position of the bracket closing the one at `open` in masked code
+fn matching(masked: &str, open: usize) -> Option<usize> {
+    let (opening, closing) = match masked.as_bytes().get(open)? {
+        b'(' => (b'(', b')'),
+        b'[' => (b'[', b']'),
+        b'{' => (b'{', b'}'),
+        _ => return None,
+    };
+    let mut depth = 0;
+
+    for (index, &byte) in masked.as_bytes().iter().enumerate().skip(open) {
+        if byte == opening {
+            depth += 1;
+        } else if byte == closing {
+            depth -= 1;
+
+            if depth == 0 {
+                return Some(index);
+            }
+        }
+    }
+
+    None
+}
+
+/// Segments of every `crate::` path in masked code
+fn crate_paths(masked: &str) -> Vec<Vec<String>> {
+    let mut paths = Vec::new();
+
+    for (start, _) in masked.match_indices("crate::") {
+        if start > 0 && is_word_byte(masked.as_bytes()[start - 1]) {
+            continue;
+        }
+
+        let rest = &masked[start + "crate::".len()..];
+        let found = match rest.starts_with('{') {
+            true => matching(rest, 0).map_or(vec![], |close| {
+                leaves(&format!("crate::{}", &rest[..=close]))
+            }),
+            false => vec![format!("crate::{}", rest)],
+        };
+
+        for path in found {
+            let path = &path["crate::".len()..];
+            let end = path
+                .find(|c: char| !is_word_char(c) && c != ':')
+                .unwrap_or(path.len());
+            let segments = path[..end]
+                .split("::")
+                .take_while(|segment| !segment.is_empty())
+                .map(str::to_string)
+                .collect::<Vec<_>>();
+
+            if !segments.is_empty() && !paths.contains(&segments) {
+                paths.push(segments);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Module file of a module path
+fn file(path: &[String]) -> PathBuf {
+    let mut file = Path::new(SRC_DIR).iter().collect::<PathBuf>();
+
+    file.extend(path);
+    file.set_extension("rs");
+    file
+}
+
+/// Path of the module file holding the item a `crate::` path names
+fn resolve(segments: &[String]) -> io::Result<Vec<String>> {
+    (1..=segments.len())
+        .rev()
+        .map(|len| &segments[..len])
+        .find(|path| file(path).is_file())
+        .map(<[String]>::to_vec)
+        .ok_or_else(|| {
+            invalid(format!(
+                "`crate::{}` is not in a module file that can be bundled",
+                segments.join("::")
+            ))
+        })
+}
+
+/// Name of the type the module implements `Problem` for, directly or through
+/// `Cases`
+fn problem_name(text: &str) -> Option<String> {
+    let masked = mask(text);
+    let (start, prefix) = ["impl Problem for ", "impl Cases for "]
+        .iter()
+        .find_map(|prefix| Some((masked.find(prefix)?, prefix)))?;
+    let rest = &masked[start + prefix.len()..];
+    let end = rest.find(|c: char| !is_word_char(c))?;
+
+    Some(rest[..end].to_string())
+}
+
+/// Write `modules`, sorted by path, as nested `mod` blocks below `depth`
+fn nest(bundle: &mut String, modules: &[(&[String], &str)], depth: usize) {
+    let mut rest = modules;
+
+    while let Some(((path, _), _)) = rest.split_first() {
+        let name = &path[depth];
+        let count = rest
+            .iter()
+            .take_while(|(path, _)| path[depth] == *name)
+            .count();
+        let (group, next) = rest.split_at(count);
+        let indent = "    ".repeat(depth);
+        let visibility = if depth == 0 { "" } else { "pub " };
+
+        *bundle += &format!("{}{}mod {} {{\n", indent, visibility, name);
+
+        for (_, text) in group.iter().filter(|(path, _)| path.len() == depth + 1) {
+            *bundle += &indented(text, depth + 1);
+        }
+
+        let children = group
+            .iter()
+            .copied()
+            .filter(|(path, _)| path.len() > depth + 1)
+            .collect::<Vec<_>>();
+
+        nest(bundle, &children, depth + 1);
+        *bundle += &format!("{}}}\n\n", indent);
+        rest = next;
+    }
+}
+
+/// `text` indented by `depth` levels, lines inside string literals aside
+fn indented(text: &str, depth: usize) -> String {
+    let masked = mask(text);
+    let indent = "    ".repeat(depth);
+    let mut result = String::new();
+    let mut at = 0;
+
+    for line in text.split_inclusive('\n') {
+        if !line.trim().is_empty() && masked.as_bytes()[at] != b'~' {
+            result += &indent;
+        }
+
+        result += match line.trim().is_empty() {
+            true => "\n",
+            false => line,
+        };
+        at += line.len();
+    }
+
+    result
+}
+
+/// True when `word` appears in masked code as a whole word
+fn contains_word(masked: &str, word: &str) -> bool {
+    masked.match_indices(word).any(|(start, _)| {
+        let end = start + word.len();
+
+        (start == 0 || !is_word_byte(masked.as_bytes()[start - 1]))
+            && masked
+                .as_bytes()
+                .get(end)
+                .is_none_or(|&byte| !is_word_byte(byte))
+    })
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn is_word_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte >= 0x80
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{algorithm, fixture, harness};
+
+    #[test]
+    fn mask_01() {
+        let source = "let s = \"a { // b\"; // c }\nlet c = '{'; /* d */ x::<'a>(r#\"\"}\"#);";
+
+        assert_eq!(
+            mask(source),
+            "let s = \"~~~~~~~~\";       \nlet c = '~';         x::<'a>(r#\"~~\"#);"
+        );
+    }
+
+    #[test]
+    fn leaves_01() {
+        assert_eq!(
+            leaves("crate::{\n    a::b,\n    c::{self, D as E},\n    f::*,\n}"),
+            vec!["crate::a::b", "crate::c", "crate::c::D as E", "crate::f::*"]
+        );
+        assert_eq!(leaves("std::fmt"), vec!["std::fmt"]);
+    }
+
+    #[test]
+    fn strip_01() {
+        let source = "\
+//! Docs
+
+use crate::{
+    error::Result,
+    generate::{self, Generate},
+};
+use std::collections::HashSet;
+
+/// Only the generator uses it
+fn naive() -> usize {
+    1
+}
+
+pub fn solve(s: &str) -> Result<usize> {
+    println!(\"{}\", s);
+    Ok(s.len()) // length
+}
+
+pub struct Thing;
+
+impl Generate for Thing {
+    fn generate() -> usize {
+        naive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+}
+";
+        let stripped = strip(source);
+
+        assert_eq!(
+            stripped.text,
+            "\
+//! Docs
+
+use crate::error::Result;
+use std::collections::HashSet;
+
+pub fn solve(s: &str) -> Result<usize> {
+    let _ = &s;
+    Ok(s.len()) // length
+}
+
+pub struct Thing;
+"
+        );
+        assert_eq!(stripped.paths, vec![vec!["error", "Result"]]);
+    }
+
+    #[test]
+    fn locals_01() {
+        let arguments = mask(
+            "Level::Debug, \"pair\", left = left, right = *right, len = s.len(), \
+             sum = total(&items[..n]), step = Step::Swap { at: i, value }",
+        );
+
+        assert_eq!(
+            locals(&arguments),
+            vec!["left", "right", "s", "items", "n", "i", "value"]
+        );
+    }
+
+    #[test]
+    fn prune_01() {
+        let modules = BTreeMap::from([
+            (
+                vec!["a".to_string()],
+                "\
+use crate::b::{used, Unused};
+use std::fmt::Write;
+
+pub struct Thing;
+
+impl Thing {
+    pub fn kept(&self) {}
+
+    pub fn dropped(&self) {}
+}
+
+pub fn run() -> String {
+    let mut s = String::new();
+
+    writeln!(s, \"{}\", used()).unwrap();
+    Thing.kept();
+    s
+}
+"
+                .to_string(),
+            ),
+            (
+                vec!["b".to_string()],
+                "pub fn used() -> u8 {\n    1\n}\n\npub struct Unused;\n".to_string(),
+            ),
+            (vec!["c".to_string()], "pub fn never() {}\n".to_string()),
+        ]);
+        let pruned = prune(modules, "fn main() {\n    a::run();\n}\n");
+
+        assert_eq!(
+            pruned.keys().collect::<Vec<_>>(),
+            vec![&vec!["a".to_string()], &vec!["b".to_string()]]
+        );
+        assert_eq!(
+            pruned[&vec!["a".to_string()]],
+            "\
+use crate::b::used;
+use std::fmt::Write;
+
+pub struct Thing;
+
+impl Thing {
+    pub fn kept(&self) {}
+}
+
+pub fn run() -> String {
+    let mut s = String::new();
+
+    writeln!(s, \"{}\", used()).unwrap();
+    Thing.kept();
+    s
+}
+"
+        );
+        assert_eq!(
+            pruned[&vec!["b".to_string()]],
+            "pub fn used() -> u8 {\n    1\n}\n"
+        );
+    }
+
+    #[test]
+    fn prune_02() {
+        // `Vec::new` and `Thing::new` keep neither `Bounds::new` nor the
+        // inherent `Thing::make`, a generic `T::make` keeps the trait's
+        let path = vec!["a".to_string()];
+        let source = "\
+pub trait Make {
+    fn make() -> Self;
+}
+
+pub struct Bounds(u8);
+
+impl Bounds {
+    pub const fn new() -> Self {
+        Bounds(0)
+    }
+
+    pub fn get(&self) -> u8 {
+        self.0
+    }
+}
+
+pub struct Thing;
+
+impl Thing {
+    pub fn new() -> Self {
+        Thing
+    }
+
+    pub fn make() -> Self {
+        Thing
+    }
+}
+
+impl Make for Bounds {
+    fn make() -> Self {
+        Bounds(1)
+    }
+}
+
+pub fn build<T: Make>() -> T {
+    T::make()
+}
+
+pub fn run() -> u8 {
+    let _ = (Vec::<u8>::new(), Thing::new());
+
+    build::<Bounds>().get()
+}
+";
+        let pruned = prune(
+            BTreeMap::from([(path.clone(), source.to_string())]),
+            "fn main() {\n    a::run();\n}\n",
+        );
+
+        assert_eq!(
+            pruned[&path],
+            "\
+pub trait Make {
+    fn make() -> Self;
+}
+
+pub struct Bounds(u8);
+
+impl Bounds {
+    pub fn get(&self) -> u8 {
+        self.0
+    }
+}
+
+pub struct Thing;
+
+impl Thing {
+    pub fn new() -> Self {
+        Thing
+    }
+}
+
+impl Make for Bounds {
+    fn make() -> Self {
+        Bounds(1)
+    }
+}
+
+pub fn build<T: Make>() -> T {
+    T::make()
+}
+
+pub fn run() -> u8 {
+    let _ = (Vec::<u8>::new(), Thing::new());
+
+    build::<Bounds>().get()
+}
+"
+        );
+    }
+
+    #[test]
+    fn prune_entries_01() {
+        let path = vec!["e".to_string()];
+        let source = "\
+pub enum Shape {
+    Square(u8),
+    Circle,
+}
+
+pub struct Size {
+    pub width: u8,
+    pub label: &'static str,
+}
+
+pub fn area(shape: &Shape) -> u8 {
+    match shape {
+        Shape::Square(side) => side * side,
+        Shape::Circle => {
+            3
+        }
+    }
+}
+
+pub fn size() -> u8 {
+    let size = Size {
+        width: 2,
+        label: \"two\",
+    };
+
+    area(&Shape::Square(size.width))
+}
+";
+        let pruned = prune_entries(BTreeMap::from([(path.clone(), source.to_string())]));
+
+        assert_eq!(
+            pruned[&path],
+            "\
+pub enum Shape {
+    Square(u8),
+}
+
+pub struct Size {
+    pub width: u8,
+}
+
+pub fn area(shape: &Shape) -> u8 {
+    match shape {
+        Shape::Square(side) => side * side,
+    }
+}
+
+pub fn size() -> u8 {
+    let size = Size {
+        width: 2,
+    };
+
+    area(&Shape::Square(size.width))
+}
+"
+        );
+    }
+
+    #[test]
+    fn bundle_01() {
+        let bundle = bundle("matrix_rotation").unwrap();
+
+        assert!(bundle.contains("\n    pub mod spiral_data {\n"));
+        assert!(bundle.contains("\nmod harness {\n"));
+
+        for gone in [
+            "#![allow",
+            "#[cfg(test)]",
+            "mod checker",
+            "Unsupported",
+            "impl Generate",
+            "matrix_rotation_naive",
+            "mod bench",
+            "mod trace",
+            "trace!",
+            "mod algorithm {\n    use",
+        ] {
+            assert!(!bundle.contains(gone), "{} is still bundled", gone);
+        }
+
+        // The `eprintln!` of the judge stub
+        assert_eq!(bundle.matches("println!").count(), 1);
+
+        assert!(matches!(
+            super::bundle("no_such_problem"),
+            Err(e) if e.kind() == io::ErrorKind::NotFound
+        ));
+    }
+
+    #[test]
+    fn compile_01() {
+        let problem = algorithm::find("matrix_rotation").unwrap();
+        let binary = env::temp_dir().join(format!("hackerank-bundle-{}", std::process::id()));
+
+        compile(&bundle(problem.slug()).unwrap(), &binary).unwrap();
+
+        for case in fixture::cases(problem.slug()).unwrap() {
+            let (Some(input), Some(output)) = (case.input, case.output) else {
+                continue;
+            };
+            let answer = binary.with_extension("out");
+            let status = Command::new(&binary)
+                .env(harness::OUTPUT_PATH, &answer)
+                .stdin(fs::File::open(&input).unwrap())
+                .status()
+                .unwrap();
+
+            assert!(status.success());
+            assert!(problem.checker().check(
+                &fs::read_to_string(input).unwrap(),
+                &fs::read_to_string(output).unwrap(),
+                &fs::read_to_string(&answer).unwrap()
+            ));
+
+            let _ = fs::remove_file(answer);
+        }
+
+        let _ = fs::remove_file(&binary);
+        let error = compile("fn main() { x }", &binary).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("cannot find value `x`"));
+
+        let error = compile("fn main() {\n    let x = 1;\n}\n", &binary).unwrap_err();
+
+        assert!(error.to_string().contains("unused variable: `x`"));
+    }
+
+    #[test]
+    fn compile_02() {
+        // Every bundle compiles without a warning, whatever its debug
+        // statements and the shared code it leaves out
+        for problem in algorithm::problems() {
+            let binary = env::temp_dir().join(format!(
+                "hackerank-bundle-{}-{}",
+                std::process::id(),
+                problem.slug()
+            ));
+            let bundled = compile(&bundle(problem.slug()).unwrap(), &binary);
+
+            let _ = fs::remove_file(&binary);
+            assert!(
+                bundled.is_ok(),
+                "{}: {}",
+                problem.slug(),
+                bundled.unwrap_err()
+            );
+        }
+    }
+}
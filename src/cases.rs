@@ -0,0 +1,148 @@
+//! Inputs made of a count followed by that many independent cases.
+//!
+//! Many HackerRank inputs start with a `q` or `t` and then repeat the same
+//! case shape, answering each case on its own line. A problem implementing
+//! [`Cases`] only describes one case and gets [`Problem`] for free, its input
+//! being the list of cases and its output the list of answers. Running it
+//! streams the cases through the solver, each one parsed, validated and solved
+//! before the next is read, so the official inputs run unchanged.
+
+use crate::{
+    answer::FormatAnswer,
+    checker::Checker,
+    error::Result,
+    meta::{self, Meta},
+    problem::Problem,
+    scanner::Scanner,
+};
+
+/// A problem whose input is a count of independent cases, implemented instead
+/// of [`Problem`]
+pub trait Cases {
+    /// Parsed form of one case
+    type Case;
+    /// Answer to one case, written on its own line
+    type Answer: FormatAnswer;
+
+    const SLUG: &'static str;
+
+    const META: Meta;
+
+    const CHECKER: Checker = Checker::Exact;
+
+    /// Name of the constraint bounding the number of cases
+    const COUNT: &'static str;
+
+    /// Read one case, the count before the cases being already read
+    fn parse_case(scanner: &mut Scanner) -> Result<Self::Case>;
+
+    /// Values of a case that the constraints of [`Cases::META`] bound
+    fn case_values(_case: &Self::Case) -> Vec<(&'static str, i64)> {
+        Vec::new()
+    }
+
+    /// Check what bounds cannot express about a case
+    fn check_case(_case: &Self::Case) -> Result<()> {
+        Ok(())
+    }
+
+    fn solve_case(case: Self::Case) -> Result<Self::Answer>;
+}
+
+impl<C: Cases> Problem for C {
+    type Input = Vec<C::Case>;
+    type Output = Vec<C::Answer>;
+
+    const SLUG: &'static str = C::SLUG;
+
+    const META: Meta = C::META;
+
+    const CHECKER: Checker = C::CHECKER;
+
+    fn parse(input: &[u8]) -> Result<Self::Input> {
+        let mut scanner = Scanner::new(input);
+        let count = scanner.next::<usize>()?;
+
+        (0..count).map(|_| C::parse_case(&mut scanner)).collect()
+    }
+
+    fn values(cases: &Self::Input) -> Vec<(&'static str, i64)> {
+        let mut values = vec![(C::COUNT, cases.len() as i64)];
+
+        values.extend(cases.iter().flat_map(C::case_values));
+        values
+    }
+
+    fn check(cases: &Self::Input) -> Result<()> {
+        cases.iter().try_for_each(C::check_case)
+    }
+
+    fn solve(cases: Self::Input) -> Result<Self::Output> {
+        cases.into_iter().map(C::solve_case).collect()
+    }
+
+    /// Solve each case as soon as it is read, only keeping its answer
+    fn run(input: &[u8]) -> Result<String> {
+        let mut scanner = Scanner::new(input);
+        let count = scanner.next::<usize>()?;
+
+        meta::validate(C::META.constraints, &[(C::COUNT, count as i64)])?;
+
+        let mut output = String::new();
+
+        for index in 0..count {
+            let case = C::parse_case(&mut scanner)?;
+
+            meta::validate(C::META.constraints, &C::case_values(&case))?;
+            C::check_case(&case)?;
+
+            if index > 0 {
+                output.push('\n');
+            }
+
+            C::solve_case(case)?.write_line(&mut output);
+        }
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithm, error::HackerankError, harness};
+
+    #[test]
+    fn cases_01() {
+        let problem = algorithm::find("anagram").unwrap();
+        let mut output = Vec::new();
+
+        harness::execute(
+            problem,
+            b"6\naaabbb\nab\nabc\nmnop\nxyyx\nxaxbbbxx\n",
+            &mut output,
+        )
+        .unwrap();
+
+        assert_eq!(output, b"3\n1\n-1\n2\n0\n1\n");
+    }
+
+    #[test]
+    fn cases_02() {
+        let problem = algorithm::find("two_strings").unwrap();
+
+        // The count is checked before any case is read, a bad case stops the
+        // run with the error of that case
+        assert!(matches!(
+            problem.run(b"11\n"),
+            Err(HackerankError::Constraint { name: "p", .. })
+        ));
+        assert!(matches!(
+            problem.run(b"2\nhello\nworld\nHi\nworld\n"),
+            Err(HackerankError::InvalidChar { found: 'H', .. })
+        ));
+        assert!(matches!(
+            problem.run(b"2\nhello\nworld\n"),
+            Err(HackerankError::Parse(_))
+        ));
+    }
+}
@@ -0,0 +1,163 @@
+//! Output checkers.
+//!
+//! The judge does not always compare answers byte for byte. Each problem picks
+//! the [`Checker`] its answers need, from the exact comparison most problems
+//! use to a custom judge for problems accepting several answers. The command
+//! line can swap it for another with [`Rechecked`], for fixtures whose
+//! expected output differs from the answer in spacing or rounding only.
+
+use std::str::FromStr;
+
+use crate::{error::Result, meta::Meta, problem::Runnable};
+
+/// Judge for answers that can be right without matching the expected output,
+/// given the input, the expected output and the actual one
+pub type Judge = fn(input: &str, expected: &str, actual: &str) -> bool;
+
+/// How an answer is compared with the expected output
+#[derive(Debug, Clone, Copy)]
+pub enum Checker {
+    /// Same text, trailing whitespace at the very end aside
+    Exact,
+    /// Same tokens, however they are spread over lines and spaces
+    Tokens,
+    /// Same tokens, numbers being equal within the given absolute error, or
+    /// relative error for numbers past 1
+    Float(f64),
+    /// Any answer the judge accepts
+    Custom(Judge),
+}
+
+impl Checker {
+    pub fn check(&self, input: &str, expected: &str, actual: &str) -> bool {
+        match self {
+            Checker::Exact => actual.trim_end() == expected.trim_end(),
+            Checker::Tokens => expected.split_whitespace().eq(actual.split_whitespace()),
+            Checker::Float(epsilon) => {
+                let (expected, actual) = (tokens(expected), tokens(actual));
+
+                expected.len() == actual.len()
+                    && expected
+                        .iter()
+                        .zip(&actual)
+                        .all(|(expected, actual)| close(expected, actual, *epsilon))
+            }
+            Checker::Custom(judge) => judge(input, expected, actual),
+        }
+    }
+}
+
+impl FromStr for Checker {
+    type Err = String;
+
+    /// `exact`, `tokens`, `float` or `float:<epsilon>`, a custom judge being
+    /// out of reach of the command line
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.split_once(':') {
+            None if s == "exact" => Ok(Checker::Exact),
+            None if s == "tokens" => Ok(Checker::Tokens),
+            None if s == "float" => Ok(Checker::Float(DEFAULT_EPSILON)),
+            Some(("float", epsilon)) => epsilon
+                .parse()
+                .map(Checker::Float)
+                .map_err(|e| format!("epsilon `{}`: {}", epsilon, e)),
+            _ => Err(format!(
+                "unknown checker `{}`, expected exact, tokens, float or float:<epsilon>",
+                s
+            )),
+        }
+    }
+}
+
+/// Error `float` allows when none is given, the usual 10^-6 of HackerRank
+pub const DEFAULT_EPSILON: f64 = 1e-6;
+
+/// A problem judged by another checker than its own
+pub struct Rechecked<'a> {
+    pub problem: &'a dyn Runnable,
+    pub checker: Checker,
+}
+
+impl Runnable for Rechecked<'_> {
+    fn slug(&self) -> &'static str {
+        self.problem.slug()
+    }
+
+    fn checker(&self) -> Checker {
+        self.checker
+    }
+
+    fn meta(&self) -> Meta {
+        self.problem.meta()
+    }
+
+    fn run(&self, input: &[u8]) -> Result<String> {
+        self.problem.run(input)
+    }
+}
+
+fn tokens(text: &str) -> Vec<&str> {
+    text.split_whitespace().collect()
+}
+
+/// Equal tokens, or numbers within `epsilon` of each other
+fn close(expected: &str, actual: &str, epsilon: f64) -> bool {
+    match (expected.parse::<f64>(), actual.parse::<f64>()) {
+        (Ok(expected), Ok(actual)) => {
+            (expected - actual).abs() <= epsilon * expected.abs().max(1.0)
+        }
+        _ => expected == actual,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_01() {
+        assert!(Checker::Exact.check("", "1 2\n3\n", "1 2\n3"));
+        assert!(!Checker::Exact.check("", "1 2\n3\n", "1 2 \n3\n"));
+        assert!(!Checker::Exact.check("", "1 2\n3\n", "1 2 3\n"));
+    }
+
+    #[test]
+    fn tokens_01() {
+        assert!(Checker::Tokens.check("", "1 2\n3\n", "1 2 \n3"));
+        assert!(Checker::Tokens.check("", "1 2\n3\n", "1\n2 3"));
+        assert!(!Checker::Tokens.check("", "1 2\n3\n", "1 2"));
+    }
+
+    #[test]
+    fn float_01() {
+        let checker = Checker::Float(1e-6);
+
+        assert!(checker.check("", "0.5 YES", "0.5000001 YES"));
+        assert!(checker.check("", "1000000", "1000000.5"));
+        assert!(!checker.check("", "0.5 YES", "0.5001 YES"));
+        assert!(!checker.check("", "0.5 YES", "0.5 NO"));
+        assert!(!checker.check("", "0.5", "0.5 0.5"));
+    }
+
+    #[test]
+    fn from_str_01() {
+        assert!(matches!("tokens".parse(), Ok(Checker::Tokens)));
+        assert!(matches!(
+            "float".parse(),
+            Ok(Checker::Float(DEFAULT_EPSILON))
+        ));
+        assert!(matches!("float:0.01".parse(), Ok(Checker::Float(0.01))));
+        assert!("float:x".parse::<Checker>().is_err());
+        assert!("custom".parse::<Checker>().is_err());
+    }
+
+    #[test]
+    fn custom_01() {
+        // Any even number will do
+        let checker =
+            Checker::Custom(|_, _, actual| actual.trim().parse::<i64>().is_ok_and(|n| n % 2 == 0));
+
+        assert!(checker.check("", "2", "4"));
+        assert!(!checker.check("", "2", "3"));
+    }
+}
@@ -0,0 +1,306 @@
+//! Empirical complexity of solutions.
+//!
+//! Every problem declares how its optimized solution grows with the length of
+//! its input, or with the dimension of the input it is known to grow with such
+//! as the `n` of `n!`. The solution is timed on inputs generated at
+//! geometrically growing sizes, and the timings are fitted against that
+//! measure of the inputs, both to the candidate [`Complexity`] classes and to
+//! a power law whose exponent is compared with the one of the declared class.
+//! Solutions growing clearly faster than declared are flagged, whatever the
+//! generator makes of its `size`.
+
+use std::{fmt, time::Duration};
+
+use crate::{
+    bench::{self, Bench},
+    error::Result,
+    generate::Shape,
+};
+
+/// How far the measured exponent may exceed the declared one. Cache effects
+/// easily add a few tenths to a linear solution, while a quadratic one
+/// declared linear is off by a whole unit.
+pub const TOLERANCE: f64 = 0.4;
+
+/// Growth classes a solution can declare
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Complexity {
+    Constant,
+    Logarithmic,
+    Linear,
+    Linearithmic,
+    Quadratic,
+    Cubic,
+}
+
+impl Complexity {
+    pub const ALL: [Complexity; 6] = [
+        Complexity::Constant,
+        Complexity::Logarithmic,
+        Complexity::Linear,
+        Complexity::Linearithmic,
+        Complexity::Quadratic,
+        Complexity::Cubic,
+    ];
+
+    /// Cost of a size `n`, up to a constant factor
+    pub fn cost(self, n: f64) -> f64 {
+        let log = n.max(2.0).ln();
+
+        match self {
+            Complexity::Constant => 1.0,
+            Complexity::Logarithmic => log,
+            Complexity::Linear => n,
+            Complexity::Linearithmic => n * log,
+            Complexity::Quadratic => n * n,
+            Complexity::Cubic => n * n * n,
+        }
+    }
+
+    /// Exponent of the power law the class follows around `n`, which is the
+    /// derivative of the log cost with respect to log `n`
+    pub fn exponent(self, n: f64) -> f64 {
+        let log = n.max(2.0).ln();
+
+        match self {
+            Complexity::Constant => 0.0,
+            Complexity::Logarithmic => 1.0 / log,
+            Complexity::Linear => 1.0,
+            Complexity::Linearithmic => 1.0 + 1.0 / log,
+            Complexity::Quadratic => 2.0,
+            Complexity::Cubic => 3.0,
+        }
+    }
+}
+
+impl fmt::Display for Complexity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Complexity::Constant => write!(f, "O(1)"),
+            Complexity::Logarithmic => write!(f, "O(log n)"),
+            Complexity::Linear => write!(f, "O(n)"),
+            Complexity::Linearithmic => write!(f, "O(n log n)"),
+            Complexity::Quadratic => write!(f, "O(n²)"),
+            Complexity::Cubic => write!(f, "O(n³)"),
+        }
+    }
+}
+
+/// Sizes to time and how many inputs of each
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    pub seed: u64,
+    /// Largest size, `None` for the largest the problem allows
+    pub size: Option<usize>,
+    pub steps: usize,
+    /// Inputs timed together at each size, which smooths out generators
+    /// picking their lengths at random below the size
+    pub seeds: usize,
+    pub shape: Shape,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            seed: 0,
+            size: None,
+            steps: 6,
+            seeds: 5,
+            shape: Shape::Random,
+        }
+    }
+}
+
+/// Timings of a solution fitted against its declared complexity
+#[derive(Debug, Clone)]
+pub struct Estimate {
+    pub declared: Complexity,
+    /// Total measure and total time of the inputs of each size
+    pub points: Vec<(usize, Duration)>,
+    /// Class fitting the timings best
+    pub fit: Complexity,
+    /// Exponent of the power law fitting the timings best
+    pub exponent: f64,
+}
+
+impl Estimate {
+    pub fn new(declared: Complexity, points: Vec<(usize, Duration)>) -> Self {
+        Estimate {
+            declared,
+            fit: best_fit(&points),
+            exponent: exponent(&points),
+            points,
+        }
+    }
+
+    /// True when the timings grow faster than the declared class allows
+    pub fn too_slow(&self) -> bool {
+        self.exponent > self.declared.exponent(self.middle_size()) + TOLERANCE
+    }
+
+    /// Geometric mean of the measures, where the declared exponent is
+    /// compared
+    fn middle_size(&self) -> f64 {
+        let logs = self.points.iter().map(|&(n, _)| (n as f64).ln());
+
+        (logs.sum::<f64>() / self.points.len().max(1) as f64).exp()
+    }
+}
+
+impl fmt::Display for Estimate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (size, time) in &self.points {
+            writeln!(f, "{:>8} {:>12.1?}", size, time)?;
+        }
+
+        write!(
+            f,
+            "declared {}, fits {}, measured exponent {:.2}",
+            self.declared, self.fit, self.exponent
+        )
+    }
+}
+
+/// Time the optimized solution of a problem on growing sizes and fit it
+pub fn estimate(bench: &dyn Bench, options: Options) -> Result<Estimate> {
+    let max = options.size.unwrap_or(bench.max_size());
+    let mut points = Vec::new();
+
+    for size in bench::sizes(max.clamp(1, bench.max_size()), options.steps) {
+        let seeds = options.seed..options.seed.wrapping_add(options.seeds.max(1) as u64);
+
+        points.push(bench.time(size, seeds, options.shape)?);
+    }
+
+    Ok(Estimate::new(bench.complexity(), points))
+}
+
+/// Slope of the least squares line through the log time over log measure
+/// points, 0 with fewer than two distinct measures
+pub fn exponent(points: &[(usize, Duration)]) -> f64 {
+    let logs = points
+        .iter()
+        .map(|&(n, time)| ((n as f64).ln(), seconds(time).ln()))
+        .collect::<Vec<_>>();
+    let count = logs.len() as f64;
+    let mean_x = logs.iter().map(|(x, _)| x).sum::<f64>() / count;
+    let mean_y = logs.iter().map(|(_, y)| y).sum::<f64>() / count;
+    let covariance = logs
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum::<f64>();
+    let variance = logs.iter().map(|(x, _)| (x - mean_x).powi(2)).sum::<f64>();
+
+    match variance > 0.0 {
+        true => covariance / variance,
+        false => 0.0,
+    }
+}
+
+/// Class whose best scaled cost has the smallest relative error over all
+/// points, the slower class losing ties
+pub fn best_fit(points: &[(usize, Duration)]) -> Complexity {
+    let error = |class: Complexity| {
+        // Scale minimizing the sum of squared relative errors
+        let ratios = points
+            .iter()
+            .map(|&(n, time)| class.cost(n as f64) / seconds(time))
+            .collect::<Vec<_>>();
+        let scale = ratios.iter().sum::<f64>() / ratios.iter().map(|r| r * r).sum::<f64>();
+
+        ratios
+            .iter()
+            .map(|r| (1.0 - scale * r).powi(2))
+            .sum::<f64>()
+    };
+
+    Complexity::ALL
+        .into_iter()
+        .map(|class| (class, error(class)))
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map_or(Complexity::Constant, |(class, _)| class)
+}
+
+/// Seconds of a timing, never 0 so its logarithm stays finite
+fn seconds(time: Duration) -> f64 {
+    time.as_secs_f64().max(1e-9)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm;
+
+    /// Exact timings of a cost function at doubling sizes
+    fn points(cost: impl Fn(f64) -> f64) -> Vec<(usize, Duration)> {
+        bench::sizes(64_000, 6)
+            .into_iter()
+            .map(|n| (n, Duration::from_secs_f64(cost(n as f64) * 1e-9)))
+            .collect()
+    }
+
+    #[test]
+    fn exponent_01() {
+        assert!((exponent(&points(|n| n * n)) - 2.0).abs() < 1e-6);
+        assert!((exponent(&points(|n| 50.0 * n)) - 1.0).abs() < 1e-6);
+        assert!(exponent(&points(|_| 1e4)).abs() < 1e-6);
+        assert_eq!(exponent(&[(1, Duration::from_millis(3))]), 0.0);
+    }
+
+    #[test]
+    fn best_fit_01() {
+        for class in Complexity::ALL {
+            assert_eq!(best_fit(&points(|n| 30.0 * class.cost(n))), class);
+        }
+    }
+
+    #[test]
+    fn estimate_01() {
+        let quadratic = Estimate::new(Complexity::Linear, points(|n| n * n));
+
+        assert!(quadratic.too_slow());
+        assert_eq!(quadratic.fit, Complexity::Quadratic);
+        assert!(
+            quadratic
+                .to_string()
+                .ends_with("declared O(n), fits O(n²), measured exponent 2.00")
+        );
+
+        // n log n is within the tolerance of a linear declaration
+        let linearithmic = Estimate::new(Complexity::Linear, points(|n| n * n.ln()));
+
+        assert!(!linearithmic.too_slow());
+        assert!(!Estimate::new(Complexity::Quadratic, points(|n| n * n)).too_slow());
+    }
+
+    #[test]
+    fn estimate_02() {
+        let options = Options {
+            size: Some(400),
+            steps: 3,
+            seeds: 2,
+            ..Options::default()
+        };
+        let generator = algorithm::find_generator("game_of_thrones_i").unwrap();
+        let estimate =
+            estimate(algorithm::find_bench("game_of_thrones_i").unwrap(), options).unwrap();
+        let measures = estimate
+            .points
+            .iter()
+            .map(|(measure, _)| *measure)
+            .collect::<Vec<_>>();
+
+        // Timings are fitted against the length of the inputs, not the size
+        // given to the generator
+        let lengths = [100, 200, 400]
+            .map(|size| {
+                (0..2)
+                    .map(|seed| generator.sample(seed, size, Shape::Random).unwrap().len())
+                    .sum::<usize>()
+            })
+            .to_vec();
+
+        assert_eq!(measures, lengths);
+        assert_eq!(estimate.declared, Complexity::Linear);
+    }
+}
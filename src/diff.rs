@@ -0,0 +1,254 @@
+//! Readable differences between an expected and an actual output.
+//!
+//! Outputs are compared line by line and token by token. The report names the
+//! first differing token and how many lines differ overall, then shows the
+//! neighbourhood of the difference: matrix outputs side by side, line per line,
+//! and any other output as the tokens around the first difference.
+
+use std::fmt;
+
+/// Lines shown on each side of the first differing line
+pub const CONTEXT_LINES: usize = 2;
+
+/// Tokens shown on each side of the first differing token
+pub const CONTEXT_TOKENS: usize = 4;
+
+/// Width a side of the side by side view is cut to
+pub const COLUMN_WIDTH: usize = 40;
+
+/// Difference between two outputs, ready to be displayed
+#[derive(Debug, Clone)]
+pub struct Diff<'a> {
+    expected: Vec<Vec<&'a str>>,
+    actual: Vec<Vec<&'a str>>,
+}
+
+impl<'a> Diff<'a> {
+    pub fn new(expected: &'a str, actual: &'a str) -> Self {
+        Diff {
+            expected: tokens(expected),
+            actual: tokens(actual),
+        }
+    }
+
+    /// 0-based line and token of the first difference, if the outputs differ
+    /// in more than whitespace
+    pub fn first(&self) -> Option<(usize, usize)> {
+        let line = (0..self.lines()).find(|&line| self.line_differs(line))?;
+        let (expected, actual) = (self.expected_line(line), self.actual_line(line));
+        let token = (0..expected.len().max(actual.len()))
+            .find(|&token| expected.get(token) != actual.get(token))
+            .unwrap_or(0);
+
+        Some((line, token))
+    }
+
+    /// Number of lines whose tokens differ
+    pub fn mismatched_lines(&self) -> usize {
+        (0..self.lines())
+            .filter(|&line| self.line_differs(line))
+            .count()
+    }
+
+    /// Lines of the longer output
+    pub fn lines(&self) -> usize {
+        self.expected.len().max(self.actual.len())
+    }
+
+    /// True when the expected output has several lines of several tokens each
+    pub fn is_matrix(&self) -> bool {
+        self.expected.len() > 1 && self.expected.iter().all(|line| line.len() > 1)
+    }
+
+    fn line_differs(&self, line: usize) -> bool {
+        self.expected_line(line) != self.actual_line(line)
+    }
+
+    fn expected_line(&self, line: usize) -> &[&'a str] {
+        self.expected.get(line).map_or(&[], Vec::as_slice)
+    }
+
+    fn actual_line(&self, line: usize) -> &[&'a str] {
+        self.actual.get(line).map_or(&[], Vec::as_slice)
+    }
+
+    fn side_by_side(&self, f: &mut fmt::Formatter<'_>, line: usize) -> fmt::Result {
+        let lines =
+            line.saturating_sub(CONTEXT_LINES)..(line + CONTEXT_LINES + 1).min(self.lines());
+        let texts = lines
+            .clone()
+            .map(|line| {
+                (
+                    cut(&self.expected_line(line).join(" ")),
+                    cut(&self.actual_line(line).join(" ")),
+                )
+            })
+            .collect::<Vec<_>>();
+        let width = texts
+            .iter()
+            .map(|(expected, _)| expected.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max("expected".len());
+
+        write!(f, "\n         | {:<width$} | actual", "expected")?;
+
+        for (line, (expected, actual)) in lines.zip(texts) {
+            let marker = if self.line_differs(line) { '>' } else { ' ' };
+
+            write!(
+                f,
+                "\n  {} {:>4} | {:<width$} | {}",
+                marker,
+                line + 1,
+                expected,
+                actual
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn around(&self, f: &mut fmt::Formatter<'_>, line: usize, token: usize) -> fmt::Result {
+        write!(
+            f,
+            "\n  expected: {}",
+            context(self.expected_line(line), token)
+        )?;
+        write!(
+            f,
+            "\n  actual:   {}",
+            context(self.actual_line(line), token)
+        )
+    }
+}
+
+impl fmt::Display for Diff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((line, token)) = self.first() else {
+            return write!(f, "outputs differ in whitespace only");
+        };
+        let found = |tokens: &[&str]| {
+            tokens
+                .get(token)
+                .map_or("nothing".to_string(), |token| format!("`{}`", token))
+        };
+
+        write!(
+            f,
+            "line {}, token {}: expected {}, found {}",
+            line + 1,
+            token + 1,
+            found(self.expected_line(line)),
+            found(self.actual_line(line))
+        )?;
+        write!(
+            f,
+            "\n{} of {} lines differ",
+            self.mismatched_lines(),
+            self.lines()
+        )?;
+
+        match self.is_matrix() {
+            true => self.side_by_side(f, line),
+            false => self.around(f, line, token),
+        }
+    }
+}
+
+fn tokens(text: &str) -> Vec<Vec<&str>> {
+    text.trim_end()
+        .lines()
+        .map(|line| line.split_whitespace().collect())
+        .collect()
+}
+
+/// Tokens around `token`, which is bracketed
+fn context(tokens: &[&str], token: usize) -> String {
+    let start = token.saturating_sub(CONTEXT_TOKENS);
+    let end = (token + CONTEXT_TOKENS + 1).min(tokens.len());
+    let mut shown = Vec::new();
+
+    if start > 0 {
+        shown.push("...".to_string());
+    }
+
+    for (index, value) in tokens.iter().enumerate().take(end).skip(start) {
+        match index == token {
+            true => shown.push(format!("[{}]", value)),
+            false => shown.push(value.to_string()),
+        }
+    }
+
+    if token >= tokens.len() {
+        shown.push("[]".to_string());
+    } else if end < tokens.len() {
+        shown.push("...".to_string());
+    }
+
+    shown.join(" ")
+}
+
+/// `text` cut to [`COLUMN_WIDTH`] characters
+fn cut(text: &str) -> String {
+    match text.chars().count() > COLUMN_WIDTH {
+        true => text.chars().take(COLUMN_WIDTH - 3).collect::<String>() + "...",
+        false => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_01() {
+        let diff = Diff::new("1 2 3 4 5 6 7 8 9 10 11\n", "1 2 3 4 5 6 0 8 9 10 11\n");
+
+        assert_eq!(diff.first(), Some((0, 6)));
+        assert_eq!(
+            diff.to_string(),
+            "line 1, token 7: expected `7`, found `0`\n\
+             1 of 1 lines differ\n  \
+             expected: ... 3 4 5 6 [7] 8 9 10 11\n  \
+             actual:   ... 3 4 5 6 [0] 8 9 10 11"
+        );
+    }
+
+    #[test]
+    fn diff_02() {
+        let expected = "1 2\n3 4\n5 6\n7 8\n9 10\n11 12\n";
+        let actual = "1 2\n3 4\n5 6\n7 0\n9 10\n11 0\n";
+        let diff = Diff::new(expected, actual);
+
+        assert!(diff.is_matrix());
+        assert_eq!(
+            diff.to_string(),
+            "line 4, token 2: expected `8`, found `0`\n\
+             2 of 6 lines differ\n         \
+             | expected | actual\n       \
+             2 | 3 4      | 3 4\n       \
+             3 | 5 6      | 5 6\n  \
+             >    4 | 7 8      | 7 0\n       \
+             5 | 9 10     | 9 10\n  \
+             >    6 | 11 12    | 11 0"
+        );
+    }
+
+    #[test]
+    fn diff_03() {
+        let diff = Diff::new("-1\n", "-1 \n");
+
+        assert_eq!(diff.first(), None);
+        assert_eq!(diff.to_string(), "outputs differ in whitespace only");
+
+        let missing = Diff::new("1\n2\n3\n", "1\n");
+
+        assert_eq!(missing.mismatched_lines(), 2);
+        assert!(
+            missing
+                .to_string()
+                .starts_with("line 2, token 1: expected `2`, found nothing")
+        );
+    }
+}
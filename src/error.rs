@@ -0,0 +1,212 @@
+//! Error type shared by parsers, solutions and the runner.
+//!
+//! Every failure carries enough context to be printed as is, and maps to its
+//! own process exit code so scripts can tell a bad input from a broken file.
+
+use std::{fmt, io, ops::RangeInclusive};
+
+use crate::scanner::ScanError;
+
+/// Result with a [`HackerankError`]
+pub type Result<T> = std::result::Result<T, HackerankError>;
+
+#[derive(Debug)]
+pub enum HackerankError {
+    /// Reading the input or writing the answer failed
+    Io(io::Error),
+    /// The input does not have the shape the problem expects
+    Parse(ScanError),
+    /// A value is outside the limits given by the problem statement
+    Constraint { name: &'static str, message: String },
+    /// A string holds a character the problem does not allow
+    InvalidChar {
+        found: char,
+        /// 0-based character position in the string
+        position: usize,
+        expected: &'static str,
+    },
+    /// A command needs tooling the problem does not implement, such as a
+    /// generator, which is bad usage rather than a bad input
+    Unsupported { slug: String, missing: &'static str },
+    /// The input layout of a problem does not fit the Rust types it is read
+    /// into, which is a bug of the problem rather than of the input
+    Layout {
+        field: &'static str,
+        message: String,
+    },
+}
+
+impl HackerankError {
+    /// Exit codes of the errors a run reports about its input or its files,
+    /// every [`HackerankError::exit_code`] but the one of bad usage. Runners
+    /// tell these from crashes by them.
+    pub const RUN_EXIT_CODES: RangeInclusive<i32> = 3..=7;
+
+    pub fn constraint(name: &'static str, message: impl Into<String>) -> Self {
+        HackerankError::Constraint {
+            name,
+            message: message.into(),
+        }
+    }
+
+    pub fn unsupported(slug: &str, missing: &'static str) -> Self {
+        HackerankError::Unsupported {
+            slug: slug.to_string(),
+            missing,
+        }
+    }
+
+    pub fn layout(field: &'static str, message: impl Into<String>) -> Self {
+        HackerankError::Layout {
+            field,
+            message: message.into(),
+        }
+    }
+
+    /// Process exit code, 1 and 2 being taken by failed tests and bad usage
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            HackerankError::Unsupported { .. } => 2,
+            HackerankError::Io(_) => 3,
+            HackerankError::Parse(_) => 4,
+            HackerankError::Constraint { .. } => 5,
+            HackerankError::InvalidChar { .. } => 6,
+            HackerankError::Layout { .. } => 7,
+        }
+    }
+
+    /// The input line a parse error points at, numbered like an editor would
+    pub fn excerpt(&self, input: &[u8]) -> Option<String> {
+        let HackerankError::Parse(error) = self else {
+            return None;
+        };
+        let line = input
+            .split(|&byte| byte == b'\n')
+            .nth(error.line.checked_sub(1)?)?;
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+
+        Some(format!(
+            "{:>5} | {}",
+            error.line,
+            String::from_utf8_lossy(line)
+        ))
+    }
+}
+
+impl fmt::Display for HackerankError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HackerankError::Io(error) => write!(f, "{}", error),
+            HackerankError::Parse(error) => write!(f, "malformed input at {}", error),
+            HackerankError::Constraint { name, message } => {
+                write!(f, "constraint on `{}` violated: {}", name, message)
+            }
+            HackerankError::InvalidChar {
+                found,
+                position,
+                expected,
+            } => write!(
+                f,
+                "invalid character {:?} at position {}, expected {}",
+                found, position, expected
+            ),
+            HackerankError::Unsupported { slug, missing } => {
+                write!(f, "`{}` has no {}", slug, missing)
+            }
+            HackerankError::Layout { field, message } => {
+                write!(f, "layout field `{}`: {}", field, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HackerankError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HackerankError::Io(error) => Some(error),
+            HackerankError::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for HackerankError {
+    fn from(error: io::Error) -> Self {
+        HackerankError::Io(error)
+    }
+}
+
+impl From<ScanError> for HackerankError {
+    fn from(error: ScanError) -> Self {
+        HackerankError::Parse(error)
+    }
+}
+
+/// Alphabet index of a lowercase ASCII letter, `a` being 0
+pub fn letter_index(c: char, position: usize) -> Result<usize> {
+    match c {
+        'a'..='z' => Ok(c as usize - 'a' as usize),
+        _ => Err(HackerankError::InvalidChar {
+            found: c,
+            position,
+            expected: "a lowercase letter",
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::Scanner;
+
+    #[test]
+    fn error_01() {
+        let input = "2\n1 x\n";
+        let error = HackerankError::from(Scanner::new(input).vec::<i32>(3).unwrap_err());
+
+        assert_eq!(error.exit_code(), 4);
+        assert_eq!(
+            error.to_string(),
+            "malformed input at line 2, token 2: expected i32, found `x`"
+        );
+        assert_eq!(error.excerpt(input.as_bytes()).unwrap(), "    2 | 1 x");
+    }
+
+    #[test]
+    fn error_02() {
+        // A new error of a run needs its exit code in the range as well
+        let errors = [
+            HackerankError::Io(io::Error::other("disk full")),
+            HackerankError::from(Scanner::new("").next::<i32>().unwrap_err()),
+            HackerankError::constraint("n", "too big"),
+            letter_index('A', 0).unwrap_err(),
+            HackerankError::layout("n", "does not fit"),
+        ];
+
+        for error in errors {
+            assert!(
+                HackerankError::RUN_EXIT_CODES.contains(&error.exit_code()),
+                "{:?}",
+                error
+            );
+        }
+
+        let usage = HackerankError::unsupported("anagram", "generator");
+
+        assert!(!HackerankError::RUN_EXIT_CODES.contains(&usage.exit_code()));
+    }
+
+    #[test]
+    fn letter_index_01() {
+        assert_eq!(letter_index('a', 0).unwrap(), 0);
+        assert_eq!(letter_index('z', 0).unwrap(), 25);
+
+        let error = letter_index('A', 3).unwrap_err();
+
+        assert_eq!(error.exit_code(), 6);
+        assert_eq!(
+            error.to_string(),
+            "invalid character 'A' at position 3, expected a lowercase letter"
+        );
+    }
+}
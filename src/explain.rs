@@ -0,0 +1,111 @@
+//! Step by step explanations of how solutions reach their answer.
+//!
+//! Problems define their own steps, usually a `Step` enum whose `Display`
+//! reads as a sentence, and report them through [`explain!`] as they solve.
+//! Steps are only built while [`run`] solves an input, so solving
+//! otherwise costs a relaxed atomic load per step.
+
+use std::{
+    cell::RefCell,
+    fmt,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use crate::{error::Result, problem::Runnable};
+
+/// Answer of a problem along with the steps it took
+#[derive(Debug, Clone, PartialEq)]
+pub struct Explanation {
+    pub steps: Vec<String>,
+    pub answer: String,
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.steps.is_empty() {
+            writeln!(f, "no steps explained")?;
+        }
+
+        for (number, step) in self.steps.iter().enumerate() {
+            writeln!(f, "{:>3}. {}", number + 1, step)?;
+        }
+
+        write!(f, "answer:\n{}", self.answer)
+    }
+}
+
+/// Set once any thread explained a solution, so solutions skip the thread
+/// local lookup in the usual case of nobody explaining
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// Steps of the solution being explained on this thread, if any
+    static STEPS: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+/// Solve `input` and collect the steps the solution explains
+pub fn run(problem: &dyn Runnable, input: &[u8]) -> Result<Explanation> {
+    ACTIVE.store(true, Ordering::Relaxed);
+    STEPS.with(|steps| *steps.borrow_mut() = Some(Vec::new()));
+
+    let answer = problem.run(input);
+    let steps = STEPS.with(|steps| steps.borrow_mut().take());
+
+    Ok(Explanation {
+        steps: steps.unwrap_or_default(),
+        answer: answer?,
+    })
+}
+
+/// True while a solution is explained on this thread
+pub fn enabled() -> bool {
+    ACTIVE.load(Ordering::Relaxed) && STEPS.with(|steps| steps.borrow().is_some())
+}
+
+pub fn record(step: &dyn fmt::Display) {
+    STEPS.with(|steps| {
+        if let Some(steps) = steps.borrow_mut().as_mut() {
+            steps.push(step.to_string());
+        }
+    });
+}
+
+/// Record a step of the solution being explained, which is only built when
+/// one is: `explain!(Step::Clipped { .. })`
+macro_rules! explain {
+    ($step:expr) => {
+        if $crate::explain::enabled() {
+            $crate::explain::record(&$step);
+        }
+    };
+}
+
+pub(crate) use explain;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm;
+
+    #[test]
+    fn explain_01() {
+        let problem = algorithm::find("highest_value_palindrome").unwrap();
+        let explanation = run(problem, b"4 1\n3943\n").unwrap();
+
+        assert_eq!(
+            explanation.to_string(),
+            "  1. phase 1: digits 1 and 2 differ (9 and 4), both become 9\n\
+             answer:\n3993"
+        );
+        assert!(!enabled());
+    }
+
+    #[test]
+    fn explain_02() {
+        let problem = algorithm::find("two_strings").unwrap();
+        let explanation = run(problem, b"1\nhello\nworld\n").unwrap();
+
+        assert_eq!(explanation.steps, Vec::<String>::new());
+        assert_eq!(explanation.to_string(), "no steps explained\nanswer:\nYES");
+    }
+}
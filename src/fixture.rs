@@ -0,0 +1,616 @@
+//! Fixture based test runner.
+//!
+//! Fixtures live in `fixtures/<slug>/` as pairs of `inputNN.txt` and
+//! `outputNN.txt`, the same naming HackerRank uses for downloaded test cases.
+//! Every pair is run through the registered problem and the answer is compared
+//! against the expected output, either in process or in a child process under
+//! the time and memory [`limits`] of the judge. Problems with several
+//! [`Solutions`](crate::bench::Solutions) can have all of them run in process,
+//! each answer being checked against the expected output and the answer of the
+//! optimized solution.
+
+use std::{
+    collections::BTreeMap,
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+    time::Duration,
+};
+
+use crate::{
+    bench::Bench,
+    checker::Checker,
+    diff::Diff,
+    error::HackerankError,
+    harness,
+    limits::{self, Exit, Limits, Usage},
+    problem::Runnable,
+};
+
+/// Root directory holding one sub directory of fixtures per problem
+pub const FIXTURES_DIR: &str = "fixtures";
+
+/// Input and expected output files sharing the same case number
+#[derive(Debug, Clone, Default)]
+pub struct Case {
+    pub number: String,
+    pub input: Option<PathBuf>,
+    pub output: Option<PathBuf>,
+}
+
+/// Outcome of running a single case
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    TimeLimit,
+    MemoryLimit,
+    /// The solution crashed, a panic included
+    RuntimeError(String),
+    /// The problem rejected the input
+    Error(String),
+    MissingInput,
+    MissingOutput,
+}
+
+/// Verdicts of every case found for a problem, with the resources used when
+/// run in a child process
+#[derive(Debug)]
+pub struct Report {
+    pub slug: &'static str,
+    pub results: Vec<(Case, Verdict, Option<Usage>)>,
+}
+
+impl Report {
+    /// True when there is at least one case and every case passed
+    pub fn passed(&self) -> bool {
+        !self.results.is_empty()
+            && self
+                .results
+                .iter()
+                .all(|(_, verdict, _)| *verdict == Verdict::Pass)
+    }
+
+    fn count(&self, predicate: impl Fn(&Verdict) -> bool) -> usize {
+        self.results
+            .iter()
+            .filter(|(_, verdict, _)| predicate(verdict))
+            .count()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.results.is_empty() {
+            return writeln!(
+                f,
+                "{}: no fixtures found in {}",
+                self.slug,
+                dir(self.slug).display()
+            );
+        }
+
+        for (case, verdict, usage) in &self.results {
+            let usage = usage
+                .map(|usage| format!(" ({})", usage))
+                .unwrap_or_default();
+
+            match verdict {
+                Verdict::Pass => writeln!(f, "case {}: AC{}", case.number, usage)?,
+                Verdict::Fail { expected, actual } => {
+                    writeln!(f, "case {}: WA{}", case.number, usage)?;
+
+                    for line in Diff::new(expected, actual).to_string().lines() {
+                        writeln!(f, "  {}", line)?;
+                    }
+                }
+                Verdict::TimeLimit => writeln!(f, "case {}: TLE{}", case.number, usage)?,
+                Verdict::MemoryLimit => writeln!(f, "case {}: MLE{}", case.number, usage)?,
+                Verdict::RuntimeError(message) => {
+                    writeln!(f, "case {}: RE{} {}", case.number, usage, message)?
+                }
+                Verdict::Error(message) => writeln!(f, "case {}: ERROR {}", case.number, message)?,
+                Verdict::MissingInput => writeln!(f, "case {}: MISSING input", case.number)?,
+                Verdict::MissingOutput => writeln!(f, "case {}: MISSING output", case.number)?,
+            }
+        }
+
+        write!(
+            f,
+            "{}: {} cases, {} passed, {} failed, {} errors, {} missing",
+            self.slug,
+            self.results.len(),
+            self.count(|verdict| *verdict == Verdict::Pass),
+            self.count(|verdict| matches!(
+                verdict,
+                Verdict::Fail { .. }
+                    | Verdict::TimeLimit
+                    | Verdict::MemoryLimit
+                    | Verdict::RuntimeError(_)
+            )),
+            self.count(|verdict| matches!(verdict, Verdict::Error(_))),
+            self.count(|verdict| matches!(verdict, Verdict::MissingInput | Verdict::MissingOutput)),
+        )
+    }
+}
+
+/// Outcome of one solution of a problem on one case
+#[derive(Debug, Clone)]
+pub struct Variant {
+    pub solution: &'static str,
+    pub verdict: Verdict,
+    pub time: Duration,
+    /// The checker accepts the answer as the answer of the first solution,
+    /// solutions failing alike agreeing too
+    pub agrees: bool,
+}
+
+/// Verdicts of every solution of a problem on every case found
+#[derive(Debug)]
+pub struct VariantReport {
+    pub slug: &'static str,
+    pub results: Vec<(Case, Vec<Variant>)>,
+}
+
+impl VariantReport {
+    /// True when there is at least one case and every solution passed every
+    /// case with the same answer
+    pub fn passed(&self) -> bool {
+        !self.results.is_empty() && self.variants().all(Variant::passed)
+    }
+
+    fn variants(&self) -> impl Iterator<Item = &Variant> {
+        self.results.iter().flat_map(|(_, variants)| variants)
+    }
+
+    /// Time each solution took over all cases, in the order of the solutions
+    pub fn totals(&self) -> Vec<(&'static str, Duration)> {
+        let mut totals = Vec::<(&'static str, Duration)>::new();
+
+        for variant in self.variants() {
+            match totals
+                .iter_mut()
+                .find(|(solution, _)| *solution == variant.solution)
+            {
+                Some((_, total)) => *total += variant.time,
+                None => totals.push((variant.solution, variant.time)),
+            }
+        }
+
+        totals
+    }
+}
+
+impl Variant {
+    fn passed(&self) -> bool {
+        self.verdict == Verdict::Pass && self.agrees
+    }
+}
+
+impl fmt::Display for VariantReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.results.is_empty() {
+            return writeln!(
+                f,
+                "{}: no fixtures found in {}",
+                self.slug,
+                dir(self.slug).display()
+            );
+        }
+
+        for (case, variants) in &self.results {
+            writeln!(f, "case {}:", case.number)?;
+
+            let first = variants.first().map_or("", |variant| variant.solution);
+
+            for variant in variants {
+                let verdict = match &variant.verdict {
+                    Verdict::Pass => "AC",
+                    Verdict::Fail { .. } => "WA",
+                    Verdict::TimeLimit => "TLE",
+                    Verdict::MemoryLimit => "MLE",
+                    Verdict::RuntimeError(_) => "RE",
+                    Verdict::Error(_) => "ERROR",
+                    Verdict::MissingInput | Verdict::MissingOutput => "MISSING",
+                };
+
+                write!(
+                    f,
+                    "  {:<16} {:<7} {:>10.1?}",
+                    variant.solution, verdict, variant.time
+                )?;
+
+                if !variant.agrees {
+                    write!(f, "  disagrees with {}", first)?;
+                }
+
+                writeln!(f)?;
+
+                match &variant.verdict {
+                    Verdict::Fail { expected, actual } => {
+                        for line in Diff::new(expected, actual).to_string().lines() {
+                            writeln!(f, "    {}", line)?;
+                        }
+                    }
+                    Verdict::Error(message) => writeln!(f, "    {}", message)?,
+                    _ => {}
+                }
+            }
+        }
+
+        for (solution, total) in self.totals() {
+            writeln!(f, "{:<18} {:<7} {:>10.1?}", solution, "total", total)?;
+        }
+
+        write!(
+            f,
+            "{}: {} cases, {} of {} answers passed, {} disagree",
+            self.slug,
+            self.results.len(),
+            self.variants().filter(|variant| variant.passed()).count(),
+            self.variants().count(),
+            self.variants().filter(|variant| !variant.agrees).count()
+        )
+    }
+}
+
+/// Directory holding the fixtures of a problem
+pub fn dir(slug: &str) -> PathBuf {
+    Path::new(FIXTURES_DIR).join(slug)
+}
+
+/// All cases found in the fixture directory of a problem, ordered by number.
+/// A missing directory simply has no cases.
+pub fn cases(slug: &str) -> io::Result<Vec<Case>> {
+    let dir = dir(slug);
+    let mut cases = BTreeMap::<String, Case>::new();
+
+    if !dir.is_dir() {
+        return Ok(vec![]);
+    }
+
+    for entry in fs::read_dir(&dir)? {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+
+        if let Some(number) = case_number(name, "input") {
+            cases.entry(number.to_string()).or_default().input = Some(path.clone());
+        } else if let Some(number) = case_number(name, "output") {
+            cases.entry(number.to_string()).or_default().output = Some(path.clone());
+        }
+    }
+
+    Ok(cases
+        .into_iter()
+        .map(|(number, case)| Case { number, ..case })
+        .collect())
+}
+
+/// Run every fixture case of a problem
+pub fn run(problem: &dyn Runnable) -> io::Result<Report> {
+    let mut results = Vec::new();
+
+    for case in cases(problem.slug())? {
+        let verdict = run_case(problem, &case)?;
+
+        results.push((case, verdict, None));
+    }
+
+    Ok(Report {
+        slug: problem.slug(),
+        results,
+    })
+}
+
+/// Run every fixture case of a problem in a child process under `limits`
+pub fn run_limited(problem: &dyn Runnable, limits: Limits) -> io::Result<Report> {
+    let mut results = Vec::new();
+
+    for case in cases(problem.slug())? {
+        let (verdict, usage) = run_case_limited(problem, &case, limits)?;
+
+        results.push((case, verdict, usage));
+    }
+
+    Ok(Report {
+        slug: problem.slug(),
+        results,
+    })
+}
+
+/// Run every solution of a problem on every fixture case, judging answers
+/// with `checker`
+pub fn run_variants(problem: &dyn Bench, checker: Checker) -> io::Result<VariantReport> {
+    let mut results = Vec::new();
+
+    for case in cases(problem.slug())? {
+        let variants = run_case_variants(problem, checker, &case)?;
+
+        results.push((case, variants));
+    }
+
+    Ok(VariantReport {
+        slug: problem.slug(),
+        results,
+    })
+}
+
+/// Run every solution on one case and compare each answer with the expected
+/// output and with the answer of the first solution
+pub fn run_case_variants(
+    problem: &dyn Bench,
+    checker: Checker,
+    case: &Case,
+) -> io::Result<Vec<Variant>> {
+    // Solutions share the verdict of a case none of them could run
+    let all = |verdict: Verdict| {
+        problem
+            .variants()
+            .into_iter()
+            .map(|solution| Variant {
+                solution,
+                verdict: verdict.clone(),
+                time: Duration::ZERO,
+                agrees: true,
+            })
+            .collect()
+    };
+    let (input, output) = match (&case.input, &case.output) {
+        (Some(input), Some(output)) => (input, output),
+        (None, _) => return Ok(all(Verdict::MissingInput)),
+        (_, None) => return Ok(all(Verdict::MissingOutput)),
+    };
+
+    let input = fs::read_to_string(input)?;
+    let expected = fs::read_to_string(output)?;
+    let answers = match problem.answers(input.as_bytes()) {
+        Ok(answers) => answers,
+        Err(e) => return Ok(all(Verdict::Error(e.to_string()))),
+    };
+    let first = match answers.first() {
+        Some((_, answer, _)) => answer.as_ref().ok().cloned(),
+        None => None,
+    };
+
+    Ok(answers
+        .into_iter()
+        .map(|(solution, answer, time)| {
+            let agrees = match (&first, &answer) {
+                (Some(first), Ok(answer)) => checker.check(&input, first, answer),
+                (None, Err(_)) => true,
+                _ => false,
+            };
+            let verdict = match answer {
+                Ok(actual) => compare(checker, &input, expected.clone(), actual),
+                Err(e) => Verdict::Error(e.to_string()),
+            };
+
+            Variant {
+                solution,
+                verdict,
+                time,
+                agrees,
+            }
+        })
+        .collect())
+}
+
+/// Run one case and compare the answer with the expected output using the
+/// checker of the problem
+pub fn run_case(problem: &dyn Runnable, case: &Case) -> io::Result<Verdict> {
+    let (input, output) = match (&case.input, &case.output) {
+        (Some(input), Some(output)) => (input, output),
+        (None, _) => return Ok(Verdict::MissingInput),
+        (_, None) => return Ok(Verdict::MissingOutput),
+    };
+
+    let input = fs::read_to_string(input)?;
+    let expected = fs::read_to_string(output)?;
+    let mut actual = Vec::new();
+
+    if let Err(e) = harness::execute(problem, input.as_bytes(), &mut actual) {
+        return Ok(Verdict::Error(e.to_string()));
+    }
+
+    let actual = String::from_utf8_lossy(&actual).into_owned();
+
+    Ok(compare(problem.checker(), &input, expected, actual))
+}
+
+/// Run one case with `hackerank run` in a child process, the way the judge
+/// would, and tell the limits it broke from a wrong answer
+pub fn run_case_limited(
+    problem: &dyn Runnable,
+    case: &Case,
+    limits: Limits,
+) -> io::Result<(Verdict, Option<Usage>)> {
+    let (input, output) = match (&case.input, &case.output) {
+        (Some(input), Some(output)) => (input, output),
+        (None, _) => return Ok((Verdict::MissingInput, None)),
+        (_, None) => return Ok((Verdict::MissingOutput, None)),
+    };
+
+    let expected = fs::read_to_string(output)?;
+    let text = fs::read_to_string(input)?;
+    let scratch = env::temp_dir().join(format!(
+        "hackerank-{}-{}-{}",
+        process::id(),
+        problem.slug(),
+        case.number
+    ));
+    let (answer, errors) = (scratch.with_extension("out"), scratch.with_extension("err"));
+    let mut command = Command::new(env::current_exe()?);
+
+    command
+        .args(["run", problem.slug()])
+        .arg(input)
+        .env(harness::OUTPUT_PATH, &answer)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(fs::File::create(&errors)?);
+
+    let (exit, usage) = limits::run(&mut command, limits)?;
+    let actual = fs::read_to_string(&answer).unwrap_or_default();
+    let stderr = fs::read_to_string(&errors).unwrap_or_default();
+
+    let _ = fs::remove_file(&answer);
+    let _ = fs::remove_file(&errors);
+
+    let verdict = match exit {
+        Exit::TimedOut => Verdict::TimeLimit,
+        _ if out_of_memory(&usage, &stderr, limits) => Verdict::MemoryLimit,
+        Exit::Code(0) => compare(problem.checker(), &text, expected, actual),
+        _ => failure(problem.slug(), exit, &stderr),
+    };
+
+    Ok((verdict, Some(usage)))
+}
+
+/// Verdict of a child that did not answer: an error when it exited with the
+/// code of an error of the run, whose message is the first line it printed,
+/// and a runtime error otherwise
+fn failure(slug: &str, exit: Exit, stderr: &str) -> Verdict {
+    match exit {
+        Exit::Code(code) if HackerankError::RUN_EXIT_CODES.contains(&code) => Verdict::Error(
+            stderr
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .trim_start_matches(&format!("error: {}: ", slug))
+                .to_string(),
+        ),
+        Exit::Code(code) => Verdict::RuntimeError(crash(stderr, &format!("exit code {}", code))),
+        Exit::Signal(signal) => {
+            Verdict::RuntimeError(crash(stderr, &format!("killed by signal {}", signal)))
+        }
+        Exit::TimedOut => Verdict::TimeLimit,
+    }
+}
+
+/// True when a child went past the memory limit, or when Rust aborted it on
+/// an allocation the address space limit refused. Any other crash, a stack
+/// overflow or a segmentation fault included, is a runtime error.
+fn out_of_memory(usage: &Usage, stderr: &str, limits: Limits) -> bool {
+    usage.peak_rss > limits.memory || stderr.contains("memory allocation of")
+}
+
+/// Pass when the checker accepts the answer
+fn compare(checker: Checker, input: &str, expected: String, actual: String) -> Verdict {
+    if checker.check(input, &expected, &actual) {
+        Verdict::Pass
+    } else {
+        Verdict::Fail { expected, actual }
+    }
+}
+
+/// What a crashed child printed, notes left out, or `fallback` when it
+/// printed nothing
+fn crash(stderr: &str, fallback: &str) -> String {
+    let message = stderr
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with("note:"))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    match message.is_empty() {
+        true => fallback.to_string(),
+        false => message,
+    }
+}
+
+/// `NN` out of `<prefix>NN.txt`
+pub fn case_number<'a>(name: &'a str, prefix: &str) -> Option<&'a str> {
+    let number = name.strip_prefix(prefix)?.strip_suffix(".txt")?;
+
+    match !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) {
+        true => Some(number),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{algorithm, layout::Layout, limits::MIB, scanner::Scanner};
+
+    #[test]
+    fn case_number_01() {
+        assert_eq!(case_number("input07.txt", "input"), Some("07"));
+        assert_eq!(case_number("output12.txt", "output"), Some("12"));
+        assert_eq!(case_number("input.txt", "input"), None);
+        assert_eq!(case_number("inputab.txt", "input"), None);
+        assert_eq!(case_number("output01.txt", "input"), None);
+    }
+
+    #[test]
+    fn out_of_memory_01() {
+        let limits = Limits::default();
+        let usage = |peak_rss| Usage {
+            elapsed: Duration::from_millis(10),
+            peak_rss,
+        };
+
+        assert!(out_of_memory(&usage(limits.memory + 1), "", limits));
+        assert!(out_of_memory(
+            &usage(MIB),
+            "memory allocation of 4294967296 bytes failed",
+            limits
+        ));
+        assert!(!out_of_memory(
+            &usage(MIB),
+            "thread 'main' has overflowed its stack",
+            limits
+        ));
+    }
+
+    #[test]
+    fn failure_01() {
+        // A layout mismatch is an error of the run, reported like `run` does
+        let layout = Layout::new("n:usize; s:String");
+        let error = layout
+            .reader(Scanner::new("3\nabc\n"))
+            .field::<i32>("s")
+            .unwrap_err();
+        let stderr = format!("error: anagram: {}\n", error);
+
+        assert_eq!(error.exit_code(), 7);
+
+        assert_eq!(
+            failure("anagram", Exit::Code(error.exit_code()), &stderr),
+            Verdict::Error(error.to_string())
+        );
+        assert_eq!(
+            failure("anagram", Exit::Code(101), "thread 'main' panicked\n"),
+            Verdict::RuntimeError("thread 'main' panicked".to_string())
+        );
+    }
+
+    #[test]
+    fn fixtures_01() {
+        // Every solved problem ships at least its sample case, scaffolded
+        // ones only have an empty placeholder for it
+        for registration in algorithm::PROBLEMS {
+            if !registration.is_scaffold() {
+                let report = run(registration.problem).unwrap();
+
+                assert!(report.passed(), "{}", report);
+            }
+        }
+    }
+
+    #[test]
+    fn variants_01() {
+        // Every solution of every problem gives the expected answers
+        for problem in algorithm::benches() {
+            let checker = algorithm::find(problem.slug()).unwrap().checker();
+            let report = run_variants(problem, checker).unwrap();
+
+            assert!(report.passed(), "{}", report);
+        }
+    }
+}
@@ -0,0 +1,336 @@
+//! Seeded random inputs.
+//!
+//! Problems that implement [`Generate`] can produce random inputs from a seed,
+//! and write them back as the text their own parser reads. The same seed always
+//! gives the same input, so any generated case can be replayed. Sizes go up to
+//! the limits of the problem statement, and besides uniformly random inputs
+//! every problem knows a couple of adversarial [`Shape`]s.
+
+use std::{fmt, ops::RangeInclusive, str::FromStr};
+
+use crate::{
+    error::Result,
+    layout::{self, Layout, Reader, Source},
+    meta::Constraint,
+    problem::Problem,
+};
+
+/// The 26 letters HackerRank string problems are made of
+pub const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+/// Small deterministic pseudo random generator (SplitMix64)
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..n`, `n` being at least 1
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    /// Uniform integer in an inclusive range
+    pub fn int(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let span = range.end().wrapping_sub(*range.start()) as u64;
+
+        match span.checked_add(1) {
+            Some(count) => range.start().wrapping_add(self.below(count) as i64),
+            None => self.next_u64() as i64,
+        }
+    }
+
+    /// Uniform size or index in an inclusive range
+    pub fn size(&mut self, range: RangeInclusive<usize>) -> usize {
+        range.start() + self.below((range.end() - range.start()) as u64 + 1) as usize
+    }
+
+    /// `len` characters picked from `alphabet`
+    pub fn string(&mut self, len: usize, alphabet: &[u8]) -> String {
+        (0..len)
+            .map(|_| alphabet[self.below(alphabet.len() as u64) as usize] as char)
+            .collect()
+    }
+
+    /// Fisher-Yates shuffle
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.size(0..=i));
+        }
+    }
+}
+
+/// Overall form of a generated input
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shape {
+    /// Uniformly random values within the constraints
+    Random,
+    /// The same character or value everywhere
+    Uniform,
+    /// The case the problem finds hardest, such as reverse sorted arrays or
+    /// the deepest automaton; each generator documents its own
+    Worst,
+}
+
+impl Shape {
+    pub const ALL: [Shape; 3] = [Shape::Random, Shape::Uniform, Shape::Worst];
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Shape::Random => write!(f, "random"),
+            Shape::Uniform => write!(f, "uniform"),
+            Shape::Worst => write!(f, "worst"),
+        }
+    }
+}
+
+impl FromStr for Shape {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Shape::ALL
+            .into_iter()
+            .find(|shape| shape.to_string() == s)
+            .ok_or_else(|| format!("unknown shape `{}`, expected random, uniform or worst", s))
+    }
+}
+
+/// A problem able to produce random inputs
+pub trait Generate: Problem {
+    /// Largest `size` the constraints of the problem allow
+    const MAX_SIZE: usize;
+
+    /// Input of the given shape with about `size` elements along its main
+    /// dimensions, `size` being in `1..=MAX_SIZE`
+    fn generate(rng: &mut Rng, size: usize, shape: Shape) -> Self::Input;
+
+    /// Input text that [`Problem::parse`] reads back into `input`
+    fn write(input: &Self::Input) -> String;
+}
+
+/// Object safe view of a [`Generate`] problem for the generator registry
+pub trait Generator: Sync {
+    fn max_size(&self) -> usize;
+
+    /// Input text for a seed, `size` being clamped to `1..=max_size()`. The
+    /// text is parsed back and validated before being returned, so a
+    /// generator writing something its problem does not read or allow fails
+    /// here.
+    fn sample(&self, seed: u64, size: usize, shape: Shape) -> Result<String>;
+}
+
+impl<P: Generate + Sync> Generator for P {
+    fn max_size(&self) -> usize {
+        P::MAX_SIZE
+    }
+
+    fn sample(&self, seed: u64, size: usize, shape: Shape) -> Result<String> {
+        let mut rng = Rng::new(seed);
+        let text = P::write(&P::generate(&mut rng, size.clamp(1, P::MAX_SIZE), shape));
+
+        P::validate(&P::parse(text.as_bytes())?)?;
+
+        Ok(text)
+    }
+}
+
+/// Space separated values on a single line
+pub fn join<T: ToString>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Reader of random fields of `layout` within `constraints`, with counts and
+/// string lengths up to `size` and strings made of `alphabet`. Relations
+/// between fields, such as a count matching the length of a string, are left
+/// to the problem.
+pub fn layout<'a>(
+    layout: &'a Layout,
+    rng: &'a mut Rng,
+    size: usize,
+    constraints: &'a [Constraint],
+    alphabet: &'a [u8],
+) -> Reader<'a, Filler<'a>> {
+    layout.reader(Filler {
+        rng,
+        size,
+        constraints,
+        alphabet,
+        counts: layout.counts(),
+    })
+}
+
+/// Random values of a layout
+pub struct Filler<'a> {
+    rng: &'a mut Rng,
+    size: usize,
+    constraints: &'a [Constraint],
+    alphabet: &'a [u8],
+    counts: &'a [&'static str],
+}
+
+impl Filler<'_> {
+    fn bounds(&self, name: Option<&str>, fallback: (i64, i64)) -> (i64, i64) {
+        name.and_then(|name| {
+            self.constraints
+                .iter()
+                .find(|constraint| constraint.name == name)
+        })
+        .map_or(fallback, |constraint| (constraint.min, constraint.max))
+    }
+}
+
+impl Source for Filler<'_> {
+    fn int(&mut self, _: &'static str, name: Option<&str>) -> Result<i64> {
+        let size = self.size as i64;
+        let (min, max) = match name.is_some_and(|name| self.counts.contains(&name)) {
+            true => {
+                let (min, max) = self.bounds(name, (1, size));
+
+                (min.max(0), max.min(size.max(min)))
+            }
+            false => self.bounds(name, (0, size)),
+        };
+
+        Ok(self.rng.int(min..=max))
+    }
+
+    fn string(&mut self, name: Option<&str>) -> Result<String> {
+        let size = self.size as i64;
+        let (min, max) = self.bounds(name.map(layout::length_name).as_deref(), (1, size));
+        let len = self.rng.int(min.max(0)..=max.min(size.max(min)));
+
+        Ok(self.rng.string(len as usize, self.alphabet))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{algorithm, layout::Fields, scanner::Scanner};
+
+    #[test]
+    fn rng_01() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn rng_02() {
+        let mut rng = Rng::new(7);
+
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.int(-3..=3)));
+            assert!((1..=5).contains(&rng.size(1..=5)));
+        }
+
+        assert_eq!(rng.size(4..=4), 4);
+        assert!(rng.string(10, b"ab").chars().all(|c| c == 'a' || c == 'b'));
+        rng.int(i64::MIN..=i64::MAX);
+    }
+
+    #[test]
+    fn generators_01() {
+        // Scaffolded problems get a generator once they are solved
+        for registration in algorithm::PROBLEMS {
+            let slug = registration.problem.slug();
+
+            if !registration.is_scaffold() {
+                assert!(
+                    algorithm::find_generator(slug).is_some(),
+                    "{} has no generator",
+                    slug
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn generators_02() {
+        for registration in algorithm::PROBLEMS {
+            let Some(generator) = registration.generator else {
+                continue;
+            };
+            let slug = registration.problem.slug();
+
+            for shape in Shape::ALL {
+                for (seed, size) in [(0, 1), (1, 2), (2, 7), (3, 50)] {
+                    if let Err(e) = generator.sample(seed, size, shape) {
+                        panic!("{} {} size {}: {}", slug, shape, size, e);
+                    }
+                }
+
+                let text = generator.sample(4, generator.max_size(), shape);
+
+                assert!(text.is_ok(), "{} {} at max size", slug, shape);
+            }
+        }
+    }
+
+    #[test]
+    fn layout_01() {
+        const CONSTRAINTS: &[Constraint] = &[
+            Constraint::new("n", 1, 10),
+            Constraint::new("health[i]", 0, 100),
+            Constraint::new("first", 0, 9),
+            Constraint::new("|d|", 1, 5),
+        ];
+
+        let layout = Layout::new(
+            "n:usize; genes:[String;n]; health:[i64;n]; s:usize; \
+             strands:[(first:i32,last:i32,d:String);s]",
+        );
+
+        for seed in 0..20 {
+            let mut rng = Rng::new(seed);
+            let mut reader = super::layout(&layout, &mut rng, 8, CONSTRAINTS, LOWERCASE);
+            let genes: Vec<String> = reader.field("genes").unwrap();
+            let health: Vec<i64> = reader.field("health").unwrap();
+            let strands: Vec<(i32, i32, String)> = reader.field("strands").unwrap();
+            let fields = Fields::default()
+                .with("genes", &genes)
+                .with("health", &health)
+                .with("strands", &strands);
+            let text = layout.write(&fields);
+            let mut reader = layout.reader(Scanner::new(&text));
+
+            assert_eq!(reader.field::<Vec<String>>("genes").unwrap(), genes);
+            assert_eq!(reader.field::<Vec<i64>>("health").unwrap(), health);
+            assert_eq!(
+                reader.field::<Vec<(i32, i32, String)>>("strands").unwrap(),
+                strands
+            );
+
+            for (name, value) in layout.values(&fields, CONSTRAINTS) {
+                let constraint = CONSTRAINTS.iter().find(|c| c.name == name).unwrap();
+
+                assert!((constraint.min..=constraint.max).contains(&value));
+            }
+        }
+    }
+}
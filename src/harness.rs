@@ -0,0 +1,99 @@
+//! Runs registered problems the same way the HackerRank judge does.
+//!
+//! The judge feeds the whole input on stdin and reads the answer back from the
+//! file named by the `OUTPUT_PATH` environment variable. Locally `OUTPUT_PATH`
+//! is usually unset, in which case the answer goes to stdout instead.
+
+use std::{
+    env,
+    ffi::OsString,
+    fs::File,
+    io::{self, BufWriter, Read, Write},
+};
+
+use crate::{error::Result, problem::Runnable};
+
+/// Environment variable the judge uses to name the output file
+pub const OUTPUT_PATH: &str = "OUTPUT_PATH";
+
+/// The whole input the judge feeds on stdin, as the bytes the scanner reads
+/// in place
+pub fn read_stdin() -> io::Result<Vec<u8>> {
+    let mut input = Vec::new();
+    io::stdin().lock().read_to_end(&mut input)?;
+
+    Ok(input)
+}
+
+/// Solve `input` and write the answer to `OUTPUT_PATH` or stdout
+pub fn judge(problem: &dyn Runnable, input: &[u8]) -> Result<()> {
+    judge_to(problem, input, env::var_os(OUTPUT_PATH))
+}
+
+/// Solve `input` and write the answer to `path` or stdout. The output is only
+/// created once the answer is known, so an input failing to parse or validate
+/// leaves no truncated file behind.
+fn judge_to(problem: &dyn Runnable, input: &[u8], path: Option<OsString>) -> Result<()> {
+    let answer = problem.run(input)?;
+    let mut output = open_output(path)?;
+
+    writeln!(output, "{}", answer)?;
+    Ok(output.flush()?)
+}
+
+/// Solve `input` and write the answer to `output` exactly as the judge
+/// expects it, one trailing newline included
+pub fn execute<W: Write>(problem: &dyn Runnable, input: &[u8], output: &mut W) -> Result<()> {
+    Ok(writeln!(output, "{}", problem.run(input)?)?)
+}
+
+/// The file at `path`, falling back to stdout when there is none
+fn open_output(path: Option<OsString>) -> io::Result<Box<dyn Write>> {
+    match path {
+        Some(path) => Ok(Box::new(BufWriter::new(File::create(path)?))),
+        None => Ok(Box::new(BufWriter::new(io::stdout().lock()))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm;
+
+    #[test]
+    fn execute_01() {
+        let problem = algorithm::find("insertion_sort_analysis").unwrap();
+        let mut output = Vec::new();
+
+        execute(problem, b"2\n5\n1 1 1 2 2\n5\n2 1 3 1 2\n", &mut output).unwrap();
+
+        assert_eq!(output, b"0\n4\n");
+    }
+
+    #[test]
+    fn execute_02() {
+        let problem = algorithm::find("matrix_rotation").unwrap();
+        let mut output = Vec::new();
+
+        execute(problem, b"2 2 3\n1 1\n1 1\n", &mut output).unwrap();
+
+        assert_eq!(output, b"1 1\n1 1\n");
+    }
+
+    #[test]
+    fn judge_to_01() {
+        let problem = algorithm::find("insertion_sort_analysis").unwrap();
+        let path = env::temp_dir().join(format!("hackerank-judge-{}", std::process::id()));
+
+        std::fs::write(&path, "previous\n").unwrap();
+
+        // A failing input leaves the output as it was
+        assert!(judge_to(problem, b"1\n2\n1\n", Some(path.clone().into())).is_err());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "previous\n");
+
+        judge_to(problem, b"1\n2\n2 1\n", Some(path.clone().into())).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1\n");
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,257 @@
+//! Import of test cases downloaded from HackerRank.
+//!
+//! HackerRank hands out test cases as a zip holding `input/inputNN.txt` and
+//! `output/outputNN.txt`. Either the zip itself or the directory it was
+//! extracted to can be imported; the files are normalized and copied into
+//! `fixtures/<slug>/` keeping their case numbers. Zips are extracted with the
+//! system `unzip`, which has to be on the `PATH` for them.
+
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::{self, Command},
+};
+
+use crate::fixture;
+
+/// Import a zip or an extracted directory into the fixtures of a problem.
+/// Returns the paths of the files written.
+pub fn import(slug: &str, source: &Path) -> io::Result<Vec<PathBuf>> {
+    import_into(source, &fixture::dir(slug))
+}
+
+/// Same as [`import`] with an explicit target directory
+pub fn import_into(source: &Path, target: &Path) -> io::Result<Vec<PathBuf>> {
+    if source.is_dir() {
+        return copy_cases(source, target);
+    }
+
+    let extracted = env::temp_dir().join(format!("hackerank-import-{}", process::id()));
+    let result = unzip(source, &extracted).and_then(|_| copy_cases(&extracted, target));
+
+    fs::remove_dir_all(&extracted).ok();
+    result
+}
+
+/// Strip carriage returns, trailing whitespace on every line and blank lines
+/// at the end, leaving a single final newline
+pub fn normalize(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+
+    for line in text.lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+
+    while normalized.ends_with("\n\n") {
+        normalized.pop();
+    }
+
+    normalized
+}
+
+/// Extract a zip with the system `unzip`
+fn unzip(zip: &Path, destination: &Path) -> io::Result<()> {
+    let status = Command::new("unzip")
+        .arg("-o")
+        .arg("-qq")
+        .arg(zip)
+        .arg("-d")
+        .arg(destination)
+        .status()
+        .map_err(|e| match e.kind() {
+            io::ErrorKind::NotFound => io::Error::new(
+                e.kind(),
+                format!(
+                    "importing {} needs the `unzip` command, install it or import the extracted directory",
+                    zip.display()
+                ),
+            ),
+            _ => e,
+        })?;
+
+    match status.success() {
+        true => Ok(()),
+        _ => Err(io::Error::other(format!(
+            "unzip failed on {} ({})",
+            zip.display(),
+            status
+        ))),
+    }
+}
+
+/// Copy every `inputNN.txt` and `outputNN.txt` found in `source` or its
+/// `input` and `output` sub directories
+fn copy_cases(source: &Path, target: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut written = Vec::new();
+
+    fs::create_dir_all(target)?;
+
+    for dir in [
+        source.to_path_buf(),
+        source.join("input"),
+        source.join("output"),
+    ] {
+        if !dir.is_dir() {
+            continue;
+        }
+
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+
+            if !is_case_file(name) {
+                continue;
+            }
+
+            let bytes = fs::read(&path)?;
+            let destination = target.join(name);
+
+            fs::write(&destination, normalize(&String::from_utf8_lossy(&bytes)))?;
+            written.push(destination);
+        }
+    }
+
+    written.sort();
+
+    match written.is_empty() {
+        true => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("no inputNN.txt or outputNN.txt in {}", source.display()),
+        )),
+        _ => Ok(written),
+    }
+}
+
+fn is_case_file(name: &str) -> bool {
+    fixture::case_number(name, "input").is_some() || fixture::case_number(name, "output").is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_01() {
+        assert_eq!(normalize("1 2 \r\n3\t\r\n\r\n\r\n"), "1 2\n3\n");
+    }
+
+    #[test]
+    fn normalize_02() {
+        assert_eq!(normalize("abc"), "abc\n");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn import_01() {
+        let root = env::temp_dir().join(format!("hackerank-import-test-{}", process::id()));
+        let source = root.join("source");
+        let target = root.join("target");
+
+        fs::create_dir_all(source.join("input")).unwrap();
+        fs::create_dir_all(source.join("output")).unwrap();
+        fs::write(source.join("input/input07.txt"), "1 \r\n2\r\n").unwrap();
+        fs::write(source.join("output/output07.txt"), "3\r\n\r\n").unwrap();
+        fs::write(source.join("input/readme.md"), "skip me").unwrap();
+
+        let written = import_into(&source, &target).unwrap();
+        let input = fs::read_to_string(target.join("input07.txt")).unwrap();
+        let output = fs::read_to_string(target.join("output07.txt")).unwrap();
+
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(written.len(), 2);
+        assert_eq!(input, "1\n2\n");
+        assert_eq!(output, "3\n");
+    }
+
+    #[test]
+    fn import_02() {
+        let root = env::temp_dir().join(format!("hackerank-import-zip-{}", process::id()));
+        let zip = root.join("cases.zip");
+        let target = root.join("target");
+
+        fs::create_dir_all(&root).unwrap();
+        fs::write(
+            &zip,
+            stored_zip(&[
+                ("input/input03.txt", b"5\r\n1 2 \r\n"),
+                ("output/output03.txt", b"3\r\n"),
+            ]),
+        )
+        .unwrap();
+
+        let written = import_into(&zip, &target).unwrap();
+        let input = fs::read_to_string(target.join("input03.txt")).unwrap();
+        let output = fs::read_to_string(target.join("output03.txt")).unwrap();
+
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(written.len(), 2);
+        assert_eq!(input, "5\n1 2\n");
+        assert_eq!(output, "3\n");
+    }
+
+    /// Zip archive holding `files` without compression, the way a HackerRank
+    /// download is laid out
+    fn stored_zip(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut archive = Vec::new();
+        let mut directory = Vec::new();
+
+        for (name, data) in files {
+            let offset = archive.len() as u32;
+            let mut header = Vec::new();
+
+            // Version, flags, stored method, time and date
+            header.extend_from_slice(&[20, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+            header.extend_from_slice(&crc32(data).to_le_bytes());
+            header.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            header.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            header.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            header.extend_from_slice(&[0, 0]);
+
+            archive.extend_from_slice(b"PK\x03\x04");
+            archive.extend_from_slice(&header);
+            archive.extend_from_slice(name.as_bytes());
+            archive.extend_from_slice(data);
+
+            directory.extend_from_slice(b"PK\x01\x02\x14\x00");
+            directory.extend_from_slice(&header);
+            // Comment length, disk, internal and external attributes
+            directory.extend_from_slice(&[0; 10]);
+            directory.extend_from_slice(&offset.to_le_bytes());
+            directory.extend_from_slice(name.as_bytes());
+        }
+
+        let start = archive.len() as u32;
+        let count = (files.len() as u16).to_le_bytes();
+
+        archive.extend_from_slice(&directory);
+        archive.extend_from_slice(b"PK\x05\x06\x00\x00\x00\x00");
+        archive.extend_from_slice(&count);
+        archive.extend_from_slice(&count);
+        archive.extend_from_slice(&(directory.len() as u32).to_le_bytes());
+        archive.extend_from_slice(&start.to_le_bytes());
+        archive.extend_from_slice(&[0, 0]);
+        archive
+    }
+
+    fn crc32(data: &[u8]) -> u32 {
+        let mut crc = !0u32;
+
+        for byte in data {
+            crc ^= *byte as u32;
+
+            for _ in 0..8 {
+                crc = match crc & 1 {
+                    1 => (crc >> 1) ^ 0xEDB8_8320,
+                    _ => crc >> 1,
+                };
+            }
+        }
+
+        !crc
+    }
+}
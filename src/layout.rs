@@ -0,0 +1,860 @@
+//! Declarative input layouts.
+//!
+//! Most inputs are a handful of counts followed by lists of that many values.
+//! A problem can describe its input once, as in
+//! `n:usize; genes:[String;n]; health:[i64;n]`, and get from the [`Layout`]:
+//!
+//! - the parser, a [`Reader`] handing out the fields as Rust values,
+//! - the writer, laying fields out the way HackerRank does, counts included,
+//! - the values its [`Constraint`]s bound, for the validator,
+//! - random inputs within the constraints through [`generate::layout`], a
+//!   skeleton for its generator.
+//!
+//! Counts, integers and strings of the input are named after the statement, so
+//! a `health` list has its elements checked against a `health[i]` constraint
+//! and a string `s` its length against `|s|`.
+//!
+//! The grammar is made of fields separated by `;`, each being `name:type`:
+//!
+//! - `usize`, `u32`, `u64`, `i32` or `i64`, an integer,
+//! - `String`, a token without whitespace,
+//! - `[type;len]`, `len` elements, `len` being a number or the name of an
+//!   integer read before,
+//! - `(name:type, type, ...)`, a tuple whose components may be named, for
+//!   their constraints or for later components to use as a length.
+//!
+//! Layouts are part of the code of a problem, so a malformed one panics. They
+//! are parsed once, into a `static`, and read straight into the Rust types of
+//! the input; a type that does not fit the layout is a [`HackerankError`].
+//!
+//! [`generate::layout`]: crate::generate::layout
+
+use std::{any::type_name, slice, str::FromStr};
+
+use crate::{
+    error::{HackerankError, Result},
+    meta::Constraint,
+    scanner::Scanner,
+};
+
+/// Type of a field
+#[derive(Debug, Clone, PartialEq)]
+pub enum Type {
+    /// Integer read as the named Rust type
+    Int(&'static str),
+    Str,
+    List(Box<Type>, Len),
+    Tuple(Vec<Field>),
+}
+
+/// Number of elements of a list
+#[derive(Debug, Clone, PartialEq)]
+pub enum Len {
+    /// An integer read before
+    Field(&'static str),
+    Fixed(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    /// Always set for top level fields
+    pub name: Option<&'static str>,
+    pub ty: Type,
+}
+
+/// Layout of an input, made of top level fields read one after the other
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layout {
+    fields: Vec<Field>,
+    /// Names of the integers used as list lengths, anywhere in the fields
+    counts: Vec<&'static str>,
+}
+
+/// Integer types a layout accepts
+const INTEGERS: &[&str] = &["usize", "u32", "u64", "i32", "i64"];
+
+impl Layout {
+    /// Layout described by `spec`, which panics when malformed
+    pub fn new(spec: &'static str) -> Self {
+        let mut parser = SpecParser { spec, position: 0 };
+        let mut fields = Vec::new();
+
+        while !parser.done() {
+            let name = parser.name();
+
+            parser.expect(':');
+            fields.push(Field {
+                name: Some(name),
+                ty: parser.ty(),
+            });
+
+            if !parser.done() {
+                parser.expect(';');
+            }
+        }
+
+        let mut counts = Vec::new();
+
+        fields
+            .iter()
+            .for_each(|field| collect_counts(&field.ty, &mut counts));
+
+        Layout { fields, counts }
+    }
+
+    pub fn counts(&self) -> &[&'static str] {
+        &self.counts
+    }
+
+    /// Reader of the fields of the layout from `source`, such as the
+    /// [`Scanner`] of an input or of one of its cases
+    pub fn reader<S: Source>(&self, source: S) -> Reader<'_, S> {
+        Reader {
+            fields: self.fields.iter(),
+            counts: &self.counts,
+            source,
+            scope: Vec::new(),
+            field: "",
+        }
+    }
+
+    /// Input text holding `fields`, the counts left out being the lengths of
+    /// the lists they give
+    pub fn write(&self, fields: &Fields) -> String {
+        let mut types = Vec::new();
+        let mut values = Vec::new();
+        let mut lines = Vec::new();
+
+        self.complete(fields, |field, value| {
+            types.push(&field.ty);
+            values.push(value);
+        });
+        write_sequence(&types, &values, false, &mut lines);
+
+        lines.join("\n") + "\n"
+    }
+
+    /// Values of `fields` the `constraints` bound, named as the constraints
+    /// are, counts left out included
+    pub fn values(
+        &self,
+        fields: &Fields,
+        constraints: &'static [Constraint],
+    ) -> Vec<(&'static str, i64)> {
+        let mut named = Vec::new();
+
+        self.complete(fields, |field, value| {
+            bounded(field.name, &field.ty, value, &mut named)
+        });
+
+        named
+            .into_iter()
+            .filter_map(|(name, value)| {
+                let constraint = constraints
+                    .iter()
+                    .find(|constraint| constraint.name == name)?;
+
+                Some((constraint.name, value))
+            })
+            .collect()
+    }
+
+    /// Visit the value of every top level field, a missing count being the
+    /// length of the first list using it. Other missing fields are left out.
+    fn complete<'s, 'a>(&'s self, fields: &Fields<'a>, mut visit: impl FnMut(&'s Field, View<'a>)) {
+        for field in &self.fields {
+            let name = name(field);
+
+            if let Some(value) = fields.get(name) {
+                visit(field, value.view());
+                continue;
+            }
+
+            let count = self.fields.iter().find_map(|other| match &other.ty {
+                Type::List(_, Len::Field(len)) if *len == name => {
+                    match fields.get(other.name?)?.view() {
+                        View::List(items) => Some(items.len()),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            });
+
+            if let Some(count) = count {
+                visit(field, View::Int(count as i64));
+            }
+        }
+    }
+}
+
+/// Names of the integers `ty` uses as list lengths
+fn collect_counts(ty: &Type, counts: &mut Vec<&'static str>) {
+    match ty {
+        Type::List(element, len) => {
+            if let Len::Field(name) = len {
+                counts.push(name);
+            }
+
+            collect_counts(element, counts);
+        }
+        Type::Tuple(fields) => fields
+            .iter()
+            .for_each(|field| collect_counts(&field.ty, counts)),
+        _ => {}
+    }
+}
+
+fn name(field: &Field) -> &'static str {
+    field.name.expect("top level fields are named")
+}
+
+/// Where the values of a layout come from: the scanner of an input, or random
+/// values for a generator
+pub trait Source {
+    /// Integer of the Rust type `kind`, `name` being the constraint bounding
+    /// it when there is one
+    fn int(&mut self, kind: &'static str, name: Option<&str>) -> Result<i64>;
+
+    /// String whose length the `|name|` constraint bounds
+    fn string(&mut self, name: Option<&str>) -> Result<String>;
+}
+
+impl Source for Scanner<'_> {
+    fn int(&mut self, kind: &'static str, _: Option<&str>) -> Result<i64> {
+        Ok(match kind {
+            "usize" => self.next::<usize>()? as i64,
+            "u32" => self.next::<u32>()? as i64,
+            "u64" => self.next::<u64>()? as i64,
+            "i32" => self.next::<i32>()? as i64,
+            _ => self.next::<i64>()?,
+        })
+    }
+
+    fn string(&mut self, _: Option<&str>) -> Result<String> {
+        Ok(self.next::<String>()?)
+    }
+}
+
+impl<S: Source + ?Sized> Source for &mut S {
+    fn int(&mut self, kind: &'static str, name: Option<&str>) -> Result<i64> {
+        (**self).int(kind, name)
+    }
+
+    fn string(&mut self, name: Option<&str>) -> Result<String> {
+        (**self).string(name)
+    }
+}
+
+/// Fields of a layout read one after the other into Rust values
+pub struct Reader<'l, S> {
+    fields: slice::Iter<'l, Field>,
+    counts: &'l [&'static str],
+    source: S,
+    /// Counts read so far, for the lengths of the lists after them
+    scope: Vec<(&'static str, i64)>,
+    /// Top level field being read, for errors
+    field: &'static str,
+}
+
+impl<S: Source> Reader<'_, S> {
+    /// Field `name` as a `T`, the fields before it being read and dropped,
+    /// counts included
+    pub fn field<T: FromLayout>(&mut self, name: &'static str) -> Result<T> {
+        while let Some(field) = self.fields.next() {
+            self.field = self::name(field);
+
+            if field.name == Some(name) {
+                return T::read(&field.ty, field.name, self);
+            }
+
+            Skip::read(&field.ty, field.name, self)?;
+        }
+
+        Err(HackerankError::layout(
+            name,
+            "not found after the fields read",
+        ))
+    }
+
+    /// Integer of the Rust type `kind`, bound to its name when a list uses it
+    /// as length
+    fn int(&mut self, kind: &'static str, name: Option<&str>) -> Result<i64> {
+        let value = self.source.int(kind, name)?;
+
+        if let Some(count) = self.counts.iter().find(|count| Some(**count) == name) {
+            self.scope.push((count, value));
+        }
+
+        Ok(value)
+    }
+
+    /// Number of elements of a list
+    fn len(&self, len: &Len) -> Result<usize> {
+        let name = match len {
+            Len::Fixed(len) => return Ok(*len),
+            Len::Field(name) => name,
+        };
+        let Some(&(_, len)) = self.scope.iter().rev().find(|(field, _)| field == name) else {
+            return Err(HackerankError::layout(
+                self.field,
+                format!("`{}` is used before being read", name),
+            ));
+        };
+
+        usize::try_from(len).map_err(|_| {
+            HackerankError::constraint(name, format!("found {}, expected a count", len))
+        })
+    }
+
+    /// The field being read is of type `ty`, which `T` cannot hold
+    fn mismatch<T>(&self, ty: &Type) -> HackerankError {
+        HackerankError::layout(
+            self.field,
+            format!("{:?} does not fit `{}`", ty, type_name::<T>()),
+        )
+    }
+}
+
+/// Rust value a field of a layout is read into
+pub trait FromLayout: Sized {
+    /// Value of type `ty`, `name` being the constraint bounding it
+    fn read<S: Source>(ty: &Type, name: Option<&str>, reader: &mut Reader<S>) -> Result<Self>;
+}
+
+/// Rust value a field of a layout is written from
+pub trait ToLayout {
+    fn view(&self) -> View<'_>;
+}
+
+/// What a value holds, as far as writing it goes
+pub enum View<'a> {
+    Int(i64),
+    Str(&'a str),
+    /// Elements of a list
+    List(Vec<&'a dyn ToLayout>),
+    /// Components of a tuple
+    Tuple(Vec<&'a dyn ToLayout>),
+}
+
+/// Any value, read and dropped
+struct Skip;
+
+impl FromLayout for Skip {
+    fn read<S: Source>(ty: &Type, name: Option<&str>, reader: &mut Reader<S>) -> Result<Self> {
+        match ty {
+            Type::Int(kind) => reader.int(kind, name).map(|_| Skip),
+            Type::Str => reader.source.string(name).map(|_| Skip),
+            Type::List(..) => Vec::<Skip>::read(ty, name, reader).map(|_| Skip),
+            Type::Tuple(fields) => {
+                let outer = reader.scope.len();
+
+                for field in fields {
+                    Skip::read(&field.ty, field.name, reader)?;
+                }
+
+                reader.scope.truncate(outer);
+                Ok(Skip)
+            }
+        }
+    }
+}
+
+macro_rules! integer_layout {
+    ($($ty:ty),*) => {
+        $(
+            impl FromLayout for $ty {
+                fn read<S: Source>(
+                    ty: &Type,
+                    name: Option<&str>,
+                    reader: &mut Reader<S>,
+                ) -> Result<Self> {
+                    let Type::Int(kind) = ty else {
+                        return Err(reader.mismatch::<$ty>(ty));
+                    };
+                    let value = reader.int(kind, name)?;
+
+                    <$ty>::try_from(value).map_err(|_| reader.mismatch::<$ty>(ty))
+                }
+            }
+
+            impl ToLayout for $ty {
+                fn view(&self) -> View<'_> {
+                    View::Int(*self as i64)
+                }
+            }
+        )*
+    };
+}
+
+integer_layout!(usize, u32, u64, i32, i64);
+
+impl FromLayout for String {
+    fn read<S: Source>(ty: &Type, name: Option<&str>, reader: &mut Reader<S>) -> Result<Self> {
+        match ty {
+            Type::Str => reader.source.string(name),
+            _ => Err(reader.mismatch::<String>(ty)),
+        }
+    }
+}
+
+impl ToLayout for String {
+    fn view(&self) -> View<'_> {
+        View::Str(self)
+    }
+}
+
+impl<T: FromLayout> FromLayout for Vec<T> {
+    fn read<S: Source>(ty: &Type, name: Option<&str>, reader: &mut Reader<S>) -> Result<Self> {
+        let Type::List(element, len) = ty else {
+            return Err(reader.mismatch::<Self>(ty));
+        };
+        let len = reader.len(len)?;
+        let name = name.map(element_name);
+
+        (0..len)
+            .map(|_| T::read(element, name.as_deref(), reader))
+            .collect()
+    }
+}
+
+impl<T: ToLayout> ToLayout for Vec<T> {
+    fn view(&self) -> View<'_> {
+        View::List(self.iter().map(|item| item as &dyn ToLayout).collect())
+    }
+}
+
+macro_rules! tuple_layout {
+    ($($ty:ident $index:tt $field:ident),*) => {
+        impl<$($ty: FromLayout),*> FromLayout for ($($ty,)*) {
+            fn read<S: Source>(ty: &Type, _: Option<&str>, reader: &mut Reader<S>) -> Result<Self> {
+                let Type::Tuple(fields) = ty else {
+                    return Err(reader.mismatch::<Self>(ty));
+                };
+                let [$($field),*] = fields.as_slice() else {
+                    return Err(reader.mismatch::<Self>(ty));
+                };
+                let outer = reader.scope.len();
+                let value = ($($ty::read(&$field.ty, $field.name, reader)?,)*);
+
+                // Components only serve as lengths within their own tuple
+                reader.scope.truncate(outer);
+                Ok(value)
+            }
+        }
+
+        impl<$($ty: ToLayout),*> ToLayout for ($($ty,)*) {
+            fn view(&self) -> View<'_> {
+                View::Tuple(vec![$(&self.$index),*])
+            }
+        }
+    };
+}
+
+tuple_layout!(A 0 a, B 1 b);
+tuple_layout!(A 0 a, B 1 b, C 2 c);
+
+/// Named values of the top level fields of a layout, to write them
+#[derive(Default)]
+pub struct Fields<'a>(Vec<(&'static str, &'a dyn ToLayout)>);
+
+impl<'a> Fields<'a> {
+    pub fn with<T: ToLayout>(mut self, name: &'static str, value: &'a T) -> Self {
+        self.0.push((name, value));
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&'a dyn ToLayout> {
+        self.0
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, value)| *value)
+    }
+}
+
+/// Lines of values read one after the other: integers in a row share a line,
+/// as do strings within a `row` such as a tuple, lists of them take one line,
+/// other lists one line per element
+fn write_sequence(types: &[&Type], values: &[View], row: bool, lines: &mut Vec<String>) {
+    let mut line = Vec::new();
+
+    for (ty, value) in types.iter().zip(values) {
+        match value {
+            View::Int(n) => line.push(n.to_string()),
+            View::Str(s) if row => line.push(s.to_string()),
+            _ => {
+                if !line.is_empty() {
+                    lines.push(line.join(" "));
+                    line.clear();
+                }
+
+                match value {
+                    View::Str(s) => lines.push(s.to_string()),
+                    _ => write_compound(ty, value, lines),
+                }
+            }
+        }
+    }
+
+    if !line.is_empty() {
+        lines.push(line.join(" "));
+    }
+}
+
+fn write_compound(ty: &Type, value: &View, lines: &mut Vec<String>) {
+    match (ty, value) {
+        (Type::List(element, _), View::List(items))
+            if matches!(**element, Type::Int(_) | Type::Str) =>
+        {
+            let mut line = Vec::new();
+
+            write_sequence(
+                &vec![&**element; items.len()],
+                &views(items),
+                true,
+                &mut line,
+            );
+            lines.push(line.concat());
+        }
+        (Type::List(element, _), View::List(items)) => {
+            for item in items {
+                write_sequence(&[&**element], &[item.view()], true, lines);
+            }
+        }
+        (Type::Tuple(fields), View::Tuple(items)) => write_sequence(
+            &fields.iter().map(|field| &field.ty).collect::<Vec<_>>(),
+            &views(items),
+            true,
+            lines,
+        ),
+        // Values not fitting their type are left out, for the parser to
+        // report when the text is read back
+        _ => {}
+    }
+}
+
+fn views<'a>(items: &[&'a dyn ToLayout]) -> Vec<View<'a>> {
+    items.iter().map(|item| item.view()).collect()
+}
+
+/// Constraint name of the elements of a list named `name`: `a[i]` for `a`,
+/// `a[i][j]` for `a[i]`
+pub fn element_name(name: &str) -> String {
+    match name.strip_suffix("[i]") {
+        Some(_) => name.to_string() + "[j]",
+        None => name.to_string() + "[i]",
+    }
+}
+
+/// Constraint name of the length of a string named `name`, `|a|` for both `a`
+/// and its elements `a[i]`
+pub fn length_name(name: &str) -> String {
+    format!("|{}|", name.split('[').next().unwrap_or(name))
+}
+
+/// Collect the integers and string lengths of `value` under the names of
+/// their constraints, parts not fitting `ty` being left out
+fn bounded(name: Option<&str>, ty: &Type, value: View, named: &mut Vec<(String, i64)>) {
+    match (ty, value) {
+        (Type::Int(_), View::Int(n)) => named.extend(name.map(|name| (name.to_string(), n))),
+        (Type::Str, View::Str(s)) => {
+            named.extend(name.map(|name| (length_name(name), s.len() as i64)))
+        }
+        (Type::List(element, _), View::List(items)) => {
+            let name = name.map(element_name);
+
+            for item in items {
+                bounded(name.as_deref(), element, item.view(), named);
+            }
+        }
+        (Type::Tuple(fields), View::Tuple(items)) => {
+            for (field, item) in fields.iter().zip(items) {
+                bounded(field.name, &field.ty, item.view(), named);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Recursive descent over a layout spec
+struct SpecParser {
+    spec: &'static str,
+    position: usize,
+}
+
+impl SpecParser {
+    fn skip_whitespace(&mut self) {
+        let rest = &self.spec[self.position..];
+
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn done(&mut self) -> bool {
+        self.skip_whitespace();
+        self.position == self.spec.len()
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.spec[self.position..].chars().next()
+    }
+
+    fn expect(&mut self, expected: char) {
+        match self.peek() {
+            Some(c) if c == expected => self.position += 1,
+            found => self.fail(&format!("`{}`, found {:?}", expected, found)),
+        }
+    }
+
+    /// Identifier or number
+    fn word(&mut self) -> &'static str {
+        self.skip_whitespace();
+
+        let spec = self.spec;
+        let rest = &spec[self.position..];
+        let len = rest
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .unwrap_or(rest.len());
+
+        self.position += len;
+        &rest[..len]
+    }
+
+    fn name(&mut self) -> &'static str {
+        match self.word() {
+            "" => self.fail("a name"),
+            name => name,
+        }
+    }
+
+    fn ty(&mut self) -> Type {
+        match self.peek() {
+            Some('[') => {
+                self.position += 1;
+
+                let element = self.ty();
+
+                self.expect(';');
+
+                let len = match self.word() {
+                    "" => self.fail("a length"),
+                    len => usize::from_str(len).map_or(Len::Field(len), Len::Fixed),
+                };
+
+                self.expect(']');
+                Type::List(Box::new(element), len)
+            }
+            Some('(') => {
+                self.position += 1;
+
+                let mut fields = Vec::new();
+
+                loop {
+                    let start = self.position;
+                    let word = self.word();
+                    let field = match self.peek() {
+                        Some(':') => {
+                            self.position += 1;
+                            Field {
+                                name: Some(word),
+                                ty: self.ty(),
+                            }
+                        }
+                        _ => {
+                            self.position = start;
+                            Field {
+                                name: None,
+                                ty: self.ty(),
+                            }
+                        }
+                    };
+
+                    fields.push(field);
+
+                    match self.peek() {
+                        Some(',') => self.position += 1,
+                        _ => break,
+                    }
+                }
+
+                self.expect(')');
+                Type::Tuple(fields)
+            }
+            _ => match self.word() {
+                "String" => Type::Str,
+                word => match INTEGERS.iter().find(|integer| **integer == word) {
+                    Some(integer) => Type::Int(integer),
+                    None => self.fail("a type"),
+                },
+            },
+        }
+    }
+
+    fn fail(&self, expected: &str) -> ! {
+        panic!(
+            "malformed layout `{}` at {}: expected {}",
+            self.spec, self.position, expected
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DNA: &str = "n:usize; genes:[String;n]; health:[i64;n]; s:usize; \
+                       strands:[(first:i32,last:i32,d:String);s]";
+
+    const CONSTRAINTS: &[Constraint] = &[
+        Constraint::new("n", 1, 10),
+        Constraint::new("health[i]", 0, 100),
+        Constraint::new("first", 0, 9),
+        Constraint::new("|d|", 1, 5),
+    ];
+
+    type Dna = (Vec<String>, Vec<i64>, Vec<(i32, i32, String)>);
+
+    fn read_dna(layout: &Layout, input: &str) -> Result<Dna> {
+        let mut reader = layout.reader(Scanner::new(input));
+
+        Ok((
+            reader.field("genes")?,
+            reader.field("health")?,
+            reader.field("strands")?,
+        ))
+    }
+
+    #[test]
+    fn layout_01() {
+        let layout = Layout::new("t:usize; cases:[(n:usize, arr:[i32;n]);t]");
+        let cases = Type::Tuple(vec![
+            Field {
+                name: Some("n"),
+                ty: Type::Int("usize"),
+            },
+            Field {
+                name: Some("arr"),
+                ty: Type::List(Box::new(Type::Int("i32")), Len::Field("n")),
+            },
+        ]);
+
+        assert_eq!(
+            layout.fields[1].ty,
+            Type::List(Box::new(cases), Len::Field("t"))
+        );
+        assert_eq!(layout.counts(), &["t", "n"]);
+        assert_eq!(
+            Layout::new("grid:[[i64;3];3]").fields[0].ty,
+            Type::List(
+                Box::new(Type::List(Box::new(Type::Int("i64")), Len::Fixed(3))),
+                Len::Fixed(3)
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a type")]
+    fn layout_02() {
+        Layout::new("n:float");
+    }
+
+    #[test]
+    fn read_01() {
+        let layout = Layout::new(DNA);
+        let input = "2\na bc\n3 4\n2\n0 1 abc\n1 1 bcd\n";
+        let (genes, health, strands) = read_dna(&layout, input).unwrap();
+
+        assert_eq!(genes, vec!["a", "bc"]);
+        assert_eq!(health, vec![3, 4]);
+        assert_eq!(
+            strands,
+            vec![(0, 1, "abc".to_string()), (1, 1, "bcd".to_string())]
+        );
+        assert_eq!(
+            layout.write(
+                &Fields::default()
+                    .with("genes", &genes)
+                    .with("health", &health)
+                    .with("strands", &strands)
+            ),
+            input
+        );
+
+        assert!(read_dna(&layout, "2\na bc\n3\n").is_err());
+
+        let layout = Layout::new("n:i32; a:[i32;n]");
+
+        assert!(
+            layout
+                .reader(Scanner::new("-1\n"))
+                .field::<Vec<i32>>("a")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn read_02() {
+        let layout = Layout::new(DNA);
+        let input = "1\na\n3\n1\n0 0 a\n";
+
+        // Types that do not fit the layout and missing fields are errors
+        let mut reader = layout.reader(Scanner::new(input));
+        let error = reader.field::<Vec<i64>>("genes").unwrap_err();
+
+        assert_eq!(error.exit_code(), 7);
+        assert!(
+            error
+                .to_string()
+                .starts_with("layout field `genes`: Str does not fit")
+        );
+
+        let mut reader = layout.reader(Scanner::new(input));
+
+        assert!(reader.field::<Vec<(i32, i32)>>("strands").is_err());
+
+        let mut reader = layout.reader(Scanner::new(input));
+
+        assert!(reader.field::<Vec<i64>>("health").is_ok());
+        assert!(reader.field::<Vec<String>>("genes").is_err());
+        assert!(reader.field::<usize>("q").is_err());
+    }
+
+    #[test]
+    fn write_01() {
+        let layout = Layout::new("n:i32; k:i32; s:String");
+        let s = "3943".to_string();
+        let fields = Fields::default().with("n", &4).with("k", &1).with("s", &s);
+
+        assert_eq!(layout.write(&fields), "4 1\n3943\n");
+
+        // Counts are the lengths of the lists they give
+        let layout = Layout::new("t:usize; cases:[(n:usize, arr:[i32;n]);t]");
+        let cases = vec![(2, vec![2, 1]), (1, vec![5])];
+
+        assert_eq!(
+            layout.write(&Fields::default().with("cases", &cases)),
+            "2\n2\n2 1\n1\n5\n"
+        );
+    }
+
+    #[test]
+    fn values_01() {
+        let layout = Layout::new(DNA);
+        let (genes, health, strands) =
+            read_dna(&layout, "2\na bc\n3 400\n1\n0 1 abcdef\n").unwrap();
+        let fields = Fields::default()
+            .with("genes", &genes)
+            .with("health", &health)
+            .with("strands", &strands);
+
+        assert_eq!(
+            layout.values(&fields, CONSTRAINTS),
+            vec![
+                ("n", 2),
+                ("health[i]", 3),
+                ("health[i]", 400),
+                ("first", 0),
+                ("|d|", 6)
+            ]
+        );
+    }
+}
@@ -0,0 +1,273 @@
+//! Time and memory limits for solutions run in a child process.
+//!
+//! HackerRank kills a submission that runs past its time limit or allocates
+//! past its memory limit, however right the answer. The child gets an address
+//! space limit through `setrlimit` before it starts, the wall clock is watched
+//! from here, and the peak resident set size is sampled from `/proc` while the
+//! child runs. A child that is `hackerank` itself also reports its own peak
+//! before exiting, which catches runs too short to be sampled. Linux only,
+//! like the judge, and the address space limit is only set on x86-64 and
+//! aarch64, whose `RLIMIT_AS` and `struct rlimit` are declared here for want
+//! of a libc crate. Other targets only get the peak RSS checked.
+
+use std::{
+    env, fmt, fs, io,
+    os::unix::process::{CommandExt, ExitStatusExt},
+    path::Path,
+    process::{self, Command},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Environment variable naming the file a child writes its peak RSS to
+pub const PEAK_RSS_PATH: &str = "HACKERANK_PEAK_RSS";
+
+/// `RLIMIT_AS` of Linux on x86-64 and aarch64, which follow the generic
+/// numbering of `asm-generic/resource.h`
+#[cfg(all(
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+const RLIMIT_AS: i32 = 9;
+
+/// `struct rlimit` of 64-bit Linux
+#[cfg(all(
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+#[repr(C)]
+struct Rlimit {
+    current: u64,
+    maximum: u64,
+}
+
+#[cfg(all(
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+unsafe extern "C" {
+    fn setrlimit(resource: i32, limit: *const Rlimit) -> i32;
+}
+
+/// Limit the address space of `command` to `bytes` once it is spawned
+#[cfg(all(
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+fn limit_address_space(command: &mut Command, bytes: u64) {
+    let memory = Rlimit {
+        current: bytes,
+        maximum: bytes,
+    };
+
+    // SAFETY: setrlimit is async-signal-safe and `memory` outlives the spawn
+    unsafe {
+        command.pre_exec(move || match setrlimit(RLIMIT_AS, &memory) {
+            0 => Ok(()),
+            _ => Err(io::Error::last_os_error()),
+        });
+    }
+}
+
+/// Targets whose `RLIMIT_AS` is not known here run without the limit
+#[cfg(not(all(
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+)))]
+fn limit_address_space(_: &mut Command, _: u64) {}
+
+/// How often the child is checked for exit, time and memory
+const POLL: Duration = Duration::from_millis(1);
+
+/// Limits a child process runs under
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Limits {
+    /// Wall clock time
+    pub time: Duration,
+    /// Address space in bytes
+    pub memory: u64,
+}
+
+impl Default for Limits {
+    /// The limits HackerRank gives Rust submissions
+    fn default() -> Self {
+        Limits {
+            time: Duration::from_secs(5),
+            memory: 512 * MIB,
+        }
+    }
+}
+
+pub const MIB: u64 = 1024 * 1024;
+
+/// How the child ended
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Exit {
+    Code(i32),
+    Signal(i32),
+    /// Killed for running past the time limit
+    TimedOut,
+}
+
+/// Resources a child used
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Usage {
+    pub elapsed: Duration,
+    /// Largest resident set size seen, in bytes. Sampled, so a child other
+    /// than `hackerank` exiting within a millisecond may report less than it
+    /// used.
+    pub peak_rss: u64,
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.1?}, {:.1} MiB",
+            self.elapsed,
+            self.peak_rss as f64 / MIB as f64
+        )
+    }
+}
+
+/// Spawn `command` under `limits` and wait for it, killing it at the time
+/// limit. A child running past the limit counts as timed out even when it
+/// managed to exit before being checked.
+pub fn run(command: &mut Command, limits: Limits) -> io::Result<(Exit, Usage)> {
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+    let report = env::temp_dir().join(format!(
+        "hackerank-{}-{}.rss",
+        process::id(),
+        RUNS.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    limit_address_space(command, limits.memory);
+
+    let start = Instant::now();
+    let mut child = command.env(PEAK_RSS_PATH, &report).spawn()?;
+    let status = Path::new("/proc")
+        .join(child.id().to_string())
+        .join("status");
+    let mut peak_rss = 0;
+
+    let exit = loop {
+        peak_rss = peak_rss.max(resident_peak(&status).unwrap_or(0));
+
+        if let Some(status) = child.try_wait()? {
+            break match (status.code(), status.signal()) {
+                _ if start.elapsed() > limits.time => Exit::TimedOut,
+                (Some(code), _) => Exit::Code(code),
+                (_, Some(signal)) => Exit::Signal(signal),
+                _ => unreachable!("a process exits with a code or a signal"),
+            };
+        }
+
+        if start.elapsed() > limits.time {
+            child.kill()?;
+            child.wait()?;
+            break Exit::TimedOut;
+        }
+
+        thread::sleep(POLL);
+    };
+
+    let elapsed = start.elapsed().min(limits.time);
+    let reported = fs::read_to_string(&report)
+        .ok()
+        .and_then(|text| text.trim().parse().ok());
+
+    let _ = fs::remove_file(&report);
+
+    let usage = Usage {
+        elapsed,
+        peak_rss: peak_rss.max(reported.unwrap_or(0)),
+    };
+
+    Ok((exit, usage))
+}
+
+/// Write the peak RSS of this process to the file named by [`PEAK_RSS_PATH`],
+/// if the parent set it
+pub fn report_peak_rss() -> io::Result<()> {
+    match (
+        env::var_os(PEAK_RSS_PATH),
+        resident_peak(Path::new("/proc/self/status")),
+    ) {
+        (Some(path), Some(peak)) => fs::write(path, peak.to_string()),
+        _ => Ok(()),
+    }
+}
+
+/// `VmHWM` out of a `/proc/<pid>/status` file, in bytes
+fn resident_peak(status: &Path) -> Option<u64> {
+    let status = fs::read_to_string(status).ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    let kilobytes = line.split_whitespace().nth(1)?.parse::<u64>().ok()?;
+
+    Some(kilobytes * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Stdio;
+
+    fn shell(script: &str) -> Command {
+        let mut command = Command::new("sh");
+
+        command.args(["-c", script]).stderr(Stdio::null());
+        command
+    }
+
+    #[test]
+    fn run_01() {
+        let (exit, usage) = run(&mut shell("exit 3"), Limits::default()).unwrap();
+
+        assert_eq!(exit, Exit::Code(3));
+        assert!(usage.elapsed < Limits::default().time);
+    }
+
+    #[test]
+    fn run_02() {
+        let limits = Limits {
+            time: Duration::from_millis(100),
+            ..Limits::default()
+        };
+        let (exit, usage) = run(&mut shell("sleep 5"), limits).unwrap();
+
+        assert_eq!(exit, Exit::TimedOut);
+        assert_eq!(usage.elapsed, limits.time);
+    }
+
+    #[test]
+    fn run_03() {
+        let limits = Limits {
+            time: Duration::from_millis(10),
+            ..Limits::default()
+        };
+        let (exit, _) = run(&mut shell("sleep 0.05; exit 0"), limits).unwrap();
+
+        assert_eq!(exit, Exit::TimedOut);
+    }
+
+    #[test]
+    fn run_04() {
+        // Holding 64 MiB in a shell variable fails under a 16 MiB limit and
+        // shows up in the peak RSS under a generous one
+        let script = "x=$(head -c 67108864 /dev/zero | tr '\\0' a); sleep 0.1";
+        let tight = Limits {
+            memory: 16 * MIB,
+            ..Limits::default()
+        };
+        let (exit, _) = run(&mut shell(script), tight).unwrap();
+
+        assert_ne!(exit, Exit::Code(0));
+
+        let (exit, usage) = run(&mut shell(script), Limits::default()).unwrap();
+
+        assert_eq!(exit, Exit::Code(0));
+        assert!(usage.peak_rss > 64 * MIB, "{}", usage);
+    }
+}
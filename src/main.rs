@@ -1,5 +1,706 @@
 mod algorithm;
+mod answer;
+mod bench;
+mod bundle;
+mod cases;
+mod checker;
+mod complexity;
+mod diff;
+mod error;
+mod explain;
+mod fixture;
+mod generate;
+mod harness;
+mod import;
+mod layout;
+mod limits;
+mod meta;
+mod problem;
+mod scaffold;
+mod scanner;
+mod shrink;
+mod stress;
+mod trace;
+
+use std::{
+    env, fmt, fs, io,
+    path::Path,
+    process,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use bench::Bench;
+use checker::Rechecked;
+use error::{HackerankError, Result};
+use generate::{Generator, Shape};
+use limits::Limits;
+use problem::Runnable;
+
+const USAGE: &str = "usage:
+  hackerank run <slug> [input-file] [--trace <filter>] [--trace-file <file>]
+      Reads the input from stdin unless a file is given and writes the answer
+      to the file named by OUTPUT_PATH, or to stdout when it is not set.
+      --trace shows the trace events of the solution on stderr, or saves them
+      to the trace file, at the verbosity the filter gives each problem:
+      off, info, debug or trace for all of them, <slug>=<level> for one, or
+      <slug> for every event of one, comma separated as in
+      \"info,dna_health=trace\". Unknown slugs are rejected.
+  hackerank test <slug> [--time 5] [--memory 512] [--checker <checker>]
+                 [--in-process] [--variants]
+      Runs every fixtures/<slug>/inputNN.txt in a child process limited to
+      the given seconds of wall clock and MiB of address space, HackerRank's
+      limits for Rust by default, and compares the answer with outputNN.txt.
+      Reports AC, WA, TLE, MLE or RE along with the time and peak memory.
+      --in-process runs the cases without a child process nor limits.
+      --variants runs every solution of the problem in process instead,
+      checking that they all agree, and reports the time each one took.
+      --checker compares answers with exact, tokens, float or
+      float:<epsilon> instead of the checker of the problem.
+  hackerank import <slug> <zip-or-dir>
+      Copies the inputNN.txt and outputNN.txt files of a HackerRank test case
+      download into fixtures/<slug>, normalizing line endings and trailing
+      whitespace. Zips are extracted with the system unzip command.
+  hackerank new <slug> [--domain algorithm] [--signature <spec>]
+      Creates src/<domain>/<slug>.rs from src/template.rs, registers it and
+      adds an empty sample fixture. The signature spec gives the parameters
+      and answer type of the generated solution, for example
+      \"s: &str, n: i32, k: i32 -> String\".
+  hackerank stress <slug> [--seed <n>] [--cases 1000] [--size 10]
+      Compares the optimized solution with the reference one on generated
+      inputs and stops on the first mismatch, printing its seed and input
+      followed by the smallest input that still shows the mismatch.
+      The first seed is random unless given; --seed <n> --cases 1 replays a
+      single case.
+  hackerank generate <slug> [--seed <n>] [--size <max>] [--shape random]
+      Writes a random input within the constraints of the problem to stdout
+      and its seed to stderr. The size defaults to the largest the problem
+      allows and the shape is one of random, uniform or worst.
+  hackerank bench <slug> [--size 1000] [--steps 5] [--runs 9] [--shape random]
+                 [--seed 0] [--threshold 25] [--save]
+      Times every solution of the problem on generated inputs of doubling
+      size up to --size and prints the median and 90th percentile of the
+      runs. The medians are compared with baselines/<slug>.txt, failing when
+      one is slower by more than the threshold percent; the baseline is
+      written when missing or when --save is given.
+  hackerank complexity [slug] [--size <max>] [--steps 6] [--seeds 5]
+                       [--shape random] [--seed 0]
+      Times the optimized solution of one problem, or of all of them, over
+      doubling sizes and fits the timings to the usual complexity classes,
+      failing when one grows faster than the complexity it declares.
+  hackerank reading [slug] [--size <max>] [--runs 9] [--shape random]
+                    [--seed 0]
+      Times splitting every token of a generated input of one problem, or of
+      all of them, with the byte scanner, then parsing the whole input. The
+      size defaults to the largest the problem allows.
+  hackerank bundle <slug> [--output <file>]
+      Writes the problem and the crate code it uses as a single main.rs ready
+      to submit, to stdout unless a file is given, then checks that it
+      compiles on its own with rustc.
+  hackerank explain <slug> [input-file]
+      Solves the input, read from stdin unless a file is given, and prints
+      the steps the solution explains on the way to its answer.
+  hackerank list [--domain <domain>] [--difficulty <difficulty>] [--tag <tag>]
+                 [--sort slug] [--details]
+      Lists the problems with their title, HackerRank domain, difficulty and
+      tags, along with how many fixtures they have and whether these pass.
+      The options keep the problems of one domain, difficulty or tag, and
+      sort them by slug, title, domain, difficulty or fixtures. --details
+      adds the URL and the input constraints of each problem.
+
+exit codes:
+  1  a test failed           4  malformed input
+  2  bad usage               5  constraint violated
+  3  I/O error               6  invalid character
+                             7  layout mismatch";
 
 fn main() {
-    println!("Hello, world!");
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+
+    match args.as_slice() {
+        ["run", slug, path, flags @ ..] if !path.starts_with("--") => run(slug, Some(path), flags),
+        ["run", slug, flags @ ..] => run(slug, None, flags),
+        ["test", slug, flags @ ..] => test(slug, flags),
+        ["import", slug, source] => import(slug, source),
+        ["new", slug, flags @ ..] => new(slug, flags),
+        ["stress", slug, flags @ ..] => stress(slug, flags),
+        ["generate", slug, flags @ ..] => generate(slug, flags),
+        ["bench", slug, flags @ ..] => bench(slug, flags),
+        ["complexity", slug, flags @ ..] if !slug.starts_with("--") => {
+            complexity(Some(slug), flags)
+        }
+        ["complexity", flags @ ..] => complexity(None, flags),
+        ["reading", slug, flags @ ..] if !slug.starts_with("--") => reading(Some(slug), flags),
+        ["reading", flags @ ..] => reading(None, flags),
+        ["bundle", slug, flags @ ..] => bundle(slug, flags),
+        ["explain", slug] => explain(slug, None),
+        ["list", flags @ ..] => list(flags),
+        ["explain", slug, path] => explain(slug, Some(path)),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    }
+}
+
+/// Look up a registered problem or exit listing the known slugs
+fn find(slug: &str) -> &'static dyn Runnable {
+    let Some(problem) = algorithm::find(slug) else {
+        unknown(slug);
+    };
+
+    problem
+}
+
+/// Exit on a slug no problem is registered under, listing the known slugs
+fn unknown(slug: &str) -> ! {
+    eprintln!("unknown problem `{}`, available problems:", slug);
+
+    for problem in algorithm::problems() {
+        eprintln!("  {}", problem.slug());
+    }
+
+    process::exit(2);
+}
+
+/// Look up the generator of a registered problem, failing with a usage error
+/// for a problem without one
+fn find_generator(slug: &str) -> Result<&'static dyn Generator> {
+    find(slug);
+    algorithm::find_generator(slug).ok_or_else(|| {
+        HackerankError::unsupported(
+            slug,
+            "input generator, implement `Generate` for it and register it with `Registration::generator`",
+        )
+    })
+}
+
+/// Look up the timed solutions of a registered problem, failing with a usage
+/// error for a problem without them
+fn find_bench(slug: &str) -> Result<&'static dyn Bench> {
+    find(slug);
+    algorithm::find_bench(slug).ok_or_else(|| {
+        HackerankError::unsupported(
+            slug,
+            "timed solutions, implement `Solutions` for it and register it with `Registration::bench`",
+        )
+    })
+}
+
+/// Solve one input with a registered problem, just like the judge would,
+/// showing or saving its trace events
+fn run(slug: &str, path: Option<&str>, flags: &[&str]) {
+    let problem = find(slug);
+    let mut trace_file = None;
+
+    for pair in flags.chunks(2) {
+        match pair {
+            [name @ "--trace", value] => {
+                let filter = parse_flag::<trace::Filter>(name, value);
+
+                if let Some(slug) = filter.unknown(|slug| algorithm::find(slug).is_some()) {
+                    unknown(slug);
+                }
+
+                trace::set_filter(filter);
+            }
+            ["--trace-file", value] => trace_file = Some(*value),
+            _ => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+        }
+    }
+
+    let input = match path {
+        Some(path) => fs::read(path),
+        None => harness::read_stdin(),
+    };
+    let input = input.unwrap_or_else(|e| fail(e.into()));
+
+    let judged = harness::judge(problem, &input);
+
+    let _ = limits::report_peak_rss();
+
+    let events = trace::take()
+        .iter()
+        .map(|event| format!("{}\n", event))
+        .collect::<String>();
+
+    match trace_file {
+        Some(path) => fs::write(path, events).unwrap_or_else(|e| fail(e.into())),
+        None => eprint!("{}", events),
+    }
+
+    if let Err(e) = judged {
+        eprintln!("error: {}: {}", slug, e);
+
+        if let Some(excerpt) = e.excerpt(&input) {
+            eprintln!("{}", excerpt);
+        }
+
+        process::exit(e.exit_code());
+    }
+}
+
+/// Run all fixtures of a problem under the judge's limits and report every
+/// case
+fn test(slug: &str, flags: &[&str]) {
+    let problem = find(slug);
+    let in_process = flags.contains(&"--in-process");
+    let variants = flags.contains(&"--variants");
+    let flags = flags
+        .iter()
+        .copied()
+        .filter(|flag| !["--in-process", "--variants"].contains(flag))
+        .collect::<Vec<_>>();
+    let mut limits = Limits::default();
+    let mut checker = problem.checker();
+
+    for pair in flags.chunks(2) {
+        match pair {
+            [name @ "--checker", value] => checker = parse_flag(name, value),
+            [name @ "--time", value] => {
+                limits.time = Duration::from_secs_f64(parse_flag(name, value))
+            }
+            [name @ "--memory", value] => {
+                limits.memory = parse_flag::<u64>(name, value) * limits::MIB
+            }
+            _ => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+        }
+    }
+
+    if variants {
+        let bench = find_bench(slug).unwrap_or_else(|e| fail(e));
+        let report = fixture::run_variants(bench, checker).unwrap_or_else(|e| fail(e.into()));
+
+        println!("{}", report);
+
+        if !report.passed() {
+            process::exit(1);
+        }
+
+        return;
+    }
+
+    let problem = Rechecked { problem, checker };
+    let report = match in_process {
+        true => fixture::run(&problem),
+        false => fixture::run_limited(&problem, limits),
+    };
+    let report = report.unwrap_or_else(|e| fail(e.into()));
+
+    println!("{}", report);
+
+    if !report.passed() {
+        process::exit(1);
+    }
+}
+
+/// Copy downloaded HackerRank test cases into the fixtures of a problem
+fn import(slug: &str, source: &str) {
+    let problem = find(slug);
+    let written =
+        import::import(problem.slug(), Path::new(source)).unwrap_or_else(|e| fail(e.into()));
+
+    for path in &written {
+        println!("{}", path.display());
+    }
+
+    println!("{}: imported {} files", slug, written.len());
+}
+
+/// Scaffold a new problem module from the template
+fn new(slug: &str, flags: &[&str]) {
+    let mut domain = "algorithm";
+    let mut spec = scaffold::DEFAULT_SIGNATURE;
+
+    for pair in flags.chunks(2) {
+        match pair {
+            ["--domain", value] => domain = value,
+            ["--signature", value] => spec = value,
+            _ => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+        }
+    }
+
+    let created = scaffold::Signature::parse(spec)
+        .and_then(|signature| scaffold::create(slug, domain, &signature))
+        .unwrap_or_else(|e| fail(e.into()));
+
+    for path in created {
+        println!("{}", path.display());
+    }
+}
+
+/// Compare the optimized and reference solutions on generated inputs
+fn stress(slug: &str, flags: &[&str]) {
+    let Some(test) = algorithm::find_stress(slug) else {
+        eprintln!(
+            "no reference solution for `{}`, stress tests exist for:",
+            slug
+        );
+
+        for test in algorithm::stress_tests() {
+            eprintln!("  {}", test.slug());
+        }
+
+        process::exit(2);
+    };
+    let mut options = stress::Options {
+        seed: random_seed(),
+        ..stress::Options::default()
+    };
+
+    for pair in flags.chunks(2) {
+        match pair {
+            [name @ "--seed", value] => options.seed = parse_flag(name, value),
+            [name @ "--cases", value] => options.cases = parse_flag(name, value),
+            [name @ "--size", value] => options.size = parse_flag(name, value),
+            _ => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+        }
+    }
+
+    println!(
+        "{}: {} cases from seed {} up to size {}",
+        slug, options.cases, options.seed, options.size
+    );
+
+    match stress::run(test, options).unwrap_or_else(|e| fail(e)) {
+        Some(case) => {
+            println!("MISMATCH at seed {} (size {})", case.seed, case.size);
+            println!("{}", case);
+            println!(
+                "replay: hackerank stress {} --seed {} --cases 1 --size {}",
+                slug, case.seed, options.size
+            );
+
+            let shrunk = test.shrink(&case).unwrap_or_else(|e| fail(e));
+
+            if shrunk.input != case.input {
+                println!("shrunk to:\n{}", shrunk);
+            }
+
+            process::exit(1);
+        }
+        None => println!("{}: all {} cases agree", slug, options.cases),
+    }
+}
+
+/// Write a random input of a problem, its seed going to stderr
+fn generate(slug: &str, flags: &[&str]) {
+    let generator = find_generator(slug).unwrap_or_else(|e| fail(e));
+    let mut seed = random_seed();
+    let mut size = generator.max_size();
+    let mut shape = Shape::Random;
+
+    for pair in flags.chunks(2) {
+        match pair {
+            [name @ "--seed", value] => seed = parse_flag(name, value),
+            [name @ "--size", value] => size = parse_flag(name, value),
+            [name @ "--shape", value] => shape = parse_flag(name, value),
+            _ => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+        }
+    }
+
+    let text = generator
+        .sample(seed, size, shape)
+        .unwrap_or_else(|e| fail(e));
+
+    eprintln!("{}: seed {}", slug, seed);
+    print!("{}", text);
+}
+
+/// Time the solutions of a problem and check them against the baseline
+fn bench(slug: &str, flags: &[&str]) {
+    let bench = find_bench(slug).unwrap_or_else(|e| fail(e));
+    let save = flags.contains(&"--save");
+    let flags = flags
+        .iter()
+        .copied()
+        .filter(|flag| *flag != "--save")
+        .collect::<Vec<_>>();
+    let mut options = bench::Options::default();
+    let mut threshold: f64 = 25.0;
+
+    for pair in flags.chunks(2) {
+        match pair {
+            [name @ "--seed", value] => options.seed = parse_flag(name, value),
+            [name @ "--size", value] => options.size = parse_flag(name, value),
+            [name @ "--steps", value] => options.steps = parse_flag(name, value),
+            [name @ "--runs", value] => options.runs = parse_flag(name, value),
+            [name @ "--shape", value] => options.shape = parse_flag(name, value),
+            [name @ "--threshold", value] => threshold = parse_flag(name, value),
+            _ => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+        }
+    }
+
+    let samples = bench.measure(options).unwrap_or_else(|e| fail(e));
+
+    println!("{}", bench::Table(&samples));
+
+    let baseline = bench::load_baseline(slug).unwrap_or_else(|e| fail(e));
+
+    match baseline {
+        Some(baseline) if !save => {
+            let regressions = bench::regressions(&samples, &baseline, threshold);
+
+            if regressions.is_empty() {
+                println!(
+                    "{}: no regression past {}% of the baseline",
+                    slug, threshold
+                );
+                return;
+            }
+
+            for regression in &regressions {
+                println!("REGRESSION {}", regression);
+            }
+
+            process::exit(1);
+        }
+        _ => {
+            let path = bench::save_baseline(slug, &samples).unwrap_or_else(|e| fail(e.into()));
+
+            println!("{}: baseline saved to {}", slug, path.display());
+        }
+    }
+}
+
+/// Check that solutions grow no faster than their declared complexity
+fn complexity(slug: Option<&str>, flags: &[&str]) {
+    let benches = match slug {
+        Some(slug) => vec![find_bench(slug).unwrap_or_else(|e| fail(e))],
+        None => algorithm::benches().collect(),
+    };
+    let mut options = complexity::Options::default();
+
+    for pair in flags.chunks(2) {
+        match pair {
+            [name @ "--seed", value] => options.seed = parse_flag(name, value),
+            [name @ "--size", value] => options.size = Some(parse_flag(name, value)),
+            [name @ "--steps", value] => options.steps = parse_flag(name, value),
+            [name @ "--seeds", value] => options.seeds = parse_flag(name, value),
+            [name @ "--shape", value] => options.shape = parse_flag(name, value),
+            _ => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+        }
+    }
+
+    let mut too_slow = 0;
+
+    for bench in benches {
+        let estimate = complexity::estimate(bench, options).unwrap_or_else(|e| fail(e));
+
+        if slug.is_some() {
+            println!("{}", estimate);
+        } else {
+            println!(
+                "{}: declared {}, fits {}, measured exponent {:.2}",
+                bench.slug(),
+                estimate.declared,
+                estimate.fit,
+                estimate.exponent
+            );
+        }
+
+        if estimate.too_slow() {
+            println!(
+                "TOO SLOW {}: grows faster than {}",
+                bench.slug(),
+                estimate.declared
+            );
+            too_slow += 1;
+        }
+    }
+
+    if too_slow > 0 {
+        process::exit(1);
+    }
+}
+
+/// Time tokenizing and parsing the largest generated inputs
+fn reading(slug: Option<&str>, flags: &[&str]) {
+    let benches = match slug {
+        Some(slug) => vec![find_bench(slug).unwrap_or_else(|e| fail(e))],
+        None => algorithm::benches().collect(),
+    };
+    let mut options = bench::Options {
+        size: usize::MAX,
+        ..bench::Options::default()
+    };
+
+    for pair in flags.chunks(2) {
+        match pair {
+            [name @ "--seed", value] => options.seed = parse_flag(name, value),
+            [name @ "--size", value] => options.size = parse_flag(name, value),
+            [name @ "--runs", value] => options.runs = parse_flag(name, value),
+            [name @ "--shape", value] => options.shape = parse_flag(name, value),
+            _ => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+        }
+    }
+
+    let readings = benches
+        .iter()
+        .map(|bench| bench.reading(options).unwrap_or_else(|e| fail(e)))
+        .collect::<Vec<_>>();
+
+    println!("{}", bench::ReadingTable(&readings));
+}
+
+/// Write a problem as a single file submission and check that it compiles
+fn bundle(slug: &str, flags: &[&str]) {
+    let problem = find(slug);
+    let mut output = None;
+
+    for pair in flags.chunks(2) {
+        match pair {
+            ["--output", value] => output = Some(*value),
+            _ => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+        }
+    }
+
+    let bundle = bundle::bundle(problem.slug()).unwrap_or_else(|e| fail(e.into()));
+
+    match output {
+        Some(path) => fs::write(path, &bundle).unwrap_or_else(|e| fail(e.into())),
+        None => print!("{}", bundle),
+    }
+
+    let binary = env::temp_dir().join(format!("hackerank-bundle-{}-{}", process::id(), slug));
+    let compiled = bundle::compile(&bundle, &binary);
+
+    let _ = fs::remove_file(&binary);
+
+    match compiled {
+        Ok(()) => eprintln!(
+            "{}: {} lines, compiles on its own",
+            slug,
+            bundle.lines().count()
+        ),
+        Err(e) if e.kind() == io::ErrorKind::InvalidData => {
+            eprintln!("error: {}: bundle does not compile\n{}", slug, e);
+            process::exit(1);
+        }
+        Err(e) => fail(e.into()),
+    }
+}
+
+/// Print how a problem reaches the answer to one input
+fn explain(slug: &str, path: Option<&str>) {
+    let problem = find(slug);
+
+    let input = match path {
+        Some(path) => fs::read(path),
+        None => harness::read_stdin(),
+    };
+    let input = input.unwrap_or_else(|e| fail(e.into()));
+
+    match explain::run(problem, &input) {
+        Ok(explanation) => println!("{}", explanation),
+        Err(e) => {
+            eprintln!("error: {}: {}", slug, e);
+
+            if let Some(excerpt) = e.excerpt(&input) {
+                eprintln!("{}", excerpt);
+            }
+
+            process::exit(e.exit_code());
+        }
+    }
+}
+
+/// Print the catalog of problems along with the state of their fixtures
+fn list(flags: &[&str]) {
+    let details = flags.contains(&"--details");
+    let flags = flags
+        .iter()
+        .copied()
+        .filter(|flag| *flag != "--details")
+        .collect::<Vec<_>>();
+    let mut query = meta::Query::default();
+
+    for pair in flags.chunks(2) {
+        match pair {
+            ["--domain", value] => query.domain = Some(value.to_string()),
+            [name @ "--difficulty", value] => query.difficulty = Some(parse_flag(name, value)),
+            ["--tag", value] => query.tag = Some(value.to_string()),
+            [name @ "--sort", value] => query.sort = Some(parse_flag(name, value)),
+            _ => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+        }
+    }
+
+    let entries = algorithm::problems()
+        .filter(|problem| query.matches(&problem.meta()))
+        .map(|problem| {
+            let report = fixture::run(problem).unwrap_or_else(|e| fail(e.into()));
+
+            meta::Entry {
+                slug: problem.slug(),
+                meta: problem.meta(),
+                fixtures: report.results.len(),
+                passed: (!report.results.is_empty()).then(|| report.passed()),
+            }
+        })
+        .collect();
+
+    let entries = query.apply(entries);
+
+    println!(
+        "{}",
+        meta::Table {
+            entries: &entries,
+            details
+        }
+    );
+}
+
+/// Value of a command line flag, exiting on a malformed one
+fn parse_flag<T: FromStr>(name: &str, value: &str) -> T
+where
+    T::Err: fmt::Display,
+{
+    value.parse().unwrap_or_else(|e| {
+        eprintln!("error: {}: {}", name, e);
+        process::exit(2);
+    })
+}
+
+/// Seed taken from the clock, for runs that do not give one
+fn random_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_nanos() as u64)
+}
+
+/// Print an error and exit with its exit code
+fn fail(error: HackerankError) -> ! {
+    eprintln!("error: {}", error);
+    process::exit(error.exit_code());
 }
@@ -0,0 +1,451 @@
+//! Catalog of the HackerRank problems the crate solves.
+//!
+//! Every problem carries a [`Meta`] telling where it comes from, how hard it
+//! is, the techniques its solution uses and the limits the statement puts on
+//! its input. `hackerank list` shows the catalog, filtered and sorted by these
+//! fields, next to how many fixtures each problem has and whether they pass.
+//!
+//! The constraints are also what inputs are validated against before being
+//! solved, so an input out of the statement fails with the constraint it
+//! breaks instead of a panic or a wrong answer.
+
+use std::{fmt, str::FromStr};
+
+use crate::error::{HackerankError, Result};
+
+/// Difficulty HackerRank rates a problem with, from the easiest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+    Advanced,
+    Expert,
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Medium => "medium",
+            Difficulty::Hard => "hard",
+            Difficulty::Advanced => "advanced",
+            Difficulty::Expert => "expert",
+        })
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "easy" => Ok(Difficulty::Easy),
+            "medium" => Ok(Difficulty::Medium),
+            "hard" => Ok(Difficulty::Hard),
+            "advanced" => Ok(Difficulty::Advanced),
+            "expert" => Ok(Difficulty::Expert),
+            _ => Err(format!(
+                "unknown difficulty `{}`, expected easy, medium, hard, advanced or expert",
+                s
+            )),
+        }
+    }
+}
+
+/// Inclusive bounds the statement puts on a value of the input
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Constraint {
+    /// The value as the statement names it, `|s|` for the length of `s` and
+    /// `a[i]` for every element of `a`
+    pub name: &'static str,
+    pub min: i64,
+    pub max: i64,
+}
+
+impl Constraint {
+    pub const fn new(name: &'static str, min: i64, max: i64) -> Self {
+        Constraint { name, min, max }
+    }
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} <= {} <= {}",
+            power_of_ten(self.min),
+            self.name,
+            power_of_ten(self.max)
+        )
+    }
+}
+
+/// What a problem is about
+#[derive(Debug, Clone, Copy)]
+pub struct Meta {
+    pub title: &'static str,
+    pub url: &'static str,
+    /// HackerRank subdomain, such as `strings` or `implementation`
+    pub domain: &'static str,
+    pub difficulty: Difficulty,
+    /// Techniques the solution uses, such as `greedy` or `Aho-Corasick`
+    pub tags: &'static [&'static str],
+    pub constraints: &'static [Constraint],
+}
+
+impl Meta {
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|own| own.eq_ignore_ascii_case(tag))
+    }
+}
+
+/// Field the catalog is sorted by
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sort {
+    Slug,
+    Title,
+    Domain,
+    Difficulty,
+    Fixtures,
+}
+
+impl FromStr for Sort {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "slug" => Ok(Sort::Slug),
+            "title" => Ok(Sort::Title),
+            "domain" => Ok(Sort::Domain),
+            "difficulty" => Ok(Sort::Difficulty),
+            "fixtures" => Ok(Sort::Fixtures),
+            _ => Err(format!(
+                "unknown field `{}`, expected slug, title, domain, difficulty or fixtures",
+                s
+            )),
+        }
+    }
+}
+
+/// Problems to show and in which order
+#[derive(Debug, Clone, Default)]
+pub struct Query {
+    pub domain: Option<String>,
+    pub difficulty: Option<Difficulty>,
+    pub tag: Option<String>,
+    pub sort: Option<Sort>,
+}
+
+impl Query {
+    pub fn matches(&self, meta: &Meta) -> bool {
+        self.domain
+            .as_ref()
+            .is_none_or(|domain| meta.domain.eq_ignore_ascii_case(domain))
+            && self
+                .difficulty
+                .is_none_or(|difficulty| meta.difficulty == difficulty)
+            && self.tag.as_ref().is_none_or(|tag| meta.has_tag(tag))
+    }
+
+    /// The entries matching the query, in its order, the slug breaking ties
+    pub fn apply(&self, entries: Vec<Entry>) -> Vec<Entry> {
+        let mut entries = entries
+            .into_iter()
+            .filter(|entry| self.matches(&entry.meta))
+            .collect::<Vec<_>>();
+
+        entries.sort_by_key(|entry| entry.slug);
+
+        match self.sort.unwrap_or(Sort::Slug) {
+            Sort::Slug => {}
+            Sort::Title => entries.sort_by_key(|entry| entry.meta.title),
+            Sort::Domain => entries.sort_by_key(|entry| entry.meta.domain),
+            Sort::Difficulty => entries.sort_by_key(|entry| entry.meta.difficulty),
+            Sort::Fixtures => entries.sort_by_key(|entry| entry.fixtures),
+        }
+
+        entries
+    }
+}
+
+/// A problem of the catalog along with the state of its fixtures
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub slug: &'static str,
+    pub meta: Meta,
+    pub fixtures: usize,
+    /// Whether every fixture passes, `None` when there are none
+    pub passed: Option<bool>,
+}
+
+/// Entries printed as a table, one row per problem
+pub struct Table<'a> {
+    pub entries: &'a [Entry],
+    /// Show the URL and the constraints of each problem under its row
+    pub details: bool,
+}
+
+impl fmt::Display for Table<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:<30} {:<34} {:<15} {:<9} {:>8} {:<6} tags",
+            "slug", "title", "domain", "level", "fixtures", "status"
+        )?;
+
+        for entry in self.entries {
+            let status = match entry.passed {
+                Some(true) => "pass",
+                Some(false) => "FAIL",
+                None => "-",
+            };
+
+            write!(
+                f,
+                "\n{:<30} {:<34} {:<15} {:<9} {:>8} {:<6} {}",
+                entry.slug,
+                entry.meta.title,
+                entry.meta.domain,
+                entry.meta.difficulty,
+                entry.fixtures,
+                status,
+                entry.meta.tags.join(", ")
+            )?;
+
+            if self.details {
+                let constraints = entry
+                    .meta
+                    .constraints
+                    .iter()
+                    .map(Constraint::to_string)
+                    .collect::<Vec<_>>();
+
+                write!(f, "\n    {}", entry.meta.url)?;
+                write!(f, "\n    {}", constraints.join(", "))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Fail on the first value outside the bounds of its constraint
+pub fn validate(constraints: &[Constraint], values: &[(&'static str, i64)]) -> Result<()> {
+    for &(name, value) in values {
+        let Some(constraint) = constraints
+            .iter()
+            .find(|constraint| constraint.name == name)
+        else {
+            panic!("no constraint on `{}` is declared", name);
+        };
+
+        if !(constraint.min..=constraint.max).contains(&value) {
+            return Err(HackerankError::constraint(
+                constraint.name,
+                format!("found {}, expected {}", value, constraint),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Fail on the first character of `s` that is not `expected`
+pub fn chars(s: &str, allowed: fn(char) -> bool, expected: &'static str) -> Result<()> {
+    match s.chars().enumerate().find(|(_, c)| !allowed(*c)) {
+        Some((position, found)) => Err(HackerankError::InvalidChar {
+            found,
+            position,
+            expected,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Fail on the first character of `s` that is not a lowercase ASCII letter
+pub fn lowercase(s: &str) -> Result<()> {
+    chars(s, |c| c.is_ascii_lowercase(), "a lowercase letter")
+}
+
+/// `value` with its trailing zeros as a power of ten, as statements write
+/// bounds: `10^5` for 100000 and `2*10^6` for 2000000
+fn power_of_ten(value: i64) -> String {
+    let mut mantissa = value;
+    let mut exponent = 0;
+
+    while mantissa != 0 && mantissa % 10 == 0 {
+        mantissa /= 10;
+        exponent += 1;
+    }
+
+    match (exponent, mantissa) {
+        (0..=2, _) => value.to_string(),
+        (_, 1) => format!("10^{}", exponent),
+        _ => format!("{}*10^{}", mantissa, exponent),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm;
+
+    const META: Meta = Meta {
+        title: "Two Strings",
+        url: "https://www.hackerrank.com/challenges/two-strings/problem",
+        domain: "strings",
+        difficulty: Difficulty::Easy,
+        tags: &["strings", "sets"],
+        constraints: &[],
+    };
+
+    fn entry(slug: &'static str, difficulty: Difficulty, fixtures: usize) -> Entry {
+        Entry {
+            slug,
+            meta: Meta { difficulty, ..META },
+            fixtures,
+            passed: None,
+        }
+    }
+
+    #[test]
+    fn catalog_01() {
+        for registration in algorithm::PROBLEMS {
+            let problem = registration.problem;
+            let meta = problem.meta();
+
+            assert!(!meta.title.is_empty(), "{} has no title", problem.slug());
+            assert!(
+                meta.url
+                    .starts_with("https://www.hackerrank.com/challenges/"),
+                "{} has no HackerRank URL",
+                problem.slug()
+            );
+            // Scaffolded problems leave the domain and tags to be filled in
+            if !registration.is_scaffold() {
+                assert!(!meta.domain.is_empty(), "{} has no domain", problem.slug());
+                assert!(!meta.tags.is_empty(), "{} has no tags", problem.slug());
+            }
+            assert!(
+                meta.constraints
+                    .iter()
+                    .all(|constraint| constraint.min <= constraint.max),
+                "{} has empty bounds",
+                problem.slug()
+            );
+        }
+    }
+
+    #[test]
+    fn constraint_01() {
+        assert_eq!(Constraint::new("n", 1, 100).to_string(), "1 <= n <= 100");
+        assert_eq!(
+            Constraint::new("|s|", 1, 100_000).to_string(),
+            "1 <= |s| <= 10^5"
+        );
+        assert_eq!(
+            Constraint::new("total length", 1, 2_000_000).to_string(),
+            "1 <= total length <= 2*10^6"
+        );
+        assert_eq!(Constraint::new("x", 0, 1005).to_string(), "0 <= x <= 1005");
+    }
+
+    #[test]
+    fn validate_01() {
+        let constraints = [Constraint::new("n", 1, 10), Constraint::new("|s|", 1, 5)];
+
+        assert!(validate(&constraints, &[("n", 10), ("|s|", 1), ("|s|", 5)]).is_ok());
+
+        let error = validate(&constraints, &[("n", 3), ("|s|", 6)]).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "constraint on `|s|` violated: found 6, expected 1 <= |s| <= 5"
+        );
+        assert_eq!(error.exit_code(), 5);
+        assert!(lowercase("abc").is_ok());
+        assert_eq!(
+            lowercase("abC").unwrap_err().to_string(),
+            "invalid character 'C' at position 2, expected a lowercase letter"
+        );
+    }
+
+    #[test]
+    fn validate_02() {
+        // Out of spec inputs fail before reaching the solutions
+        for (slug, input, error) in [
+            (
+                "dna_health",
+                "1\nab\n1\n1\n0 3 abc\n",
+                "constraint on `last` violated: strand 0 covers genes 0 to 3, \
+                 expected first <= last < n = 1",
+            ),
+            (
+                "string_construction",
+                "1\naBc\n",
+                "invalid character 'B' at position 1, expected a lowercase letter",
+            ),
+            (
+                "matrix_rotation",
+                "2 2 0\n1 2\n3 4\n",
+                "constraint on `r` violated: found 0, expected 1 <= r <= 10^9",
+            ),
+        ] {
+            let problem = algorithm::find(slug).unwrap();
+
+            assert_eq!(
+                problem.run(input.as_bytes()).unwrap_err().to_string(),
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn query_01() {
+        let entries = vec![
+            entry("c", Difficulty::Easy, 1),
+            entry("a", Difficulty::Hard, 3),
+            entry("b", Difficulty::Medium, 2),
+        ];
+        let slugs = |query: Query| {
+            query
+                .apply(entries.clone())
+                .iter()
+                .map(|entry| entry.slug)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(slugs(Query::default()), vec!["a", "b", "c"]);
+        assert_eq!(
+            slugs(Query {
+                sort: Some(Sort::Difficulty),
+                ..Query::default()
+            }),
+            vec!["c", "b", "a"]
+        );
+        assert_eq!(
+            slugs(Query {
+                difficulty: Some(Difficulty::Medium),
+                ..Query::default()
+            }),
+            vec!["b"]
+        );
+        assert_eq!(
+            slugs(Query {
+                tag: Some("SETS".to_string()),
+                domain: Some("strings".to_string()),
+                sort: Some(Sort::Fixtures),
+                ..Query::default()
+            }),
+            vec!["c", "b", "a"]
+        );
+        assert_eq!(
+            slugs(Query {
+                tag: Some("greedy".to_string()),
+                ..Query::default()
+            }),
+            Vec::<&str>::new()
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{
+    answer::FormatAnswer,
+    checker::Checker,
+    error::Result,
+    meta::{self, Meta},
+};
+
+/// A HackerRank problem that can be driven from the command line.
+///
+/// Each module in `algorithm` implements this for a unit struct and adds it to
+/// `algorithm::PROBLEMS`, which is what `hackerank run <slug>` looks up.
+pub trait Problem {
+    /// Parsed form of the judge input
+    type Input;
+    /// Answer produced by the solution, which the runner writes in the
+    /// format the judge expects
+    type Output: FormatAnswer;
+
+    /// Name used on the command line, same as the module name
+    const SLUG: &'static str;
+
+    /// Where the problem comes from, how hard it is and what its statement
+    /// allows in the input
+    const META: Meta;
+
+    /// How answers are compared with the expected output
+    const CHECKER: Checker = Checker::Exact;
+
+    /// Parse the raw input text exactly as HackerRank provides it, usually
+    /// with a [`Scanner`](crate::scanner::Scanner)
+    fn parse(input: &[u8]) -> Result<Self::Input>;
+
+    /// Values of a parsed input that the constraints of [`Problem::META`]
+    /// bound, one pair per value under the name of its constraint
+    fn values(_input: &Self::Input) -> Vec<(&'static str, i64)> {
+        Vec::new()
+    }
+
+    /// Check what bounds cannot express, such as the alphabet of a string or
+    /// the shape of a grid
+    fn check(_input: &Self::Input) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check a parsed input against the statement, naming the first
+    /// constraint it violates
+    fn validate(input: &Self::Input) -> Result<()> {
+        meta::validate(Self::META.constraints, &Self::values(input))?;
+        Self::check(input)
+    }
+
+    /// Compute the answer for a parsed input. Fails on inputs outside of what
+    /// the problem statement allows rather than panicking.
+    fn solve(input: Self::Input) -> Result<Self::Output>;
+
+    /// Parse, validate, solve and format in one go
+    fn run(input: &[u8]) -> Result<String> {
+        let input = Self::parse(input)?;
+
+        Self::validate(&input)?;
+
+        Ok(Self::solve(input)?.format_answer())
+    }
+}
+
+/// Object safe view of a [`Problem`] so problems with different input and
+/// output types can live in the same registry
+pub trait Runnable: Sync {
+    fn slug(&self) -> &'static str;
+
+    fn checker(&self) -> Checker;
+
+    fn meta(&self) -> Meta;
+
+    /// Parse, validate, solve and format in one go
+    fn run(&self, input: &[u8]) -> Result<String>;
+}
+
+impl<P: Problem + Sync> Runnable for P {
+    fn slug(&self) -> &'static str {
+        P::SLUG
+    }
+
+    fn checker(&self) -> Checker {
+        P::CHECKER
+    }
+
+    fn meta(&self) -> Meta {
+        P::META
+    }
+
+    fn run(&self, input: &[u8]) -> Result<String> {
+        <P as Problem>::run(input)
+    }
+}
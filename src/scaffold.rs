@@ -0,0 +1,494 @@
+//! Scaffolding of new problem modules from `src/template.rs`.
+//!
+//! `hackerank new <slug>` renders the template into `src/<domain>/<slug>.rs`,
+//! declares and registers the module in `src/<domain>/mod.rs` and creates an
+//! empty sample fixture to fill in. The tree keeps passing its tests: the
+//! problem is registered without tooling, which the tests asking for a
+//! generator, timed solutions, fixtures or a domain and tags skip, and its own
+//! fixture test is ignored until the sample and the solution are filled in.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::fixture;
+
+const TEMPLATE: &str = include_str!("template.rs");
+
+/// Signature used when none is given, same as the original template
+pub const DEFAULT_SIGNATURE: &str = "s1: &str, s2: &str -> String";
+
+/// Parameter of the generated `solution`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Param {
+    pub name: String,
+    pub ty: String,
+}
+
+/// Parsed form of a spec like `s: &str, n: i32, k: i32 -> String`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Signature {
+    pub params: Vec<Param>,
+    pub output: String,
+}
+
+impl Signature {
+    pub fn parse(spec: &str) -> io::Result<Signature> {
+        let (params, output) = spec
+            .split_once("->")
+            .ok_or_else(|| invalid(format!("missing `-> <type>` in signature `{}`", spec)))?;
+
+        let params = split_top_level(params)
+            .into_iter()
+            .map(|param| {
+                let (name, ty) = param
+                    .split_once(':')
+                    .ok_or_else(|| invalid(format!("expected `name: type`, got `{}`", param)))?;
+                let param = Param {
+                    name: name.trim().to_string(),
+                    ty: ty.trim().to_string(),
+                };
+
+                match is_identifier(&param.name) {
+                    true => Ok(param),
+                    _ => Err(invalid(format!("invalid parameter name `{}`", param.name))),
+                }
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        if params.is_empty() {
+            return Err(invalid(
+                "signature needs at least one parameter".to_string(),
+            ));
+        }
+
+        Ok(Signature {
+            params,
+            output: output.trim().to_string(),
+        })
+    }
+}
+
+/// Render the template for a problem. Fails on parameter types the generated
+/// parser does not know how to handle, or output types without a judge format.
+pub fn render(slug: &str, signature: &Signature) -> io::Result<String> {
+    check_output(&signature.output)?;
+
+    let mut parse = Vec::new();
+
+    for (index, param) in signature.params.iter().enumerate() {
+        parse.push(format!(
+            "        let {} = {}?;",
+            param.name,
+            parse_expression(&signature.params[..index], param)?
+        ));
+    }
+
+    let names = signature
+        .params
+        .iter()
+        .map(|param| param.name.clone())
+        .collect::<Vec<_>>();
+    let owned = signature
+        .params
+        .iter()
+        .map(|param| owned_type(&param.ty))
+        .collect::<Vec<_>>();
+    let args = signature
+        .params
+        .iter()
+        .map(|param| match param.ty.starts_with('&') {
+            true => format!("&{}", param.name),
+            _ => param.name.clone(),
+        })
+        .collect::<Vec<_>>();
+    let params = signature
+        .params
+        .iter()
+        .map(|param| format!("{}: {}", param.name, param.ty))
+        .collect::<Vec<_>>();
+
+    // A single parameter is returned straight from the parser, several are
+    // read into locals and returned as a tuple
+    let (input_type, input_value, parse, input_expr) = match names.len() {
+        1 => (
+            owned[0].clone(),
+            names[0].clone(),
+            String::new(),
+            format!("Ok({}?)", parse_expression(&[], &signature.params[0])?),
+        ),
+        _ => (
+            format!("({})", owned.join(", ")),
+            format!("({})", names.join(", ")),
+            parse.join("\n") + "\n\n",
+            format!("Ok(({}))", names.join(", ")),
+        ),
+    };
+
+    Ok(TEMPLATE
+        .replace("\r\n", "\n")
+        .replace("{{params}}", &params.join(", "))
+        .replace("{{output}}", &signature.output)
+        .replace("{{name}}", &struct_name(slug))
+        .replace("{{slug}}", slug)
+        .replace("{{title}}", &title(slug))
+        .replace("{{challenge}}", &slug.replace('_', "-"))
+        .replace("{{input_type}}", &input_type)
+        .replace("{{input_value}}", &input_value)
+        .replace("{{parse}}", &parse)
+        .replace("{{input_expr}}", &input_expr)
+        .replace("{{args}}", &args.join(", ")))
+}
+
+/// Create the module, register it and add an empty sample fixture.
+/// Returns the files created or changed.
+pub fn create(slug: &str, domain: &str, signature: &Signature) -> io::Result<Vec<PathBuf>> {
+    if !is_identifier(slug) || slug.chars().any(|c| c.is_ascii_uppercase()) {
+        return Err(invalid(format!(
+            "`{}` is not a snake_case module name",
+            slug
+        )));
+    }
+
+    let domain_dir = Path::new("src").join(domain);
+    let mod_rs = domain_dir.join("mod.rs");
+    let module = domain_dir.join(format!("{}.rs", slug));
+
+    if !mod_rs.is_file() {
+        return Err(invalid(format!("unknown domain `{}`", domain)));
+    }
+
+    if module.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", module.display()),
+        ));
+    }
+
+    let source = render(slug, signature)?;
+    let registry = register(&fs::read_to_string(&mod_rs)?, slug)?;
+    let fixtures = fixture::dir(slug);
+
+    fs::write(&module, source)?;
+    fs::write(&mod_rs, registry)?;
+    fs::create_dir_all(&fixtures)?;
+
+    let mut created = vec![module, mod_rs];
+
+    for name in ["input00.txt", "output00.txt"] {
+        let path = fixtures.join(name);
+
+        if !path.exists() {
+            fs::write(&path, "")?;
+        }
+
+        created.push(path);
+    }
+
+    Ok(created)
+}
+
+/// Add `pub mod <slug>;` and a registration without tooling to the contents
+/// of a domain `mod.rs`, keeping both lists sorted
+pub fn register(mod_rs: &str, slug: &str) -> io::Result<String> {
+    let declaration = format!("pub mod {};", slug);
+    let entry = format!(
+        "    Registration::problem(&{}::{}),",
+        slug,
+        struct_name(slug)
+    );
+    let mut lines = mod_rs.lines().map(str::to_string).collect::<Vec<_>>();
+
+    if lines.contains(&declaration) {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("module `{}` is already declared", slug),
+        ));
+    }
+
+    let declarations = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with("pub mod "))
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    let at = declarations
+        .iter()
+        .copied()
+        .find(|&index| lines[index].as_str() > declaration.as_str())
+        .or(declarations.last().map(|index| index + 1))
+        .ok_or_else(|| invalid("no `pub mod` declarations found".to_string()))?;
+
+    lines.insert(at, declaration);
+
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub static PROBLEMS"))
+        .ok_or_else(|| invalid("no `PROBLEMS` registry found".to_string()))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line == "];")
+            .ok_or_else(|| invalid("unterminated `PROBLEMS` registry".to_string()))?;
+    // Entries are sorted by module whatever tooling they register
+    let module = |line: &str| line.split_once("(&").map(|(_, path)| path.to_string());
+    let at = (start + 1..end)
+        .find(|&index| module(&lines[index]) > module(&entry))
+        .unwrap_or(end);
+
+    lines.insert(at, entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// `new_year_chaos` -> `NewYearChaos`
+fn struct_name(slug: &str) -> String {
+    title(slug).replace(' ', "")
+}
+
+/// `new_year_chaos` -> `New Year Chaos`
+fn title(slug: &str) -> String {
+    slug.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Type stored in `Problem::Input` for a parameter type
+fn owned_type(ty: &str) -> String {
+    match ty {
+        "&str" => "String".to_string(),
+        _ => match ty.strip_prefix("&[").and_then(|ty| ty.strip_suffix(']')) {
+            Some(element) => format!("Vec<{}>", element),
+            None => ty.to_string(),
+        },
+    }
+}
+
+/// Scanner call reading a parameter. Vectors take their length from the
+/// integer parameter right before them, matrices their rows and columns from
+/// the two parameters before them.
+fn parse_expression(previous: &[Param], param: &Param) -> io::Result<String> {
+    let owned = owned_type(&param.ty);
+
+    if owned == "String" || is_scalar(&owned) {
+        return Ok(format!("scanner.next::<{}>()", owned));
+    }
+
+    let element = owned
+        .strip_prefix("Vec<")
+        .and_then(|ty| ty.strip_suffix('>'))
+        .ok_or_else(|| invalid(format!("unsupported parameter type `{}`", param.ty)))?;
+
+    if element == "String" || is_scalar(element) {
+        let lengths = lengths(previous, 1, param)?;
+
+        return Ok(format!("scanner.vec::<{}>({})", element, lengths[0]));
+    }
+
+    match element
+        .strip_prefix("Vec<")
+        .and_then(|ty| ty.strip_suffix('>'))
+    {
+        Some(cell) if cell == "String" || is_scalar(cell) => {
+            let lengths = lengths(previous, 2, param)?;
+
+            Ok(format!(
+                "scanner.matrix::<{}>({}, {})",
+                cell, lengths[0], lengths[1]
+            ))
+        }
+        _ => Err(invalid(format!(
+            "unsupported parameter type `{}`",
+            param.ty
+        ))),
+    }
+}
+
+/// The last `count` parameters before a vector, as `usize` expressions
+fn lengths(previous: &[Param], count: usize, param: &Param) -> io::Result<Vec<String>> {
+    let start = previous.len().checked_sub(count);
+    let lengths = start.map(|start| &previous[start..]).filter(|lengths| {
+        lengths
+            .iter()
+            .all(|length| is_scalar(&length.ty) && length.ty != "f64")
+    });
+
+    match lengths {
+        Some(lengths) => Ok(lengths
+            .iter()
+            .map(|length| match length.ty.as_str() {
+                "usize" => length.name.clone(),
+                _ => format!("{} as usize", length.name),
+            })
+            .collect()),
+        None => Err(invalid(format!(
+            "`{}` needs {} integer parameter(s) right before it giving its size",
+            param.name, count
+        ))),
+    }
+}
+
+/// Check that the judge format of an output type is known, that is the type
+/// implements [`FormatAnswer`](crate::answer::FormatAnswer)
+fn check_output(ty: &str) -> io::Result<()> {
+    let inner = ["Vec<", "Option<"]
+        .iter()
+        .find_map(|prefix| ty.strip_prefix(prefix)?.strip_suffix('>'));
+    let components = ty
+        .strip_prefix('(')
+        .and_then(|ty| ty.strip_suffix(')'))
+        .map(split_top_level);
+
+    match (inner, components) {
+        _ if ty == "String" || ty == "bool" || is_scalar(ty) => Ok(()),
+        (Some(inner), _) => check_output(inner),
+        (_, Some(components)) if (2..=3).contains(&components.len()) => {
+            components.iter().try_for_each(|ty| check_output(ty))
+        }
+        _ => Err(invalid(format!("unsupported output type `{}`", ty))),
+    }
+}
+
+fn is_scalar(ty: &str) -> bool {
+    matches!(
+        ty,
+        "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "usize" | "isize" | "f64"
+    )
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Split on commas that are not nested inside `<>` or `[]`
+fn split_top_level(list: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+
+    for c in list.chars() {
+        match c {
+            '<' | '[' | '(' => depth += 1,
+            '>' | ']' | ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+
+        current.push(c);
+    }
+
+    if !current.trim().is_empty() {
+        parts.push(current.trim().to_string());
+    }
+
+    parts
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signature_01() {
+        let signature = Signature::parse("s: &str, n: i32, k: i32 -> String").unwrap();
+
+        assert_eq!(signature.params.len(), 3);
+        assert_eq!(signature.params[0].ty, "&str");
+        assert_eq!(signature.params[2].name, "k");
+        assert_eq!(signature.output, "String");
+    }
+
+    #[test]
+    fn signature_02() {
+        let signature = Signature::parse("grid: Vec<Vec<i32>>, arr: &[i64] -> Vec<i64>").unwrap();
+
+        assert_eq!(signature.params[0].ty, "Vec<Vec<i32>>");
+        assert_eq!(signature.params[1].ty, "&[i64]");
+        assert!(Signature::parse("s: &str").is_err());
+        assert!(Signature::parse("-> i32").is_err());
+    }
+
+    #[test]
+    fn render_01() {
+        let signature = Signature::parse("n: i32, arr: &[i32] -> Vec<i32>").unwrap();
+        let source = render("new_year_chaos", &signature).unwrap();
+
+        assert!(source.contains("fn solution(n: i32, arr: &[i32]) -> Vec<i32> {"));
+        assert!(source.contains("pub struct NewYearChaos;"));
+        assert!(source.contains("type Input = (i32, Vec<i32>);"));
+        assert!(source.contains("const SLUG: &'static str = \"new_year_chaos\";"));
+        assert!(source.contains("title: \"New Year Chaos\","));
+        assert!(source.contains("challenges/new-year-chaos/problem"));
+        assert!(source.contains("let arr = scanner.vec::<i32>(n as usize)?;"));
+        assert!(source.contains("Ok((n, arr))"));
+        assert!(source.contains("solution(n, &arr)"));
+        assert!(!source.contains("{{"));
+    }
+
+    #[test]
+    fn render_02() {
+        let signature =
+            Signature::parse("rows: usize, cols: usize, grid: &[Vec<i64>] -> i64").unwrap();
+        let source = render("grid", &signature).unwrap();
+
+        assert!(source.contains("let grid = scanner.matrix::<i64>(rows, cols)?;"));
+
+        let signature = Signature::parse("s: &str -> String").unwrap();
+        let source = render("single", &signature).unwrap();
+
+        assert!(source.contains("        Ok(scanner.next::<String>()?)\n"));
+    }
+
+    #[test]
+    fn render_03() {
+        let signature = Signature::parse("arr: Vec<i32>, k: i32 -> i32").unwrap();
+
+        assert!(render("no_length", &signature).is_err());
+
+        let signature = Signature::parse("s: &str, grid: Vec<Vec<i32>> -> i32").unwrap();
+
+        assert!(render("no_dimensions", &signature).is_err());
+
+        let signature = Signature::parse("n: i32 -> HashMap<i32, i32>").unwrap();
+
+        assert!(render("no_format", &signature).is_err());
+
+        let signature = Signature::parse("n: i32 -> Vec<(i64, Option<String>)>").unwrap();
+
+        assert!(render("tuples", &signature).is_ok());
+    }
+
+    #[test]
+    fn register_01() {
+        let mod_rs = "pub mod anagram;\npub mod two_strings;\n\npub static PROBLEMS: &[Registration] = &[\n    Registration::bench(&anagram::Anagram),\n    Registration::stress(&two_strings::TwoStrings),\n];\n";
+        let registered = register(mod_rs, "new_year_chaos").unwrap();
+
+        assert_eq!(
+            registered,
+            "pub mod anagram;\npub mod new_year_chaos;\npub mod two_strings;\n\npub static PROBLEMS: &[Registration] = &[\n    Registration::bench(&anagram::Anagram),\n    Registration::problem(&new_year_chaos::NewYearChaos),\n    Registration::stress(&two_strings::TwoStrings),\n];\n"
+        );
+        assert!(register(&registered, "new_year_chaos").is_err());
+    }
+}
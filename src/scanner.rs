@@ -0,0 +1,332 @@
+//! Typed reader for HackerRank style inputs.
+//!
+//! Inputs are whitespace separated tokens spread over lines. The scanner reads
+//! them one by one across line breaks and reports the line and token position
+//! of anything that is missing or does not parse. It works on the bytes of
+//! the input as read from stdin, splitting on ASCII whitespace, and hands out
+//! tokens as slices of the input so only the values parsed out of them are
+//! allocated. The input is never copied nor checked as a whole to be UTF-8,
+//! only the tokens and lines handed out are.
+
+use std::{any::type_name, fmt, io, str, str::FromStr};
+
+/// What went wrong while scanning
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScanErrorKind {
+    /// The input ended before the expected value
+    UnexpectedEnd { expected: &'static str },
+    /// A token was found but does not parse as the expected type
+    Invalid {
+        found: String,
+        expected: &'static str,
+    },
+}
+
+/// Scanning error with the 1-based line and token position it happened at
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanError {
+    pub line: usize,
+    pub token: usize,
+    pub kind: ScanErrorKind,
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ScanErrorKind::UnexpectedEnd { expected } => write!(
+                f,
+                "line {}, token {}: unexpected end of input, expected {}",
+                self.line, self.token, expected
+            ),
+            ScanErrorKind::Invalid { found, expected } => write!(
+                f,
+                "line {}, token {}: expected {}, found `{}`",
+                self.line, self.token, expected, found
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ScanError {}
+
+impl From<ScanError> for io::Error {
+    fn from(error: ScanError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, error)
+    }
+}
+
+pub struct Scanner<'a> {
+    input: &'a [u8],
+    /// Byte offset of the first unread byte
+    position: usize,
+    /// 1-based number of the current line, 0 before anything was read
+    line: usize,
+    /// Tokens already read on the current line
+    token: usize,
+}
+
+impl<'a> Scanner<'a> {
+    /// Scanner over the bytes of `input`, such as a `Vec<u8>` read from stdin
+    /// or a string
+    pub fn new(input: &'a (impl AsRef<[u8]> + ?Sized)) -> Self {
+        Scanner {
+            input: input.as_ref(),
+            position: 0,
+            line: 0,
+            token: 0,
+        }
+    }
+
+    /// Next token parsed as `T`, moving to the next line when needed
+    #[allow(clippy::should_implement_trait)]
+    pub fn next<T: FromStr>(&mut self) -> Result<T, ScanError> {
+        let token = self
+            .token_bytes()
+            .ok_or_else(|| self.error(Self::unexpected_end::<T>()))?;
+
+        self.text::<T>(token)?.parse::<T>().map_err(|_| {
+            self.error(ScanErrorKind::Invalid {
+                found: String::from_utf8_lossy(token).into_owned(),
+                expected: short_type_name::<T>(),
+            })
+        })
+    }
+
+    /// Next token as a slice of the input, without parsing or copying it
+    pub fn token(&mut self) -> Result<&'a str, ScanError> {
+        let token = self
+            .token_bytes()
+            .ok_or_else(|| self.error(Self::unexpected_end::<&str>()))?;
+
+        self.text::<&str>(token)
+    }
+
+    /// Next `n` tokens parsed as `T`, wherever the line breaks fall
+    pub fn vec<T: FromStr>(&mut self, n: usize) -> Result<Vec<T>, ScanError> {
+        (0..n).map(|_| self.next::<T>()).collect()
+    }
+
+    /// `rows` rows of `cols` tokens each
+    pub fn matrix<T: FromStr>(
+        &mut self,
+        rows: usize,
+        cols: usize,
+    ) -> Result<Vec<Vec<T>>, ScanError> {
+        (0..rows).map(|_| self.vec::<T>(cols)).collect()
+    }
+
+    /// Rest of the current line, or the next line when the current one has
+    /// been read entirely. Surrounding whitespace is trimmed.
+    pub fn line(&mut self) -> Result<&'a str, ScanError> {
+        let mut start = self.position;
+
+        if self.line == 0
+            || self.input[start..self.line_end(start)]
+                .trim_ascii()
+                .is_empty()
+        {
+            // A final line break does not start an empty line
+            if self.line > 0 {
+                start = self.line_end(start) + 1;
+            }
+
+            if start >= self.input.len() {
+                return Err(self.error(Self::unexpected_end::<&str>()));
+            }
+
+            self.line += 1;
+            self.token = 0;
+        }
+
+        let end = self.line_end(start);
+
+        self.position = end;
+        self.token += 1;
+
+        self.text::<&str>(self.input[start..end].trim_ascii())
+    }
+
+    /// `bytes` of the input as text, read for a `T`
+    fn text<T>(&self, bytes: &'a [u8]) -> Result<&'a str, ScanError> {
+        str::from_utf8(bytes).map_err(|_| {
+            self.error(ScanErrorKind::Invalid {
+                found: String::from_utf8_lossy(bytes).into_owned(),
+                expected: short_type_name::<T>(),
+            })
+        })
+    }
+
+    /// Offset of the line break ending the line `from` is on, or of the end
+    /// of the input
+    fn line_end(&self, from: usize) -> usize {
+        self.input[from..]
+            .iter()
+            .position(|&byte| byte == b'\n')
+            .map_or(self.input.len(), |offset| from + offset)
+    }
+
+    /// Skip whitespace and split the next token off the input. Lines are only
+    /// counted once a token is found, so an input ending with a line break
+    /// reports a missing value on its last line.
+    fn token_bytes(&mut self) -> Option<&'a [u8]> {
+        let bytes = self.input;
+        let mut start = self.position;
+        let mut line_breaks = 0;
+
+        while start < bytes.len() && bytes[start].is_ascii_whitespace() {
+            line_breaks += (bytes[start] == b'\n') as usize;
+            start += 1;
+        }
+
+        if start == bytes.len() {
+            return None;
+        }
+
+        let end = bytes[start..]
+            .iter()
+            .position(u8::is_ascii_whitespace)
+            .map_or(bytes.len(), |offset| start + offset);
+
+        if self.line == 0 || line_breaks > 0 {
+            self.line = self.line.max(1) + line_breaks;
+            self.token = 0;
+        }
+
+        self.position = end;
+        self.token += 1;
+
+        Some(&bytes[start..end])
+    }
+
+    fn unexpected_end<T>() -> ScanErrorKind {
+        ScanErrorKind::UnexpectedEnd {
+            expected: short_type_name::<T>(),
+        }
+    }
+
+    fn error(&self, kind: ScanErrorKind) -> ScanError {
+        let token = match kind {
+            ScanErrorKind::UnexpectedEnd { .. } => self.token + 1,
+            ScanErrorKind::Invalid { .. } => self.token,
+        };
+
+        ScanError {
+            line: self.line.max(1),
+            token,
+            kind,
+        }
+    }
+}
+
+/// `alloc::string::String` -> `String`
+fn short_type_name<T>() -> &'static str {
+    let name = type_name::<T>();
+
+    match name.find('<') {
+        Some(_) => name,
+        None => name.rsplit_once("::").map_or(name, |(_, name)| name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scanner_01() {
+        let mut scanner = Scanner::new("3 2\n1 2\n3\nhello world\n");
+
+        assert_eq!(scanner.next::<usize>(), Ok(3));
+        assert_eq!(scanner.next::<i64>(), Ok(2));
+        assert_eq!(scanner.vec::<i32>(3), Ok(vec![1, 2, 3]));
+        assert_eq!(scanner.line(), Ok("hello world"));
+    }
+
+    #[test]
+    fn scanner_02() {
+        let mut scanner = Scanner::new("2 3\n1 2 3\n4 5 6\n");
+        let rows = scanner.next::<usize>().unwrap();
+        let cols = scanner.next::<usize>().unwrap();
+
+        assert_eq!(
+            scanner.matrix::<i32>(rows, cols),
+            Ok(vec![vec![1, 2, 3], vec![4, 5, 6]])
+        );
+    }
+
+    #[test]
+    fn scanner_03() {
+        let mut scanner = Scanner::new("1 2\n3 x 5\n");
+
+        scanner.vec::<i32>(3).unwrap();
+
+        let error = scanner.next::<i32>().unwrap_err();
+
+        assert_eq!(
+            error,
+            ScanError {
+                line: 2,
+                token: 2,
+                kind: ScanErrorKind::Invalid {
+                    found: "x".to_string(),
+                    expected: "i32",
+                },
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 2, token 2: expected i32, found `x`"
+        );
+        assert_eq!(scanner.next::<String>(), Ok("5".to_string()));
+    }
+
+    #[test]
+    fn scanner_04() {
+        let mut scanner = Scanner::new("1 2\n");
+
+        let error = scanner.vec::<u64>(3).unwrap_err();
+
+        assert_eq!(error.line, 1);
+        assert_eq!(error.token, 3);
+        assert_eq!(
+            error.to_string(),
+            "line 1, token 3: unexpected end of input, expected u64"
+        );
+    }
+
+    #[test]
+    fn scanner_05() {
+        let mut scanner = Scanner::new("2\r\n\tab  cd\r\n\r\nlast line \r\n");
+
+        assert_eq!(scanner.next::<usize>(), Ok(2));
+        assert_eq!(scanner.token(), Ok("ab"));
+        assert_eq!(scanner.line(), Ok("cd"));
+        assert_eq!(scanner.line(), Ok(""));
+        assert_eq!(scanner.line(), Ok("last line"));
+
+        let error = scanner.token().unwrap_err();
+
+        assert_eq!((error.line, error.token), (4, 2));
+    }
+
+    #[test]
+    fn scanner_06() {
+        // Only the tokens read are checked to be UTF-8, not the whole input
+        let input = b"2 \xff\xfe\n\xc3\xa9t\xc3\xa9\n";
+        let mut scanner = Scanner::new(input);
+
+        assert_eq!(scanner.next::<u32>(), Ok(2));
+
+        let error = scanner.next::<String>().unwrap_err();
+
+        assert_eq!((error.line, error.token), (1, 2));
+        assert_eq!(
+            error.kind,
+            ScanErrorKind::Invalid {
+                found: "\u{fffd}\u{fffd}".to_string(),
+                expected: "String",
+            }
+        );
+        assert_eq!(scanner.line(), Ok("été"));
+    }
+}
@@ -0,0 +1,150 @@
+//! Delta debugging of failing inputs.
+//!
+//! A random input that makes two solutions disagree is usually far larger
+//! than needed to show the bug. Problems implementing [`Shrink`] list smaller
+//! variants of an input, and [`minimize`] keeps replacing the input by the
+//! first variant that still fails until none does. Each distinct variant is
+//! tried once, and the caller leaves out those breaking the constraints.
+
+use std::{collections::HashSet, hash::Hash, ops::Range};
+
+use crate::problem::Problem;
+
+/// Upper bound on the candidates tried by [`minimize`], so shrinking a huge
+/// input cannot run forever
+pub const MAX_ATTEMPTS: usize = 10_000;
+
+/// A problem whose inputs can be made smaller while staying valid
+pub trait Shrink: Problem {
+    /// Strictly smaller variants of `input`, the most aggressive first.
+    /// Candidates breaking the constraints of the problem are skipped, so
+    /// they need not be filtered out here.
+    fn shrink(input: &Self::Input) -> Vec<Self::Input>;
+}
+
+/// Ranges to cut out of a sequence of `len` items: the whole sequence, then
+/// halves, quarters and so on down to single items
+pub fn removal_ranges(len: usize) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut chunk = len;
+
+    while chunk > 0 {
+        ranges.extend(
+            (0..len)
+                .step_by(chunk)
+                .map(|start| start..len.min(start + chunk)),
+        );
+
+        if chunk == 1 {
+            break;
+        }
+
+        chunk = chunk.div_ceil(2);
+    }
+
+    ranges
+}
+
+/// `items` with each of the [`removal_ranges`] cut out, the empty result
+/// first. Problems needing at least one item filter it out.
+pub fn removals<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    removal_ranges(items.len())
+        .into_iter()
+        .map(|range| [&items[..range.start], &items[range.end..]].concat())
+        .collect()
+}
+
+/// Shrink `input` for as long as `fails` holds on a smaller candidate, trying
+/// at most [`MAX_ATTEMPTS`] candidates. A candidate already tried, whether
+/// from this input or an earlier one, is not tried again.
+pub fn minimize<T: Clone + Eq + Hash>(
+    input: T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    mut fails: impl FnMut(&T) -> bool,
+) -> T {
+    let mut tried = HashSet::from([input.clone()]);
+    let mut current = input;
+
+    'shrink: loop {
+        for candidate in shrink(&current) {
+            if tried.len() > MAX_ATTEMPTS {
+                break 'shrink;
+            }
+
+            if !tried.insert(candidate.clone()) {
+                continue;
+            }
+
+            if fails(&candidate) {
+                current = candidate;
+                continue 'shrink;
+            }
+        }
+
+        break;
+    }
+
+    current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removal_ranges_01() {
+        assert_eq!(
+            removal_ranges(4),
+            vec![0..4, 0..2, 2..4, 0..1, 1..2, 2..3, 3..4]
+        );
+        assert_eq!(removal_ranges(0), vec![]);
+    }
+
+    #[test]
+    fn removals_01() {
+        assert_eq!(
+            removals(&[1, 2, 3]),
+            vec![
+                vec![],
+                vec![3],
+                vec![1, 2],
+                vec![2, 3],
+                vec![1, 3],
+                vec![1, 2]
+            ]
+        );
+    }
+
+    #[test]
+    fn minimize_01() {
+        // Fails whenever both 3 and 7 are present
+        let input = (0..50).collect::<Vec<_>>();
+        let minimal = minimize(
+            input,
+            |items| removals(items),
+            |items| items.contains(&3) && items.contains(&7),
+        );
+
+        assert_eq!(minimal, vec![3, 7]);
+    }
+
+    #[test]
+    fn minimize_02() {
+        // Removals of a single item from 1, 2, 3 repeat the cut of 3
+        let mut tried = Vec::new();
+        let minimal = minimize(
+            vec![1, 2, 3],
+            |items| removals(items),
+            |items: &Vec<i32>| {
+                tried.push(items.clone());
+                items.len() == 3
+            },
+        );
+
+        assert_eq!(minimal, vec![1, 2, 3]);
+        assert_eq!(
+            tried,
+            vec![vec![], vec![3], vec![1, 2], vec![2, 3], vec![1, 3]]
+        );
+    }
+}
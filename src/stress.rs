@@ -0,0 +1,338 @@
+//! Differential stress testing.
+//!
+//! A problem with a slow but obviously correct [`Reference`] solution is run
+//! on thousands of generated inputs, comparing the reference answer with the
+//! one of the optimized [`Problem::solve`]. The first mismatch stops the run
+//! and is reported with its seed, so it can be replayed with `--cases 1`, along
+//! with the smallest input [`shrink`](crate::shrink) could reduce it to among
+//! those the problem accepts.
+
+use std::{fmt, hash::Hash};
+
+use crate::{
+    answer::FormatAnswer,
+    error::Result,
+    generate::{Generate, Rng, Shape},
+    problem::Problem,
+    shrink::{self, Shrink},
+};
+
+/// A problem with a reference solution to check the optimized one against
+pub trait Reference: Generate {
+    /// Straightforward solution, correct by construction rather than fast
+    fn reference(input: Self::Input) -> Result<Self::Output>;
+}
+
+/// Object safe view of a [`Reference`] problem for the stress registry
+pub trait Stress: Sync {
+    fn slug(&self) -> &'static str;
+
+    /// Generate the input of one seed and solve it both ways
+    fn case(&self, seed: u64, size: usize) -> Result<Case>;
+
+    /// Smallest variant of a mismatching case that still mismatches
+    fn shrink(&self, case: &Case) -> Result<Case>;
+}
+
+impl<P> Stress for P
+where
+    P: Reference + Shrink + Sync,
+    P::Input: Clone + Eq + Hash,
+{
+    fn slug(&self) -> &'static str {
+        P::SLUG
+    }
+
+    fn case(&self, seed: u64, size: usize) -> Result<Case> {
+        let mut rng = Rng::new(seed);
+        let size = rng.size(1..=size.clamp(1, P::MAX_SIZE));
+        let shape = Shape::ALL[rng.size(0..=2)];
+        let input = P::write(&P::generate(&mut rng, size, shape));
+
+        // Both sides solve the parsed text so a replay sees exactly the same
+        let (expected, actual) = compare::<P>(P::parse(input.as_bytes())?);
+
+        Ok(Case {
+            seed,
+            size,
+            input,
+            expected,
+            actual,
+        })
+    }
+
+    fn shrink(&self, case: &Case) -> Result<Case> {
+        // The judge never passes an input breaking the constraints, so a
+        // mismatch on one would not be a bug
+        let valid = |input: &P::Input| {
+            let mut candidates = P::shrink(input);
+
+            candidates.retain(|candidate| P::validate(candidate).is_ok());
+            candidates
+        };
+        let minimal = shrink::minimize(P::parse(case.input.as_bytes())?, valid, |input| {
+            let (expected, actual) = compare::<P>(input.clone());
+            expected != actual
+        });
+        let (expected, actual) = compare::<P>(minimal.clone());
+
+        Ok(Case {
+            input: P::write(&minimal),
+            expected,
+            actual,
+            ..case.clone()
+        })
+    }
+}
+
+/// One generated input with the answers of both solutions. Errors are kept as
+/// `error: ...` answers so a solution failing where the other does not counts
+/// as a mismatch.
+#[derive(Debug, Clone)]
+pub struct Case {
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Case {
+    pub fn passed(&self) -> bool {
+        self.expected == self.actual
+    }
+}
+
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "input:\n{}", self.input.trim_end())?;
+        writeln!(f, "reference:\n{}", self.expected)?;
+        write!(f, "optimized:\n{}", self.actual)
+    }
+}
+
+/// How many cases to run and how large they get
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// Seed of the first case, the following cases use the next seeds
+    pub seed: u64,
+    pub cases: usize,
+    /// Largest size passed to the generator, each case picks one up to it
+    pub size: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            seed: 0,
+            cases: 1000,
+            size: 10,
+        }
+    }
+}
+
+/// Run the cases in seed order and return the first mismatch, if any
+pub fn run(test: &dyn Stress, options: Options) -> Result<Option<Case>> {
+    for index in 0..options.cases as u64 {
+        let case = test.case(options.seed.wrapping_add(index), options.size)?;
+
+        if !case.passed() {
+            return Ok(Some(case));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Formatted answers of the reference and optimized solutions
+fn compare<P: Reference>(input: P::Input) -> (String, String)
+where
+    P::Input: Clone,
+{
+    (
+        answer::<P>(P::reference(input.clone())),
+        answer::<P>(P::solve(input)),
+    )
+}
+
+fn answer<P: Problem>(output: Result<P::Output>) -> String {
+    match output {
+        Ok(output) => output.format_answer(),
+        Err(e) => format!("error: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithm,
+        meta::{Constraint, Difficulty, Meta},
+        scanner::Scanner,
+    };
+
+    /// Sums a list, the optimized side being off by one past 5 elements
+    struct Broken;
+
+    impl Problem for Broken {
+        type Input = Vec<i64>;
+        type Output = i64;
+
+        const SLUG: &'static str = "broken";
+
+        const META: Meta = Meta {
+            title: "Broken",
+            url: "https://www.hackerrank.com/challenges/broken/problem",
+            domain: "testing",
+            difficulty: Difficulty::Easy,
+            tags: &[],
+            constraints: &[],
+        };
+
+        fn parse(input: &[u8]) -> Result<Self::Input> {
+            let mut scanner = Scanner::new(input);
+            let n = scanner.next::<usize>()?;
+
+            Ok(scanner.vec::<i64>(n)?)
+        }
+
+        fn solve(input: Self::Input) -> Result<Self::Output> {
+            Ok(input.iter().sum::<i64>() + (input.len() > 5) as i64)
+        }
+    }
+
+    impl Generate for Broken {
+        const MAX_SIZE: usize = 1000;
+
+        fn generate(rng: &mut Rng, size: usize, _: Shape) -> Self::Input {
+            (0..size).map(|_| rng.int(-9..=9)).collect()
+        }
+
+        fn write(input: &Self::Input) -> String {
+            format!("{}\n{}\n", input.len(), crate::generate::join(input))
+        }
+    }
+
+    impl Shrink for Broken {
+        fn shrink(input: &Self::Input) -> Vec<Self::Input> {
+            shrink::removals(input)
+        }
+    }
+
+    impl Reference for Broken {
+        fn reference(input: Self::Input) -> Result<Self::Output> {
+            Ok(input.iter().sum())
+        }
+    }
+
+    /// [`Broken`] on lists of at least 7 elements
+    struct Bounded;
+
+    impl Problem for Bounded {
+        type Input = Vec<i64>;
+        type Output = i64;
+
+        const SLUG: &'static str = "bounded";
+
+        const META: Meta = Meta {
+            constraints: &[Constraint::new("n", 7, 1000)],
+            ..Broken::META
+        };
+
+        fn parse(input: &[u8]) -> Result<Self::Input> {
+            Broken::parse(input)
+        }
+
+        fn values(input: &Self::Input) -> Vec<(&'static str, i64)> {
+            vec![("n", input.len() as i64)]
+        }
+
+        fn solve(input: Self::Input) -> Result<Self::Output> {
+            Broken::solve(input)
+        }
+    }
+
+    impl Generate for Bounded {
+        const MAX_SIZE: usize = Broken::MAX_SIZE;
+
+        fn generate(rng: &mut Rng, size: usize, shape: Shape) -> Self::Input {
+            Broken::generate(rng, size.max(7), shape)
+        }
+
+        fn write(input: &Self::Input) -> String {
+            Broken::write(input)
+        }
+    }
+
+    impl Shrink for Bounded {
+        fn shrink(input: &Self::Input) -> Vec<Self::Input> {
+            <Broken as Shrink>::shrink(input)
+        }
+    }
+
+    impl Reference for Bounded {
+        fn reference(input: Self::Input) -> Result<Self::Output> {
+            Broken::reference(input)
+        }
+    }
+
+    #[test]
+    fn stress_01() {
+        for test in algorithm::stress_tests() {
+            let options = Options {
+                cases: 300,
+                ..Options::default()
+            };
+
+            if let Some(case) = run(test, options).unwrap() {
+                panic!("{}: mismatch at seed {}\n{}", test.slug(), case.seed, case);
+            }
+        }
+    }
+
+    #[test]
+    fn stress_02() {
+        let case = run(&Broken, Options::default()).unwrap().unwrap();
+
+        assert!(case.size > 5);
+        assert_ne!(case.expected, case.actual);
+
+        let replay = Options {
+            seed: case.seed,
+            cases: 1,
+            ..Options::default()
+        };
+        let again = run(&Broken, replay).unwrap().unwrap();
+
+        assert_eq!(again.input, case.input);
+    }
+
+    #[test]
+    fn stress_03() {
+        let options = Options {
+            size: 200,
+            ..Options::default()
+        };
+        let case = run(&Broken, options).unwrap().unwrap();
+        let shrunk = Broken.shrink(&case).unwrap();
+
+        // Six elements are the fewest that still trigger the bug
+        assert!(shrunk.input.starts_with("6\n"), "{}", shrunk.input);
+        assert!(!shrunk.passed());
+        assert_eq!(shrunk.seed, case.seed);
+    }
+
+    #[test]
+    fn stress_04() {
+        let options = Options {
+            size: 200,
+            ..Options::default()
+        };
+        let case = run(&Bounded, options).unwrap().unwrap();
+        let shrunk = Bounded.shrink(&case).unwrap();
+
+        // Six elements trigger the bug but break the constraint on n
+        assert!(shrunk.input.starts_with("7\n"), "{}", shrunk.input);
+        assert!(!shrunk.passed());
+    }
+}
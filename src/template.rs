@@ -1,63 +1,53 @@
-use std::{fs::read_to_string, io::Result};
+use crate::{
+    error::Result,
+    meta::{Difficulty, Meta},
+    problem::Problem,
+    scanner::Scanner,
+};
 
-fn solution(s1: &str, s2: &str) -> String {
-    let answer = "OK";
+fn solution({{params}}) -> {{output}} {
+    let _ = {{input_value}};
 
-    answer.to_string()
+    todo!("{{slug}}")
 }
 
-fn parse_and_run(file_path: &str) -> Result<String> {
-    let content = read_to_string(file_path)?;
-    let mut lines = content.lines();
+pub struct {{name}};
 
-    let first_multiple_input: Vec<String> = lines
-        .next()
-        .unwrap()
-        .split(' ')
-        .map(|s| s.to_string())
-        .collect();
+impl Problem for {{name}} {
+    type Input = {{input_type}};
+    type Output = {{output}};
 
-    let n = first_multiple_input[0].trim().parse::<i32>().unwrap();
-    let k = first_multiple_input[1].trim().parse::<i32>().unwrap();
-    let s = lines.next().unwrap();
+    const SLUG: &'static str = "{{slug}}";
 
-    let result = solution("s1", "s2");
-    Ok(result)
-}
+    const META: Meta = Meta {
+        title: "{{title}}",
+        url: "https://www.hackerrank.com/challenges/{{challenge}}/problem",
+        domain: "",
+        difficulty: Difficulty::Easy,
+        tags: &[],
+        constraints: &[],
+    };
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    fn parse(input: &[u8]) -> Result<Self::Input> {
+        let mut scanner = Scanner::new(input);
 
-    #[test]
-    fn solution_01() {
-        let result = solution("a", "b");
-        assert_eq!(result, "OK");
+{{parse}}        {{input_expr}}
     }
 
-    #[test]
-    fn solution_02() {
-        let result = solution("a", "b");
-        assert_eq!(result, "OK");
+    fn solve({{input_value}}: Self::Input) -> Result<Self::Output> {
+        Ok(solution({{args}}))
     }
+}
 
-    #[test]
-    fn solution_03() {
-        let result = solution("a", "b");
-        assert_eq!(result, "OK");
-    }
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixture;
 
     #[test]
-    fn test_solution_04() {
-        let result = parse_and_run("src/algorithm/solution_01.txt");
-        match result {
-            Ok(output) => {
-                assert_eq!(output, "OK")
-            }
-            Err(e) => {
-                println!("Error reading file: {}", e);
-                // The test will pass even if file doesn't exist, for flexibility
-            }
-        }
+    #[ignore = "fill in the sample fixture and the solution, then remove this"]
+    fn {{slug}}_01() {
+        let report = fixture::run(&{{name}}).unwrap();
+        assert!(report.passed(), "{}", report);
     }
 }
@@ -0,0 +1,245 @@
+//! Structured debug tracing for solutions.
+//!
+//! Solutions report what they do through [`trace!`] events instead of
+//! printing, which would flood stress and bench runs and slow big inputs down.
+//! Events are dropped unless tracing is turned on for their problem at their
+//! level or a more verbose one, which `hackerank run --trace` does. Kept events
+//! are collected on the thread that solves until the runner takes them to show
+//! or save.
+
+use std::{
+    cell::RefCell,
+    fmt,
+    str::FromStr,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Verbosity of a problem, and level of an event from [`Level::Info`] on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    /// Results of a whole solve
+    Info,
+    /// Intermediate values, a few per solve
+    Debug,
+    /// Single steps, possibly one per input value
+    Trace,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Level::Off => "off",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        })
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(Level::Off),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!(
+                "unknown level `{}`, expected off, info, debug or trace",
+                s
+            )),
+        }
+    }
+}
+
+/// Verbosity of every problem
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Filter {
+    /// Verbosity of problems not listed
+    pub default: Option<Level>,
+    pub problems: Vec<(String, Level)>,
+}
+
+impl Filter {
+    pub fn level(&self, slug: &str) -> Level {
+        self.problems
+            .iter()
+            .find(|(problem, _)| problem == slug)
+            .map(|(_, level)| *level)
+            .or(self.default)
+            .unwrap_or(Level::Off)
+    }
+
+    /// First problem the filter names that `known` does not accept, such as
+    /// a typo that would otherwise leave tracing off without a word
+    pub fn unknown(&self, known: impl Fn(&str) -> bool) -> Option<&str> {
+        self.problems
+            .iter()
+            .map(|(slug, _)| slug.as_str())
+            .find(|slug| !known(slug))
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    /// Comma separated levels for every problem, `<slug>=<level>` for one, or
+    /// `<slug>` for all the events of one, as in `info,dna_health=trace`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = Filter::default();
+
+        for part in s.split(',').filter(|part| !part.is_empty()) {
+            match part.split_once('=') {
+                Some((slug, level)) => filter.problems.push((slug.to_string(), level.parse()?)),
+                None => match part.parse() {
+                    Ok(level) => filter.default = Some(level),
+                    Err(_) => filter.problems.push((part.to_string(), Level::Trace)),
+                },
+            }
+        }
+
+        Ok(filter)
+    }
+}
+
+/// Something a solution did, with the values it did it with
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    pub slug: &'static str,
+    pub level: Level,
+    /// What happened, the same for every event of a kind
+    pub name: &'static str,
+    /// Values, in their `Debug` form
+    pub fields: Vec<(&'static str, String)>,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:<5} {}: {}", self.level, self.slug, self.name)?;
+
+        for (key, value) in &self.fields {
+            write!(f, " {}={}", key, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Set once any thread turned tracing on, so solutions skip the thread local
+/// lookup in the usual case of nobody tracing
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static FILTER: RefCell<Filter> = RefCell::new(Filter::default());
+    static EVENTS: RefCell<Vec<Event>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Trace the solutions run on this thread according to `filter`
+pub fn set_filter(filter: Filter) {
+    ACTIVE.store(true, Ordering::Relaxed);
+    FILTER.with(|current| *current.borrow_mut() = filter);
+}
+
+/// True when events of `level` from the module at `module_path` are kept
+pub fn enabled(module_path: &str, level: Level) -> bool {
+    ACTIVE.load(Ordering::Relaxed)
+        && FILTER
+            .with(|filter| level != Level::Off && level <= filter.borrow().level(slug(module_path)))
+}
+
+pub fn record(event: Event) {
+    EVENTS.with(|events| events.borrow_mut().push(event));
+}
+
+/// Events recorded on this thread since the last call
+pub fn take() -> Vec<Event> {
+    EVENTS.with(|events| events.take())
+}
+
+/// Slug of the problem a module path belongs to, problem modules being named
+/// after their slug
+pub fn slug(module_path: &str) -> &str {
+    module_path.rsplit("::").next().unwrap_or(module_path)
+}
+
+/// Record an event of the calling problem, if its verbosity allows it:
+/// `trace!(Level::Debug, "reduced", direction = "east", squares = 3)`. The
+/// values are only formatted when the event is kept.
+macro_rules! trace {
+    ($level:expr, $name:literal $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::enabled(module_path!(), $level) {
+            $crate::trace::record($crate::trace::Event {
+                slug: $crate::trace::slug(module_path!()),
+                level: $level,
+                name: $name,
+                fields: vec![$((stringify!($key), format!("{:?}", $value))),*],
+            });
+        }
+    };
+}
+
+pub(crate) use trace;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithm;
+
+    #[test]
+    fn filter_01() {
+        let filter = "info,dna_health=trace,anagram=off,two_strings"
+            .parse::<Filter>()
+            .unwrap();
+
+        assert_eq!(filter.level("dna_health"), Level::Trace);
+        assert_eq!(filter.level("anagram"), Level::Off);
+        assert_eq!(filter.level("two_strings"), Level::Trace);
+        assert_eq!(filter.level("queens_attack_ii"), Level::Info);
+        assert_eq!(Filter::default().level("dna_health"), Level::Off);
+        assert!("dna_health=loud".parse::<Filter>().is_err());
+    }
+
+    #[test]
+    fn filter_02() {
+        let known = |slug: &str| algorithm::find(slug).is_some();
+        let filter = "debug,dna_health=trace,anagram".parse::<Filter>().unwrap();
+
+        assert_eq!(filter.unknown(known), None);
+
+        // A typo in a slug, or in a level taken for a slug
+        let filter = "dna_helth=trace".parse::<Filter>().unwrap();
+
+        assert_eq!(filter.unknown(known), Some("dna_helth"));
+
+        let filter = "anagram,degub".parse::<Filter>().unwrap();
+
+        assert_eq!(filter.unknown(known), Some("degub"));
+    }
+
+    #[test]
+    fn trace_01() {
+        let problem = algorithm::find("palindrome_index").unwrap();
+
+        problem.run(b"1\naaab\n").unwrap();
+        assert_eq!(take(), vec![]);
+
+        set_filter("palindrome_index=debug".parse().unwrap());
+        problem.run(b"1\naaab\n").unwrap();
+        assert_eq!(take(), vec![]);
+
+        set_filter("palindrome_index".parse().unwrap());
+        problem.run(b"1\naaab\n").unwrap();
+
+        let events = take();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].to_string(),
+            "trace palindrome_index: compare left=0 left_char='a' right=3 right_char='b'"
+        );
+
+        set_filter(Filter::default());
+    }
+}